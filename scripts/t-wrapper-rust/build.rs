@@ -0,0 +1,40 @@
+//! 빌드 스크립트
+//! src/ 아래 소스의 해시를 `T_WRAPPER_SOURCE_HASH`로 넘겨
+//! 패키지 버전을 올리지 않고 동작을 바꿔도 증분 실행 캐시가 무효화되도록 한다
+
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// `dir` 아래 모든 파일 (정렬된 순서)
+fn source_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.filter_map(|entry| entry.ok()) {
+        let path = entry.path();
+        if path.is_dir() {
+            source_files(&path, files);
+        } else {
+            files.push(path);
+        }
+    }
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=src");
+
+    let mut files = Vec::new();
+    source_files(Path::new("src"), &mut files);
+    files.sort();
+
+    let mut hasher = Sha256::new();
+    for file in &files {
+        hasher.update(file.to_string_lossy().as_bytes());
+        hasher.update([0]);
+        hasher.update(fs::read(file).unwrap_or_default());
+        hasher.update([0]);
+    }
+    let hash: String = hasher.finalize().iter().take(8).map(|byte| format!("{:02x}", byte)).collect();
+    println!("cargo:rustc-env=T_WRAPPER_SOURCE_HASH={}", hash);
+}
@@ -1,46 +1,130 @@
-/// AST 헬퍼 함수들
-/// 순수 함수로 구성되어 테스트하기 쉬움
+//! AST 헬퍼 함수들
+//! 순수 함수로 구성되어 테스트하기 쉬움
 
-use crate::constants::{StringConstants, RegexPatterns};
+use crate::classifier::{classify_module, TranslationMode};
+use crate::constants::{JsxAttributes, StringConstants, RegexPatterns};
+use crate::parser::{parse_file, ParseOptions};
+use serde::Serialize;
+use swc_common::comments::{Comment, Comments};
+use swc_common::{BytePos, Span, Spanned};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{Visit, VisitWith};
+
+/// i18n-ignore 주석인지 확인
+/// `// i18n-ignore`, `/* i18n-ignore */`, `{/* i18n-ignore */}` 모두 같은 주석 본문을 가진다
+pub fn is_ignore_comment(comment: &Comment) -> bool {
+    comment.text.trim().starts_with(StringConstants::I18N_IGNORE)
+}
 
 /// i18n-ignore 주석이 노드 바로 위에 있는지 확인
-/// 파일의 원본 소스코드를 직접 검사하여 주석 감지
 /// 
-/// TypeScript 버전과 동일한 로직:
-/// 1. AST의 leadingComments 확인 (TODO: SWC AST로 구현)
-/// 2. 부모 노드의 leadingComments 확인 (TODO: SWC AST로 구현)
-/// 3. 소스코드 직접 검사 (현재 구현됨)
-pub fn has_ignore_comment(_path: (), source_code: Option<&str>) -> bool {
-    // TODO: SWC AST 노드의 leadingComments 확인
-    // if let Some(node) = path.node {
-    //     if let Some(comments) = node.leading_comments {
-    //         // 주석 확인 로직
-    //     }
-    // }
-    
-    // TODO: 부모 노드의 leadingComments 확인
-    // if let Some(parent) = path.parent_path {
-    //     // 부모 주석 확인 로직
-    // }
-    
-    // 3. 소스코드 직접 검사 (node.loc가 있는 경우)
-    if let Some(code) = source_code {
-        let lines: Vec<&str> = code.lines().collect();
-        
-        // 현재 라인과 바로 위 라인 검사 (최대 3줄 전까지)
-        for i in 0..lines.len().min(3) {
-            let line = lines[i];
-            if line.contains(StringConstants::I18N_IGNORE)
-                || line.contains(StringConstants::I18N_IGNORE_COMMENT)
-                || line.contains(StringConstants::I18N_IGNORE_BLOCK)
-                || line.contains(StringConstants::I18N_IGNORE_JSX)
-            {
-                return true;
-            }
+/// 파서가 수집한 주석에서 노드 시작 위치(span.lo)의 leading comments를 확인한다.
+/// 파일 전체가 아니라 해당 노드에 붙은 주석만 본다.
+pub fn has_ignore_comment(span: Span, comments: Option<&dyn Comments>) -> bool {
+    let Some(comments) = comments else {
+        return false;
+    };
+    if span.is_dummy() {
+        return false;
+    }
+    comments
+        .get_leading(span.lo)
+        .is_some_and(|leading| leading.iter().any(is_ignore_comment))
+}
+
+/// JSX 표현식 컨테이너 안에 i18n-ignore 주석이 있는지 확인
+/// 
+/// - `{/* i18n-ignore */}`: 다음 형제 노드를 스킵하는 마커
+/// - `{/* i18n-ignore */ "텍스트"}`: 컨테이너 안의 표현식을 스킵
+/// 
+/// JSX 안의 주석은 다음 노드의 leading comment가 아니라
+/// `{` 바로 뒤의 trailing comment로 붙기 때문에 별도로 확인한다.
+pub fn is_jsx_ignore_container(container: &JSXExprContainer, comments: Option<&dyn Comments>) -> bool {
+    let Some(comments) = comments else {
+        return false;
+    };
+    let has_ignore = |found: Option<Vec<Comment>>| {
+        found.is_some_and(|found| found.iter().any(is_ignore_comment))
+    };
+    let after_brace = container.span.lo + BytePos(1);
+    if has_ignore(comments.get_trailing(after_brace)) {
+        return true;
+    }
+    match &container.expr {
+        JSXExpr::JSXEmptyExpr(empty) => {
+            has_ignore(comments.get_leading(empty.span.lo))
+                || has_ignore(comments.get_leading(empty.span.hi))
+        }
+        JSXExpr::Expr(expr) => has_ignore_comment(expr.span(), Some(comments)),
+    }
+}
+
+/// 방문 중인 노드의 조상 종류 (Babel의 path.parent / findParent 대응)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AncestorKind {
+    /// `t("...")` 호출의 인자 (이미 래핑됨)
+    TranslationCall,
+    /// import/export 구문, `import("...")`, `require("...")`의 모듈 경로
+    ModuleSource,
+    /// 객체 프로퍼티 KEY, computed 멤버 접근 KEY (`obj["키"]`)
+    PropertyKey,
+    /// TS enum 멤버, `declare` 블록 등 타입/선언 전용 위치
+    TypeOnly,
+    /// `"use client"` 같은 디렉티브
+    Directive,
+    /// 그 외 일반 노드 (부모 주석 확인용)
+    Node,
+}
+
+/// 조상 스택의 한 항목
+#[derive(Debug, Clone, Copy)]
+pub struct Ancestor {
+    pub kind: AncestorKind,
+    pub span: Span,
+}
+
+impl Ancestor {
+    pub fn new(kind: AncestorKind, span: Span) -> Self {
+        Self { kind, span }
+    }
+}
+
+/// 변환 후보 문자열을 t()로 감싸지 않은 이유
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SkipReason {
+    /// 자신이나 부모 노드에 i18n-ignore 주석
+    IgnoreComment,
+    /// 이미 `t("...")` 호출의 인자
+    AlreadyTranslated,
+    /// import/export, `import()`/`require()`의 모듈 경로
+    ModuleSource,
+    /// 객체 프로퍼티 KEY, computed 멤버 접근 KEY
+    ObjectKey,
+    /// TS enum 멤버, `declare` 블록
+    TypeOnly,
+    /// `"use client"` 같은 디렉티브
+    Directive,
+    /// 컴포넌트/훅 body 밖 (모듈 상수, 유틸 함수 등 t()를 쓸 수 없는 위치)
+    NonComponentScope,
+    /// server 모드의 훅 body (async로 바꾸면 훅을 호출하는 쪽이 Promise를 받으므로 변환하지 않음)
+    ServerHook,
+    /// className, id 등 화면에 보이지 않는 JSX 속성
+    NonTranslatableAttribute,
+}
+
+impl AncestorKind {
+    /// 이 조상 아래의 문자열을 스킵하는 이유 (Node는 스킵하지 않음)
+    pub fn skip_reason(self) -> Option<SkipReason> {
+        match self {
+            AncestorKind::TranslationCall => Some(SkipReason::AlreadyTranslated),
+            AncestorKind::ModuleSource => Some(SkipReason::ModuleSource),
+            AncestorKind::PropertyKey => Some(SkipReason::ObjectKey),
+            AncestorKind::TypeOnly => Some(SkipReason::TypeOnly),
+            AncestorKind::Directive => Some(SkipReason::Directive),
+            AncestorKind::Node => None,
         }
     }
-    
-    false
 }
 
 /// 문자열 리터럴 경로를 스킵해야 하는지 확인
@@ -48,42 +132,99 @@ pub fn has_ignore_comment(_path: (), source_code: Option<&str>) -> bool {
 /// TypeScript 버전과 동일한 로직:
 /// 1. i18n-ignore 주석이 있는 경우 스킵
 /// 2. 부모 노드에 i18n-ignore 주석이 있는 경우도 스킵
-/// 3. t() 함수로 이미 래핑된 경우 스킵 (TODO: SWC AST로 구현)
-/// 4. import 구문은 스킵 (TODO: SWC AST로 구현)
-/// 5. 객체 프로퍼티 KEY면 무조건 스킵 (TODO: SWC AST로 구현)
+/// 3. t() 함수로 이미 래핑된 경우 스킵
+/// 4. import/export 구문, import()/require() 경로는 스킵
+/// 5. 객체 프로퍼티 KEY면 무조건 스킵
+/// 
+/// 추가 규칙:
+/// 6. TS enum 멤버, declare 블록은 스킵
+/// 7. "use client" 같은 디렉티브는 스킵
+/// 
+/// `ancestors`는 루트부터 바로 위 부모까지의 조상 스택
 pub fn should_skip_path(
-    _path: (),
-    has_ignore_comment_fn: fn((), Option<&str>) -> bool,
-    source_code: Option<&str>,
+    span: Span,
+    ancestors: &[Ancestor],
+    has_ignore_comment_fn: fn(Span, Option<&dyn Comments>) -> bool,
+    comments: Option<&dyn Comments>,
 ) -> bool {
+    skip_reason(span, ancestors, has_ignore_comment_fn, comments).is_some()
+}
+
+/// should_skip_path와 같은 규칙으로 스킵 이유를 반환 (스킵하지 않으면 None)
+/// 조상 중에서는 가장 바깥(루트 쪽) 조상의 이유를 우선한다
+pub fn skip_reason(
+    span: Span,
+    ancestors: &[Ancestor],
+    has_ignore_comment_fn: fn(Span, Option<&dyn Comments>) -> bool,
+    comments: Option<&dyn Comments>,
+) -> Option<SkipReason> {
     // i18n-ignore 주석이 있는 경우 스킵
-    if has_ignore_comment_fn((), source_code) {
-        return true;
+    if has_ignore_comment_fn(span, comments) {
+        return Some(SkipReason::IgnoreComment);
+    }
+
+    // 부모 노드에 i18n-ignore 주석이 있는 경우도 스킵
+    if let Some(parent) = ancestors.last() {
+        if has_ignore_comment_fn(parent.span, comments) {
+            return Some(SkipReason::IgnoreComment);
+        }
+    }
+
+    ancestors.iter().find_map(|ancestor| ancestor.kind.skip_reason())
+}
+
+/// `t("...")` 또는 `i18n.t("...")` 형태의 번역 함수 호출인지 확인
+pub fn is_translation_call(call: &CallExpr) -> bool {
+    let Callee::Expr(callee) = &call.callee else {
+        return false;
+    };
+    match callee.as_ref() {
+        Expr::Ident(ident) => ident.sym == StringConstants::TRANSLATION_FUNCTION,
+        Expr::Member(MemberExpr {
+            prop: MemberProp::Ident(prop),
+            ..
+        }) => prop.sym == StringConstants::TRANSLATION_FUNCTION,
+        _ => false,
     }
-    
-    // TODO: 부모 노드에 i18n-ignore 주석이 있는 경우도 스킵
-    // if path.parent && has_ignore_comment_fn(path.parent_path, source_code) {
-    //     return true;
-    // }
-    
-    // TODO: t() 함수로 이미 래핑된 경우 스킵
-    // if t.isCallExpression(path.parent) && 
-    //    t.isIdentifier(path.parent.callee, { name: "t" }) {
-    //     return true;
-    // }
-    
-    // TODO: import 구문은 스킵
-    // let import_parent = path.find_parent(|p| t.isImportDeclaration(p.node));
-    // if import_parent.is_some() {
-    //     return true;
-    // }
-    
-    // TODO: 객체 프로퍼티 KEY면 무조건 스킵
-    // if t.isObjectProperty(path.parent) && path.parent.key == path.node {
-    //     return true;
-    // }
-    
-    false
+}
+
+/// `import("...")`, `require("...")` 처럼 인자가 모듈 경로인 호출인지 확인
+pub fn is_module_source_call(call: &CallExpr) -> bool {
+    match &call.callee {
+        Callee::Import(_) => true,
+        Callee::Expr(callee) => {
+            matches!(callee.as_ref(), Expr::Ident(ident) if ident.sym == StringConstants::REQUIRE)
+        }
+        Callee::Super(_) => false,
+    }
+}
+
+/// 디렉티브(`"use client"`, `"use strict"` 등) 구문인지 확인
+/// 
+/// 디렉티브는 모듈/함수 body 맨 앞의 문자열 표현식 구문이므로
+/// 호출하는 쪽에서 prologue 위치인지 함께 확인해야 한다.
+pub fn is_directive(stmt: &Stmt) -> bool {
+    matches!(
+        stmt,
+        Stmt::Expr(ExprStmt { expr, .. }) if matches!(expr.as_ref(), Expr::Lit(Lit::Str(_)))
+    )
+}
+
+/// 모듈 맨 앞 prologue의 디렉티브 값 목록
+/// 예: `"use client"; 'use strict';` → ["use client", "use strict"]
+pub fn module_directives(module: &Module) -> Vec<String> {
+    module
+        .body
+        .iter()
+        .map_while(|item| item.as_stmt().filter(|stmt| is_directive(stmt)))
+        .filter_map(|stmt| match stmt {
+            Stmt::Expr(ExprStmt { expr, .. }) => match expr.as_ref() {
+                Expr::Lit(Lit::Str(str_lit)) => Some(str_lit.value.to_string_lossy().into_owned()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
 }
 
 /// React 컴포넌트 이름인지 확인
@@ -92,12 +233,254 @@ pub fn is_react_component(name: &str) -> bool {
         || RegexPatterns::react_hook().is_match(name)
 }
 
+/// `memo(...)`, `forwardRef(...)`, `React.memo(...)` 처럼 컴포넌트를 감싸는 호출인지 확인
+pub fn is_component_wrapper_call(call: &CallExpr) -> bool {
+    let Callee::Expr(callee) = &call.callee else {
+        return false;
+    };
+    let name = match callee.as_ref() {
+        Expr::Ident(ident) => &ident.sym,
+        Expr::Member(MemberExpr {
+            obj,
+            prop: MemberProp::Ident(prop),
+            ..
+        }) if matches!(obj.as_ref(), Expr::Ident(obj) if obj.sym == StringConstants::REACT_NAMESPACE) => {
+            &prop.sym
+        }
+        _ => return false,
+    };
+    StringConstants::COMPONENT_WRAPPERS.contains(&name.as_ref())
+}
+
+/// 컴포넌트 본문이 될 수 있는 표현식인지 확인
+///
+/// - `() => ...`, `function () {}`
+/// - `memo(() => ...)`, `forwardRef(function (props, ref) {})`, `memo(forwardRef(...))`
+pub fn is_component_init(expr: &Expr) -> bool {
+    match expr {
+        Expr::Arrow(_) | Expr::Fn(_) => true,
+        Expr::Paren(ParenExpr { expr, .. }) => is_component_init(expr),
+        Expr::Call(call) if is_component_wrapper_call(call) => call
+            .args
+            .first()
+            .is_some_and(|arg| arg.spread.is_none() && is_component_init(&arg.expr)),
+        _ => false,
+    }
+}
+
+/// 컴포넌트 함수 노드 (body에 t 바인딩을 추가할 대상)
+pub enum ComponentFnMut<'a> {
+    Arrow(&'a mut ArrowExpr),
+    Function(&'a mut Function),
+}
+
+/// is_component_init이 인정하는 표현식에서 실제 컴포넌트 함수를 꺼낸다
+/// `memo(forwardRef(() => ...))`처럼 감싼 경우 가장 안쪽 함수를 반환
+pub fn component_fn_mut(expr: &mut Expr) -> Option<ComponentFnMut<'_>> {
+    match expr {
+        Expr::Arrow(arrow) => Some(ComponentFnMut::Arrow(arrow)),
+        Expr::Fn(fn_expr) => Some(ComponentFnMut::Function(&mut fn_expr.function)),
+        Expr::Paren(ParenExpr { expr, .. }) => component_fn_mut(expr),
+        Expr::Call(call) if is_component_wrapper_call(call) => match call.args.first_mut() {
+            Some(arg) if arg.spread.is_none() => component_fn_mut(&mut arg.expr),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// body 안에 `name(...)` 호출이 있는지 확인 (예: 이미 useTranslation()을 호출하는지)
+pub fn has_call_to(body: &BlockStmt, name: &str) -> bool {
+    struct CallFinder<'a> {
+        name: &'a str,
+        found: bool,
+    }
+
+    impl Visit for CallFinder<'_> {
+        fn visit_call_expr(&mut self, call: &CallExpr) {
+            if let Callee::Expr(callee) = &call.callee {
+                if matches!(callee.as_ref(), Expr::Ident(ident) if ident.sym == self.name) {
+                    self.found = true;
+                    return;
+                }
+            }
+            call.visit_children_with(self);
+        }
+    }
+
+    let mut finder = CallFinder { name, found: false };
+    body.visit_with(&mut finder);
+    finder.found
+}
+
+/// body 최상위에 `name` 바인딩이 선언되어 있는지 확인
+/// 예: `const { t } = await getServerTranslation();`, `const t = useT();`
+pub fn has_top_level_binding(body: &BlockStmt, name: &str) -> bool {
+    body.stmts.iter().any(|stmt| match stmt {
+        Stmt::Decl(Decl::Var(var)) => var.decls.iter().any(|decl| pat_binds(&decl.name, name)),
+        Stmt::Decl(Decl::Fn(func)) => func.ident.sym == name,
+        _ => false,
+    })
+}
+
+/// 함수 매개변수가 `name`을 바인딩하는지 확인 (구조 분해, 기본값 포함)
+/// 예: `function Foo({ t })`, `({ t = fallback }) => ...`, `(t) => ...`
+pub fn has_param_binding<'a>(params: impl IntoIterator<Item = &'a Pat>, name: &str) -> bool {
+    params.into_iter().any(|param| pat_binds(param, name))
+}
+
+/// 모듈 최상위에 `name` 바인딩이 선언되어 있는지 확인
+/// 예: `const { useTranslation } = require("i18nexus");`
+pub fn has_module_binding(module: &Module, name: &str) -> bool {
+    module.body.iter().any(|item| match item {
+        ModuleItem::Stmt(Stmt::Decl(Decl::Var(var))) => var.decls.iter().any(|decl| pat_binds(&decl.name, name)),
+        ModuleItem::Stmt(Stmt::Decl(Decl::Fn(func))) => func.ident.sym == name,
+        _ => false,
+    })
+}
+
+/// 패턴이 `name`을 바인딩하는지 확인 (구조 분해 포함)
+fn pat_binds(pat: &Pat, name: &str) -> bool {
+    match pat {
+        Pat::Ident(binding) => binding.id.sym == name,
+        Pat::Array(array) => array.elems.iter().flatten().any(|elem| pat_binds(elem, name)),
+        Pat::Rest(rest) => pat_binds(&rest.arg, name),
+        Pat::Assign(assign) => pat_binds(&assign.left, name),
+        Pat::Object(object) => object.props.iter().any(|prop| match prop {
+            ObjectPatProp::KeyValue(key_value) => pat_binds(&key_value.value, name),
+            ObjectPatProp::Assign(assign) => assign.key.sym == name,
+            ObjectPatProp::Rest(rest) => pat_binds(&rest.arg, name),
+        }),
+        _ => false,
+    }
+}
+
+/// JSX 속성 값이 번역 대상인지 확인
+/// className, id, href 등 화면에 보이지 않는 속성은 제외
+pub fn is_translatable_jsx_attr(name: &JSXAttrName) -> bool {
+    let name = match name {
+        JSXAttrName::Ident(ident) => ident.sym.to_string(),
+        JSXAttrName::JSXNamespacedName(namespaced) => {
+            format!("{}:{}", namespaced.ns.sym, namespaced.name.sym)
+        }
+    };
+    !JsxAttributes::NON_TRANSLATABLE.contains(&name.as_str())
+}
+
 /// 서버 컴포넌트인지 확인
 /// 
-/// TypeScript 버전과 동일한 로직:
-/// await getServerTranslation() 패턴이 있으면 서버 컴포넌트로 판단
+/// 소스코드를 파싱하여 classify_module로 판단
+/// (디렉티브, 서버 전용 import, await getServerTranslation(), 훅 사용, async default export)
+/// 파싱에 실패하면 false
 pub fn is_server_component(source_code: &str) -> bool {
-    // await getServerTranslation() 패턴 확인
-    // 정규식으로 간단히 확인 (실제로는 AST로 확인하는 것이 더 정확하지만, 현재는 소스코드 직접 검사)
-    RegexPatterns::server_component().is_match(source_code)
+    parse_file(source_code, ParseOptions::default()).is_ok_and(|module| {
+        classify_module(
+            &module,
+            StringConstants::GET_SERVER_TRANSLATION,
+            TranslationMode::Client,
+        )
+        .mode
+            == TranslationMode::Server
+    })
+}
+
+/// JSX 공백 문자인지 확인
+/// React는 스페이스/탭/개행만 공백으로 취급 (`&nbsp;` 등은 텍스트로 유지)
+fn is_jsx_whitespace(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\n' | '\r')
+}
+
+/// JSXText를 앞쪽 공백, 본문, 뒤쪽 공백으로 분리
+///
+/// 본문만 t()로 감싸고 앞뒤 공백은 JSXText로 그대로 남기기 위해 사용
+/// 예: `"\n  안녕하세요 "` → (`"\n  "`, `"안녕하세요"`, `" "`)
+pub fn split_jsx_text(text: &str) -> (&str, &str, &str) {
+    let body_start = text.len() - text.trim_start_matches(is_jsx_whitespace).len();
+    let body_end = text.trim_end_matches(is_jsx_whitespace).len().max(body_start);
+    (
+        &text[..body_start],
+        &text[body_start..body_end],
+        &text[body_end..],
+    )
+}
+
+/// JSX 텍스트를 React가 렌더링하는 문자열로 정규화
+///
+/// React(Babel)의 JSX 공백 규칙과 동일:
+/// 1. 각 줄의 앞뒤 공백 제거 (첫 줄 앞, 마지막 줄 뒤는 유지)
+/// 2. 공백만 있는 줄 제거
+/// 3. 남은 줄들은 공백 하나로 연결
+pub fn normalize_jsx_text(text: &str) -> String {
+    let lines: Vec<&str> = text.split('\n').map(|line| line.trim_end_matches('\r')).collect();
+    let last_index = lines.len() - 1;
+    let mut normalized = String::new();
+
+    for (index, line) in lines.iter().enumerate() {
+        let mut line: &str = line;
+        if index != 0 {
+            line = line.trim_start_matches([' ', '\t']);
+        }
+        if index != last_index {
+            line = line.trim_end_matches([' ', '\t']);
+        }
+        if line.is_empty() {
+            continue;
+        }
+        if !normalized.is_empty() {
+            normalized.push(' ');
+        }
+        normalized.push_str(line);
+    }
+
+    normalized
+}
+
+/// 템플릿 리터럴 표현식의 interpolation 변수명 생성
+///
+/// TypeScript 버전과 동일한 규칙:
+/// - `${name}` → `name`
+/// - `${user.name}` → `user_name` (MEMBER_SEPARATOR로 연결)
+/// - 호출, computed 멤버 등 복잡한 표현식 → `expr0`, `expr1` (표현식 인덱스 기준)
+///
+/// 같은 입력에는 항상 같은 이름을 반환한다 (충돌 처리는 호출하는 쪽에서 담당)
+pub fn interpolation_var_name(expr: &Expr, index: usize) -> String {
+    match expr {
+        Expr::Ident(ident) => ident.sym.to_string(),
+        Expr::Paren(ParenExpr { expr, .. })
+        | Expr::TsNonNull(TsNonNullExpr { expr, .. })
+        | Expr::TsAs(TsAsExpr { expr, .. }) => interpolation_var_name(expr, index),
+        Expr::Member(_) | Expr::OptChain(_) => {
+            let mut parts = Vec::new();
+            match collect_member_path(expr, &mut parts) {
+                Some(()) if !parts.is_empty() => parts.join(StringConstants::MEMBER_SEPARATOR),
+                _ => format!("{}{}", StringConstants::EXPR_PREFIX, index),
+            }
+        }
+        _ => format!("{}{}", StringConstants::EXPR_PREFIX, index),
+    }
+}
+
+/// `a.b.c`, `a?.b`, `this.a` 형태의 멤버 체인을 이름 목록으로 수집
+/// computed 접근(`a[0]`)이나 호출이 섞여 있으면 None
+fn collect_member_path(expr: &Expr, parts: &mut Vec<String>) -> Option<()> {
+    let member = match expr {
+        Expr::Ident(ident) => {
+            parts.push(ident.sym.to_string());
+            return Some(());
+        }
+        Expr::This(_) => return Some(()),
+        Expr::Member(member) => member,
+        Expr::OptChain(OptChainExpr { base, .. }) => match base.as_ref() {
+            OptChainBase::Member(member) => member,
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    collect_member_path(&member.obj, parts)?;
+    match &member.prop {
+        MemberProp::Ident(ident) => parts.push(ident.sym.to_string()),
+        _ => return None,
+    }
+    Some(())
 }
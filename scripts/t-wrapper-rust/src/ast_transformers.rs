@@ -1,150 +1,582 @@
-/// AST 변환 로직
-/// 문자열 리터럴, 템플릿 리터럴, JSX 텍스트를 t() 함수로 변환
+//! AST 변환 로직
+//! 문자열 리터럴, 템플릿 리터럴, JSX 텍스트를 t() 함수로 변환
 
 use crate::constants::{StringConstants, RegexPatterns};
-use crate::ast_helpers::{has_ignore_comment, should_skip_path};
+use crate::ast_helpers::{
+    has_ignore_comment, interpolation_var_name, is_component_init, is_directive,
+    is_jsx_ignore_container, is_module_source_call, is_react_component, is_translatable_jsx_attr, is_translation_call, normalize_jsx_text,
+    skip_reason, split_jsx_text, Ancestor, AncestorKind, SkipReason,
+};
+use crate::text_edits::{node_code, TextEdits};
+use crate::session::SourceFileHandle;
 use swc_ecma_ast::*;
-use swc_ecma_visit::{VisitMut, VisitMutWith};
-use swc_common::DUMMY_SP;
+use swc_ecma_visit::{Visit, VisitMut, VisitMutWith, VisitWith};
+use swc_common::comments::Comments;
+use swc_common::{BytePos, EqIgnoreSpan, Span, Spanned, DUMMY_SP};
+use serde::Serialize;
+
+/// 변환 후보 문자열의 노드 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StringKind {
+    /// `<p>안녕하세요</p>`
+    JsxText,
+    /// `placeholder="검색"`
+    JsxAttribute,
+    /// `"안녕하세요"`
+    StringLiteral,
+    /// `` `안녕 ${name}` ``
+    TemplateLiteral,
+}
+
+/// 변환 후보 문자열(한국어 포함) 하나의 처리 기록
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StringRecord {
+    /// 파일 경로 (TransformResult::locate 전에는 빈 문자열)
+    pub file: String,
+    /// 1부터 시작하는 줄 번호 (locate 전에는 0)
+    pub line: usize,
+    /// 1부터 시작하는 열 번호 (문자 단위, locate 전에는 0)
+    pub column: usize,
+    /// 원본 텍스트
+    pub text: String,
+    /// t()에 넘긴 번역 키 (감싸지 않았으면 None)
+    pub key: Option<String>,
+    pub kind: StringKind,
+    pub wrapped: bool,
+    /// 감싸지 않은 이유
+    pub skip_reason: Option<SkipReason>,
+    /// 원본 위치 (줄/열 계산용)
+    #[serde(skip)]
+    pub span: Span,
+}
+
+impl StringRecord {
+    pub fn wrapped(span: Span, kind: StringKind, text: impl Into<String>, key: impl Into<String>) -> Self {
+        Self {
+            file: String::new(),
+            line: 0,
+            column: 0,
+            text: text.into(),
+            key: Some(key.into()),
+            kind,
+            wrapped: true,
+            skip_reason: None,
+            span,
+        }
+    }
+
+    pub fn skipped(span: Span, kind: StringKind, text: impl Into<String>, reason: SkipReason) -> Self {
+        Self {
+            file: String::new(),
+            line: 0,
+            column: 0,
+            text: text.into(),
+            key: None,
+            kind,
+            wrapped: false,
+            skip_reason: Some(reason),
+            span,
+        }
+    }
+}
 
 /// 변환 결과
 #[derive(Debug, Clone)]
 pub struct TransformResult {
     pub was_modified: bool,
+    /// 변환 후보 문자열 기록 (원본 위치 순)
+    pub records: Vec<StringRecord>,
+    /// 변환한 노드의 원본 범위 교체 목록 (minimal diff 출력용)
+    pub edits: TextEdits,
+    /// server 모드에서 변환하지 않은, 후보 문자열이 있는 훅 이름
+    pub skipped_hooks: Vec<String>,
 }
 
 impl TransformResult {
     pub fn new(was_modified: bool) -> Self {
-        Self { was_modified }
-    }
-}
-
-/// 함수 body 내의 AST 노드들을 변환
-/// 
-/// TypeScript 버전과 동일한 로직:
-/// 1. StringLiteral: 한국어가 포함된 문자열을 t() 호출로 변환
-/// 2. TemplateLiteral: 템플릿 리터럴을 i18next 형식으로 변환
-/// 3. JSXText: JSX 텍스트를 t() 호출로 변환
-/// 
-/// TODO: SWC AST traverse로 구현 필요
-/// 현재는 소스코드에서 한국어 감지만 수행
-pub fn transform_function_body(_path: (), source_code: &str) -> TransformResult {
-    let mut was_modified = false;
-
-    // TODO: SWC AST traverse로 구현
-    // path.traverse({
-    //     StringLiteral: (subPath) => {
-    //         if (shouldSkipPath(subPath, hasIgnoreComment) || hasIgnoreComment(subPath, sourceCode)) {
-    //             return;
-    //         }
-    //         const trimmedValue = subPath.node.value.trim();
-    //         if (!trimmedValue) {
-    //             return;
-    //         }
-    //         if (REGEX_PATTERNS.KOREAN_TEXT.test(subPath.node.value)) {
-    //             wasModified = true;
-    //             const replacement = t.callExpression(
-    //                 t.identifier(STRING_CONSTANTS.TRANSLATION_FUNCTION),
-    //                 [t.stringLiteral(subPath.node.value)]
-    //             );
-    //             if (t.isJSXAttribute(subPath.parent)) {
-    //                 subPath.replaceWith(t.jsxExpressionContainer(replacement));
-    //             } else {
-    //                 subPath.replaceWith(replacement);
-    //             }
-    //         }
-    //     },
-    //     TemplateLiteral: (subPath) => {
-    //         // i18n-ignore 주석이 있는 경우 스킵
-    //         // 이미 t()로 래핑된 경우 스킵
-    //         // 템플릿 리터럴을 i18next interpolation 형식으로 변환
-    //         // 예: `안녕 ${name}` → t(`안녕 {{name}}`, { name })
-    //     },
-    //     JSXText: (subPath) => {
-    //         // i18n-ignore 주석이 있는 경우 스킵
-    //         // 한국어가 포함된 텍스트만 처리
-    //         // t() 함수 호출로 감싸기
-    //     },
-    // });
-
-    // 임시로 한국어가 포함되어 있으면 수정되었다고 가정
-    if RegexPatterns::korean_text().is_match(source_code) {
-        was_modified = true;
-    }
-
-    TransformResult::new(was_modified)
+        Self {
+            was_modified,
+            records: Vec::new(),
+            edits: TextEdits::new(),
+            skipped_hooks: Vec::new(),
+        }
+    }
+
+    pub fn with_records(mut self, mut records: Vec<StringRecord>) -> Self {
+        records.sort_by_key(|record| record.span.lo);
+        self.records = records;
+        self
+    }
+
+    /// t()로 감싼 문자열 개수
+    pub fn wrapped_count(&self) -> usize {
+        self.records.iter().filter(|record| record.wrapped).count()
+    }
+
+    /// 감싸지 않은 후보 문자열 개수
+    pub fn skipped_count(&self) -> usize {
+        self.records.len() - self.wrapped_count()
+    }
+
+    /// 기록에 파일 경로와 줄/열 채우기
+    /// 파싱한 세션의 파일 handle이 있어야 span을 줄/열로 바꿀 수 있다
+    pub fn locate(&mut self, file: &SourceFileHandle) {
+        let name = file.name();
+        for record in &mut self.records {
+            let loc = file.line_col(record.span.lo);
+            record.file = name.clone();
+            record.line = loc.line;
+            record.column = loc.column;
+        }
+    }
+
+    pub fn with_edits(mut self, edits: TextEdits) -> Self {
+        self.edits = edits;
+        self
+    }
+
+    pub fn with_skipped_hooks(mut self, skipped_hooks: Vec<String>) -> Self {
+        self.skipped_hooks = skipped_hooks;
+        self
+    }
 }
 
 /// SWC AST Module을 변환하는 Transformer
-pub struct TranslationTransformer {
+pub struct TranslationTransformer<'a> {
     pub was_modified: bool,
+    /// 원본 소스코드
+    #[allow(dead_code)]
     source_code: String,
+    /// 파서가 수집한 주석 (i18n-ignore 판단용)
+    comments: Option<&'a dyn Comments>,
+    /// 현재 방문 중인 노드의 조상 스택 (스킵 규칙 판단용)
+    ancestors: Vec<Ancestor>,
+    /// 현재 방문 중인 컴포넌트/훅 중첩 깊이 (0이면 컴포넌트 밖)
+    component_depth: usize,
+    /// 훅 body를 변환하지 않음 (server 모드)
+    skip_hooks: bool,
+    /// 현재 방문 중인, 변환하지 않는 훅 중첩 깊이
+    skipped_hook_depth: usize,
+    /// 후보 문자열이 있어 건너뛴 훅 이름
+    pub skipped_hooks: Vec<String>,
     /// 변환된 함수 목록 (함수 이름)
     pub modified_functions: Vec<String>,
+    /// 변환 후보 문자열 기록
+    pub records: Vec<StringRecord>,
+    /// 변환한 노드의 원본 범위 교체 목록
+    pub edits: TextEdits,
 }
 
-impl TranslationTransformer {
+impl<'a> TranslationTransformer<'a> {
     pub fn new(source_code: String) -> Self {
         Self {
             was_modified: false,
             source_code,
+            comments: None,
+            ancestors: Vec::new(),
+            component_depth: 0,
+            skip_hooks: false,
+            skipped_hook_depth: 0,
+            skipped_hooks: Vec::new(),
             modified_functions: Vec::new(),
+            records: Vec::new(),
+            edits: TextEdits::new(),
+        }
+    }
+
+    /// 파서가 수집한 주석 설정
+    /// 설정하지 않으면 i18n-ignore 주석을 인식하지 못한다
+    pub fn with_comments(mut self, comments: &'a dyn Comments) -> Self {
+        self.comments = Some(comments);
+        self
+    }
+
+    /// 훅(`useXxx`) body는 변환하지 않음
+    /// server 모드의 `await getServerTranslation()`은 함수를 async로 바꿔야 하는데,
+    /// 훅을 async로 바꾸면 훅을 호출하는 모든 곳이 값 대신 Promise를 받게 된다
+    pub fn with_hooks_skipped(mut self) -> Self {
+        self.skip_hooks = true;
+        self
+    }
+
+    fn comments(&self) -> Option<&'a dyn Comments> {
+        self.comments
+    }
+
+    /// 노드(문장, JSX 요소, 프로퍼티 등) 바로 위에 i18n-ignore 주석이 있는지 확인
+    fn is_ignored(&self, span: Span) -> bool {
+        has_ignore_comment(span, self.comments())
+    }
+
+    /// 문자열/템플릿 리터럴을 스킵해야 하는 이유 (스킵하지 않으면 None)
+    /// 컴포넌트/훅 body 밖(모듈 상수, 유틸 함수 등)은 t()를 쓸 수 없으므로 스킵
+    fn literal_skip_reason(&self, span: Span) -> Option<SkipReason> {
+        skip_reason(span, &self.ancestors, has_ignore_comment, self.comments())
+            .or((self.skipped_hook_depth > 0).then_some(SkipReason::ServerHook))
+            .or((self.component_depth == 0).then_some(SkipReason::NonComponentScope))
+    }
+
+    /// t()로 감싼 문자열 기록
+    fn record_wrapped(&mut self, span: Span, kind: StringKind, text: impl Into<String>, key: impl Into<String>) {
+        self.was_modified = true;
+        self.records.push(StringRecord::wrapped(span, kind, text, key));
+    }
+
+    /// i18n-ignore 주석으로 통째로 건너뛴 노드 안의 후보 문자열 기록
+    fn record_ignored<N>(&mut self, node: &N)
+    where
+        N: for<'v> VisitWith<IgnoredStringCollector<'v>>,
+    {
+        node.visit_with(&mut IgnoredStringCollector {
+            records: &mut self.records,
+        });
+    }
+
+    /// 컴포넌트/훅 노드 방문
+    /// body 안에서 변환이 일어나면 컴포넌트 이름을 modified_functions에 기록
+    /// 훅을 건너뛰는 경우(with_hooks_skipped) 후보 문자열은 ServerHook으로 기록만 한다
+    fn visit_component<N>(&mut self, name: String, span: Span, node: &mut N)
+    where
+        N: VisitMutWith<Self>,
+    {
+        if self.skip_hooks && RegexPatterns::react_hook().is_match(&name) {
+            let records_before = self.records.len();
+            self.skipped_hook_depth += 1;
+            self.visit_children_as(AncestorKind::Node, span, node);
+            self.skipped_hook_depth -= 1;
+            if self.records[records_before..]
+                .iter()
+                .any(|record| record.skip_reason == Some(SkipReason::ServerHook))
+            {
+                self.skipped_hooks.push(name);
+            }
+            return;
+        }
+        let outer_modified = std::mem::take(&mut self.was_modified);
+        self.component_depth += 1;
+        self.visit_children_as(AncestorKind::Node, span, node);
+        self.component_depth -= 1;
+        if self.was_modified {
+            self.modified_functions.push(name);
+        }
+        self.was_modified |= outer_modified;
+    }
+
+    /// 조상 스택에 노드를 올린 채로 자식 노드 방문
+    fn visit_children_as<N>(&mut self, kind: AncestorKind, span: Span, node: &mut N)
+    where
+        N: VisitMutWith<Self>,
+    {
+        self.ancestors.push(Ancestor::new(kind, span));
+        node.visit_mut_children_with(self);
+        self.ancestors.pop();
+    }
+
+    /// 구문 목록 방문
+    /// 맨 앞의 디렉티브("use client" 등)는 Directive로 표시하여 변환하지 않는다
+    fn visit_stmts_with_directives<N>(&mut self, items: &mut [N], as_stmt: fn(&N) -> Option<&Stmt>)
+    where
+        N: VisitMutWith<Self>,
+    {
+        let mut in_prologue = true;
+        for item in items.iter_mut() {
+            in_prologue = in_prologue && as_stmt(item).is_some_and(is_directive);
+            if in_prologue {
+                let span = as_stmt(item).map(|stmt| stmt.span()).unwrap_or(DUMMY_SP);
+                self.ancestors.push(Ancestor::new(AncestorKind::Directive, span));
+                item.visit_mut_with(self);
+                self.ancestors.pop();
+            } else {
+                item.visit_mut_with(self);
+            }
         }
     }
 
     /// t() 함수 호출 생성
-    /// TODO: 실제 AST 노드 생성 구현
-    fn create_t_call(&self, _value: &str) -> Expr {
-        // TODO: 실제 AST 노드 생성 구현
-        // 현재는 플레이스홀더
-        Expr::Ident(Ident {
+    /// `t("value")` 형태의 CallExpr를 만든다
+    fn create_t_call(&self, value: Str) -> Expr {
+        self.create_t_call_with_values(value, Vec::new())
+    }
+
+    /// interpolation 값이 있는 t() 함수 호출 생성
+    /// 값이 있으면 `t("value", { name })` 형태로 두 번째 인자를 추가한다
+    fn create_t_call_with_values(&self, value: Str, values: Vec<PropOrSpread>) -> Expr {
+        let mut args = vec![ExprOrSpread {
+            spread: None,
+            expr: Box::new(Expr::Lit(Lit::Str(value))),
+        }];
+        if !values.is_empty() {
+            args.push(ExprOrSpread {
+                spread: None,
+                expr: Box::new(Expr::Object(ObjectLit {
+                    span: DUMMY_SP,
+                    props: values,
+                })),
+            });
+        }
+
+        Expr::Call(CallExpr {
             span: DUMMY_SP,
-            sym: StringConstants::TRANSLATION_FUNCTION.into(),
-            optional: false,
+            callee: Callee::Expr(Box::new(Expr::Ident(Ident {
+                span: DUMMY_SP,
+                sym: StringConstants::TRANSLATION_FUNCTION.into(),
+                optional: false,
+                ctxt: Default::default(),
+            }))),
+            args,
+            type_args: None,
             ctxt: Default::default(),
         })
     }
+
+    /// JSXText를 t() 호출을 담은 JSXExprContainer로 변환
+    /// 
+    /// React가 렌더링하는 공백은 그대로 유지:
+    /// 본문 앞뒤의 공백은 JSXText로 남기고 본문만 `{t("...")}`로 감싼다.
+    /// 변환 대상이 아니면 None 반환
+    fn transform_jsx_text(&mut self, text: &JSXText) -> Option<Vec<JSXElementChild>> {
+        let (_, body, _) = split_jsx_text(&text.value);
+        let key = normalize_jsx_text(body);
+
+        // 빈 텍스트나 공백만 있는 경우, 한국어가 없는 경우 스킵
+        if key.is_empty() || !RegexPatterns::korean_text().is_match(&key) {
+            return None;
+        }
+
+        // 앞뒤 공백을 제외한 본문 범위
+        let body_span = jsx_text_body_span(text);
+        if let Some(reason) = self.literal_skip_reason(text.span) {
+            self.records.push(StringRecord::skipped(body_span, StringKind::JsxText, key, reason));
+            return None;
+        }
+
+        self.record_wrapped(body_span, StringKind::JsxText, key.clone(), key.clone());
+
+        let (leading, _, trailing) = split_jsx_text(&text.raw);
+        let t_call = self.create_t_call(Str {
+            span: DUMMY_SP,
+            value: key.into(),
+            raw: None,
+        });
+        // 앞뒤 공백은 원본 그대로 두고 본문 범위만 교체
+        self.edits.replace_code(body_span, format!("{{{}}}", node_code(&t_call)));
+
+        let mut children = Vec::with_capacity(3);
+        if !leading.is_empty() {
+            children.push(JSXElementChild::JSXText(JSXText {
+                span: DUMMY_SP,
+                value: leading.into(),
+                raw: leading.into(),
+            }));
+        }
+        children.push(JSXElementChild::JSXExprContainer(JSXExprContainer {
+            span: text.span,
+            expr: JSXExpr::Expr(Box::new(t_call)),
+        }));
+        if !trailing.is_empty() {
+            children.push(JSXElementChild::JSXText(JSXText {
+                span: DUMMY_SP,
+                value: trailing.into(),
+                raw: trailing.into(),
+            }));
+        }
+        Some(children)
+    }
+
+    /// 템플릿 리터럴을 i18next interpolation 형식의 t() 호출로 변환
+    /// 
+    /// 예: `안녕하세요 ${name}님` → t("안녕하세요 {{name}}님", { name })
+    /// 
+    /// 변수명은 표현식에서 결정적으로 만들어지고,
+    /// 같은 이름이 다른 표현식에 이미 쓰였으면 `_1`, `_2`... 접미사를 붙인다.
+    /// 동일한 표현식이 반복되면 같은 변수를 재사용한다.
+    /// 변환 대상이 아니면 None 반환, 변환하면 t() 호출과 번역 키 반환
+    fn transform_tpl(&mut self, tpl: &mut Tpl) -> Option<(Expr, String)> {
+        // 템플릿 리터럴의 모든 부분에 하나라도 한국어가 있는지 확인
+        if !tpl_has_korean(tpl) {
+            return None;
+        }
+        let quasi_texts: Vec<String> = tpl.quasis.iter().map(quasi_text).collect();
+
+        let exprs = std::mem::take(&mut tpl.exprs);
+        let mut message = String::new();
+        let mut values: Vec<(String, Box<Expr>)> = Vec::new();
+
+        for (index, text) in quasi_texts.iter().enumerate() {
+            message.push_str(text);
+
+            let Some(expr) = exprs.get(index) else {
+                continue;
+            };
+
+            let base_name = interpolation_var_name(expr, index);
+            let mut name = base_name.clone();
+            let mut suffix = 0;
+            loop {
+                match values.iter().find(|(existing, _)| *existing == name) {
+                    // 같은 표현식이면 변수 재사용
+                    Some((_, existing_expr)) if existing_expr.eq_ignore_span(expr) => break,
+                    Some(_) => {
+                        suffix += 1;
+                        name = format!(
+                            "{}{}{}",
+                            base_name,
+                            StringConstants::MEMBER_SEPARATOR,
+                            suffix
+                        );
+                    }
+                    None => {
+                        values.push((name.clone(), expr.clone()));
+                        break;
+                    }
+                }
+            }
+
+            message.push_str(StringConstants::INTERPOLATION_START);
+            message.push_str(&name);
+            message.push_str(StringConstants::INTERPOLATION_END);
+        }
+
+        let props = values
+            .into_iter()
+            .map(|(name, expr)| {
+                let prop = match *expr {
+                    Expr::Ident(ident) if ident.sym == *name => Prop::Shorthand(ident),
+                    expr => Prop::KeyValue(KeyValueProp {
+                        key: PropName::Ident(IdentName {
+                            span: DUMMY_SP,
+                            sym: name.into(),
+                        }),
+                        value: Box::new(expr),
+                    }),
+                };
+                PropOrSpread::Prop(Box::new(prop))
+            })
+            .collect();
+
+        let t_call = self.create_t_call_with_values(
+            Str {
+                span: tpl.span,
+                value: message.clone().into(),
+                raw: None,
+            },
+            props,
+        );
+        Some((t_call, message))
+    }
 }
 
-impl VisitMut for TranslationTransformer {
+/// JSXText에서 앞뒤 공백을 제외한 본문의 원본 범위
+fn jsx_text_body_span(text: &JSXText) -> Span {
+    let (leading, _, trailing) = split_jsx_text(&text.raw);
+    Span::new(
+        text.span.lo + BytePos(leading.len() as u32),
+        text.span.hi - BytePos(trailing.len() as u32),
+    )
+}
+
+/// 템플릿 리터럴 조각 중 하나라도 한국어가 있는지 확인
+fn tpl_has_korean(tpl: &Tpl) -> bool {
+    tpl.quasis
+        .iter()
+        .any(|quasi| RegexPatterns::korean_text().is_match(&quasi_text(quasi)))
+}
+
+/// 기록용 템플릿 리터럴 원본 텍스트 (`` `안녕 ${name}` ``)
+fn tpl_source_text(tpl: &Tpl) -> String {
+    let mut text = String::from("`");
+    for (index, quasi) in tpl.quasis.iter().enumerate() {
+        text.push_str(&quasi.raw);
+        if let Some(expr) = tpl.exprs.get(index) {
+            text.push_str("${");
+            text.push_str(&node_code(expr.as_ref()));
+            text.push('}');
+        }
+    }
+    text.push('`');
+    text
+}
+
+/// 템플릿 조각의 실제 문자열 값 (cooked 값이 없으면 raw 사용)
+fn quasi_text(quasi: &TplElement) -> String {
+    match &quasi.cooked {
+        Some(cooked) => cooked.to_string_lossy().into_owned(),
+        None => quasi.raw.to_string(),
+    }
+}
+
+impl VisitMut for TranslationTransformer<'_> {
     /// FunctionDeclaration 변환
     /// TypeScript 버전과 동일한 로직:
     /// 1. React 컴포넌트인지 확인
     /// 2. 함수 body를 변환
     /// 3. 변환된 경우 함수 이름 저장
     fn visit_mut_fn_decl(&mut self, func: &mut FnDecl) {
+        // declare function은 선언 전용
+        if func.declare {
+            return self.visit_children_as(AncestorKind::TypeOnly, func.function.span, func);
+        }
+
         // React 컴포넌트인지 확인
         let name = func.ident.sym.to_string();
-        if crate::ast_helpers::is_react_component(&name) {
-            // 함수 body 변환 (자식 노드 방문으로 자동 처리됨)
-            let before_modified = self.was_modified;
-            func.visit_mut_children_with(self);
-            // 변환되었으면 함수 이름 저장
-            if self.was_modified && !before_modified {
-                self.modified_functions.push(name);
-            }
-            return;
+        if is_react_component(&name) {
+            // 함수 body 변환 후 변환되었으면 함수 이름 저장
+            return self.visit_component(name, func.function.span, func);
         }
         // React 컴포넌트가 아니면 자식 노드만 방문
-        func.visit_mut_children_with(self);
+        self.visit_children_as(AncestorKind::Node, func.function.span, func);
     }
 
-    /// ArrowFunctionExpression 변환
-    /// TypeScript 버전과 동일한 로직:
-    /// 1. 변수 선언의 일부인지 확인
-    /// 2. React 컴포넌트인지 확인
-    /// 3. 함수 body를 변환
-    /// 4. 변환된 경우 함수 이름 저장
+    /// `export default function () {}`, `export default function Page() {}`
+    /// 이름이 없으면 DEFAULT_EXPORT_NAME으로 기록
+    fn visit_mut_export_default_decl(&mut self, decl: &mut ExportDefaultDecl) {
+        if let DefaultDecl::Fn(fn_expr) = &decl.decl {
+            let name = fn_expr
+                .ident
+                .as_ref()
+                .map_or(StringConstants::DEFAULT_EXPORT_NAME.to_string(), |ident| ident.sym.to_string());
+            if fn_expr.ident.is_none() || is_react_component(&name) {
+                return self.visit_component(name, decl.span, decl);
+            }
+        }
+        self.visit_children_as(AncestorKind::Node, decl.span, decl);
+    }
+
+    /// `export default () => ...`, `export default memo(...)`
+    fn visit_mut_export_default_expr(&mut self, export: &mut ExportDefaultExpr) {
+        if is_component_init(&export.expr) {
+            let name = StringConstants::DEFAULT_EXPORT_NAME.to_string();
+            return self.visit_component(name, export.span, export);
+        }
+        self.visit_children_as(AncestorKind::Node, export.span, export);
+    }
+
+    /// Expression 변환
+    /// 컴포넌트로 쓰이는 ArrowFunctionExpression은 visit_mut_var_declarator에서 처리
     fn visit_mut_expr(&mut self, expr: &mut Expr) {
-        // ArrowFunctionExpression 처리
-        if let Expr::Arrow(arrow) = expr {
-            // TODO: 부모 노드 확인하여 변수 선언인지 확인
-            // 현재는 일단 모든 ArrowFunction을 처리
-            let before_modified = self.was_modified;
-            arrow.visit_mut_children_with(self);
-            // 변환되었으면 기록 (이름은 나중에 부모에서 확인)
-            if self.was_modified && !before_modified {
-                // TODO: 변수 이름 추출
+        // TemplateLiteral 변환
+        // TypeScript 버전과 동일한 로직:
+        // 1. i18n-ignore 주석이 있는 경우 스킵
+        // 2. 템플릿 리터럴의 모든 부분에 하나라도 한국어가 있는지 확인
+        // 3. 템플릿 리터럴을 i18next interpolation 형식으로 변환
+        //    예: `안녕 ${name}` → t("안녕 {{name}}", { name })
+        // 태그드 템플릿(styled`...`)은 Expr::TaggedTpl이므로 대상이 아님
+        if let Expr::Tpl(tpl) = expr {
+            // 중첩 표현식을 변환하기 전의 원본 텍스트
+            let text = tpl_has_korean(tpl).then(|| tpl_source_text(tpl));
+            if let Some(reason) = self.literal_skip_reason(tpl.span) {
+                if let Some(text) = text {
+                    self.records.push(StringRecord::skipped(tpl.span, StringKind::TemplateLiteral, text, reason));
+                }
+                return;
+            }
+            // 표현식 안의 중첩 문자열/템플릿 먼저 변환
+            self.visit_children_as(AncestorKind::Node, tpl.span, tpl);
+            if let Some((t_call, key)) = self.transform_tpl(tpl) {
+                self.record_wrapped(tpl.span, StringKind::TemplateLiteral, text.unwrap_or_default(), key);
+                self.edits.replace_code(tpl.span, node_code(&t_call));
+                *expr = t_call;
             }
             return;
         }
@@ -157,34 +589,29 @@ impl VisitMut for TranslationTransformer {
         if let Expr::Lit(Lit::Str(str_lit)) = expr {
             // Wtf8Atom을 &str로 변환하여 한국어 체크
             // 방법: to_string_lossy() 직접 사용 (최신 SWC API)
-            let str_value: &str = &str_lit.value.to_string_lossy();
-            
+            let str_value = str_lit.value.to_string_lossy().into_owned();
+            let is_korean = RegexPatterns::korean_text().is_match(&str_value);
+
+            if let Some(reason) = self.literal_skip_reason(str_lit.span) {
+                if is_korean {
+                    self.records.push(StringRecord::skipped(str_lit.span, StringKind::StringLiteral, str_value, reason));
+                }
+                return;
+            }
+
             // 한국어가 포함되어 있는지 확인
-            if RegexPatterns::korean_text().is_match(str_value) {
-                self.was_modified = true;
+            if is_korean {
+                self.record_wrapped(str_lit.span, StringKind::StringLiteral, str_value.clone(), str_value);
                 
                 // t() 함수 호출 생성
-                let t_call = Expr::Call(CallExpr {
-                    span: DUMMY_SP,
-                    callee: Callee::Expr(Box::new(Expr::Ident(Ident {
-                        span: DUMMY_SP,
-                        sym: StringConstants::TRANSLATION_FUNCTION.into(),
-                        optional: false,
-                        ctxt: Default::default(),
-                    }))),
-                    args: vec![ExprOrSpread {
-                        spread: None,
-                        expr: Box::new(Expr::Lit(Lit::Str(Str {
-                            span: str_lit.span,
-                            value: str_lit.value.clone(),
-                            raw: None,
-                        }))),
-                    }],
-                    type_args: None,
-                    ctxt: Default::default(),
+                let t_call = self.create_t_call(Str {
+                    span: str_lit.span,
+                    value: str_lit.value.clone(),
+                    raw: None,
                 });
                 
                 // 현재 Expression을 t() 호출로 교체
+                self.edits.replace_code(str_lit.span, node_code(&t_call));
                 *expr = t_call;
                 // 변환 후에는 자식 노드를 방문하지 않음 (무한 재귀 방지)
                 return;
@@ -192,7 +619,8 @@ impl VisitMut for TranslationTransformer {
         }
         
         // 재귀적으로 자식 노드 방문
-        expr.visit_mut_children_with(self);
+        let span = expr.span();
+        self.visit_children_as(AncestorKind::Node, span, expr);
     }
     
     /// StringLiteral 변환 (하위 호환성을 위해 유지)
@@ -201,25 +629,49 @@ impl VisitMut for TranslationTransformer {
         let _ = n;
     }
 
-    /// TemplateLiteral 변환
+    /// JSXAttribute 문자열 값 변환
     /// TypeScript 버전과 동일한 로직:
-    /// 1. i18n-ignore 주석이 있는 경우 스킵
-    /// 2. 이미 t()로 래핑된 경우 스킵
-    /// 3. 템플릿 리터럴의 모든 부분에 하나라도 한국어가 있는지 확인
-    /// 4. 템플릿 리터럴을 i18next interpolation 형식으로 변환
-    ///    예: `안녕 ${name}` → t(`안녕 {{name}}`, { name })
-    fn visit_mut_tpl(&mut self, n: &mut Tpl) {
-        // TODO: shouldSkipPath 및 hasIgnoreComment로 스킵 확인
-        // TODO: 이미 t()로 래핑된 경우 스킵
-        // TODO: 템플릿 리터럴의 모든 부분에 하나라도 한국어가 있는지 확인
-        // TODO: Wtf8Atom을 문자열로 변환하는 올바른 방법 찾기
-        // 현재는 소스코드에서 직접 검사
-        if RegexPatterns::korean_text().is_match(&self.source_code) {
-            self.was_modified = true;
-            // TODO: 실제로는 i18next interpolation 형식으로 변환
-            // 예: `안녕 ${name}` → t(`안녕 {{name}}`, { name })
+    /// 한국어가 포함된 속성 문자열을 JSXExpressionContainer로 감싼 t() 호출로 변환
+    ///   예: placeholder="검색어 입력" → placeholder={t("검색어 입력")}
+    /// className, id 등 화면에 보이지 않는 속성은 변환하지 않음
+    fn visit_mut_jsx_attr(&mut self, attr: &mut JSXAttr) {
+        // 속성 바로 위에 i18n-ignore 주석이 있으면 속성 전체를 스킵
+        if self.is_ignored(attr.span) {
+            self.record_ignored(attr);
+            return;
         }
-        let _ = n;
+
+        // {...} 안의 표현식은 visit_mut_expr에서 처리
+        self.visit_children_as(AncestorKind::Node, attr.span, attr);
+
+        let Some(JSXAttrValue::Str(str_lit)) = &attr.value else {
+            return;
+        };
+        let str_value = str_lit.value.to_string_lossy().into_owned();
+        if str_value.trim().is_empty() || !RegexPatterns::korean_text().is_match(&str_value) {
+            return;
+        }
+        let skip = if is_translatable_jsx_attr(&attr.name) {
+            self.literal_skip_reason(str_lit.span)
+        } else {
+            Some(SkipReason::NonTranslatableAttribute)
+        };
+        if let Some(reason) = skip {
+            self.records.push(StringRecord::skipped(str_lit.span, StringKind::JsxAttribute, str_value, reason));
+            return;
+        }
+
+        self.record_wrapped(str_lit.span, StringKind::JsxAttribute, str_value.clone(), str_value);
+        let t_call = self.create_t_call(Str {
+            span: str_lit.span,
+            value: str_lit.value.clone(),
+            raw: None,
+        });
+        self.edits.replace_code(str_lit.span, format!("{{{}}}", node_code(&t_call)));
+        attr.value = Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+            span: str_lit.span,
+            expr: JSXExpr::Expr(Box::new(t_call)),
+        }));
     }
 
     /// JSXText 변환
@@ -228,25 +680,317 @@ impl VisitMut for TranslationTransformer {
     /// 2. 빈 텍스트나 공백만 있는 경우 스킵
     /// 3. 한국어가 포함된 텍스트만 처리
     /// 4. t() 함수 호출로 감싸기
-    fn visit_mut_jsx_text(&mut self, n: &mut JSXText) {
-        // TODO: hasIgnoreComment로 스킵 확인
-        // TODO: Wtf8Atom을 문자열로 변환하는 올바른 방법 찾기
-        // 현재는 소스코드에서 직접 검사
-        if RegexPatterns::korean_text().is_match(&self.source_code) {
-            self.was_modified = true;
-            // TODO: 실제로는 JSXExpressionContainer로 감싸야 함
-            // 현재는 플래그만 설정
+    /// 
+    /// JSXText 노드 자체를 JSXExprContainer로 바꿔야 하므로
+    /// 노드 단위가 아닌 children 목록 단위로 처리한다.
+    /// `{/* i18n-ignore */}` 바로 다음 형제 노드(공백 제외)는 변환하지 않는다.
+    fn visit_mut_jsx_element_childs(&mut self, children: &mut Vec<JSXElementChild>) {
+        let mut ignore_next = false;
+        let mut index = 0;
+        while index < children.len() {
+            let child = &mut children[index];
+
+            if let JSXElementChild::JSXExprContainer(container) = child {
+                if is_jsx_ignore_container(container, self.comments()) {
+                    // {/* i18n-ignore */}만 있으면 다음 형제 노드를 스킵
+                    ignore_next = matches!(container.expr, JSXExpr::JSXEmptyExpr(_));
+                    let container = container.clone();
+                    self.record_ignored(&container);
+                    index += 1;
+                    continue;
+                }
+            }
+
+            // 주석과 대상 사이의 공백/줄바꿈은 건너뜀
+            if let JSXElementChild::JSXText(text) = child {
+                if text.value.trim().is_empty() {
+                    index += 1;
+                    continue;
+                }
+            }
+
+            if ignore_next {
+                ignore_next = false;
+                let child = child.clone();
+                self.record_ignored(&child);
+                index += 1;
+                continue;
+            }
+
+            // 중첩된 JSX 먼저 변환
+            child.visit_mut_with(self);
+
+            let replacement = match &children[index] {
+                JSXElementChild::JSXText(text) => self.transform_jsx_text(text),
+                _ => None,
+            };
+            match replacement {
+                Some(new_children) => {
+                    let count = new_children.len();
+                    children.splice(index..=index, new_children);
+                    index += count;
+                }
+                None => index += 1,
+            }
         }
-        let _ = n;
+    }
+
+    /// JSX 표현식 컨테이너 (속성 값 포함)
+    /// `{/* i18n-ignore */ "텍스트"}`처럼 컨테이너 안에 주석이 있으면 스킵
+    fn visit_mut_jsx_expr_container(&mut self, container: &mut JSXExprContainer) {
+        if is_jsx_ignore_container(container, self.comments()) {
+            self.record_ignored(container);
+            return;
+        }
+        container.visit_mut_children_with(self);
+    }
+
+    /// 함수 호출
+    /// t() 인자는 이미 래핑된 것으로, import()/require() 인자는 모듈 경로로 표시
+    fn visit_mut_call_expr(&mut self, call: &mut CallExpr) {
+        let kind = if is_translation_call(call) {
+            AncestorKind::TranslationCall
+        } else if is_module_source_call(call) {
+            AncestorKind::ModuleSource
+        } else {
+            AncestorKind::Node
+        };
+        self.visit_children_as(kind, call.span, call);
+    }
+
+    /// 객체 프로퍼티 KEY (`{ ["키"]: value }`)
+    fn visit_mut_prop_name(&mut self, name: &mut PropName) {
+        let span = name.span();
+        self.visit_children_as(AncestorKind::PropertyKey, span, name);
+    }
+
+    /// computed 멤버 접근 KEY (`obj["키"]`)
+    fn visit_mut_member_prop(&mut self, prop: &mut MemberProp) {
+        let span = prop.span();
+        self.visit_children_as(AncestorKind::PropertyKey, span, prop);
+    }
+
+    /// import 구문
+    fn visit_mut_import_decl(&mut self, import: &mut ImportDecl) {
+        self.visit_children_as(AncestorKind::ModuleSource, import.span, import);
+    }
+
+    /// `export { a } from "..."`
+    fn visit_mut_named_export(&mut self, export: &mut NamedExport) {
+        self.visit_children_as(AncestorKind::ModuleSource, export.span, export);
+    }
+
+    /// `export * from "..."`
+    fn visit_mut_export_all(&mut self, export: &mut ExportAll) {
+        self.visit_children_as(AncestorKind::ModuleSource, export.span, export);
+    }
+
+    /// TS enum 멤버 (초기값은 상수여야 하므로 t()로 바꿀 수 없음)
+    fn visit_mut_ts_enum_member(&mut self, member: &mut TsEnumMember) {
+        self.visit_children_as(AncestorKind::TypeOnly, member.span, member);
+    }
+
+    /// `declare module`, `declare global`, `namespace`
+    fn visit_mut_ts_module_decl(&mut self, decl: &mut TsModuleDecl) {
+        let kind = if decl.declare {
+            AncestorKind::TypeOnly
+        } else {
+            AncestorKind::Node
+        };
+        self.visit_children_as(kind, decl.span, decl);
+    }
+
+    /// `declare const` 등 선언 전용 변수
+    fn visit_mut_var_decl(&mut self, decl: &mut VarDecl) {
+        let kind = if decl.declare {
+            AncestorKind::TypeOnly
+        } else {
+            AncestorKind::Node
+        };
+        self.visit_children_as(kind, decl.span, decl);
+    }
+
+    /// 변수 선언자
+    /// TypeScript 버전과 동일한 로직:
+    /// 1. `const Foo = () => ...` 처럼 식별자에 함수가 바인딩되는지 확인
+    /// 2. React 컴포넌트(또는 훅) 이름인지 확인
+    /// 3. 함수 body를 변환하고 변환된 경우 변수 이름 저장
+    ///
+    /// `memo(...)`, `forwardRef(...)`로 감싼 함수도 컴포넌트로 처리
+    fn visit_mut_var_declarator(&mut self, declarator: &mut VarDeclarator) {
+        if let (Pat::Ident(binding), Some(init)) = (&declarator.name, &declarator.init) {
+            let name = binding.id.sym.to_string();
+            if is_react_component(&name) && is_component_init(init) {
+                return self.visit_component(name, declarator.span, declarator);
+            }
+        }
+        self.visit_children_as(AncestorKind::Node, declarator.span, declarator);
+    }
+
+    /// `declare class`
+    fn visit_mut_class_decl(&mut self, decl: &mut ClassDecl) {
+        let kind = if decl.declare {
+            AncestorKind::TypeOnly
+        } else {
+            AncestorKind::Node
+        };
+        self.visit_children_as(kind, decl.class.span, decl);
+    }
+
+    /// TS 타입 위치 (TsLitType 등)
+    /// 타입에는 런타임 문자열이 없으므로 방문하지 않는다
+    fn visit_mut_ts_type(&mut self, _ty: &mut TsType) {}
+
+    /// 모듈 최상위 구문 목록 ("use client" 등 디렉티브 처리)
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        self.visit_stmts_with_directives(items, ModuleItem::as_stmt);
+    }
+
+    /// 함수 body 등의 구문 목록 ("use server" 등 디렉티브 처리)
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        self.visit_stmts_with_directives(stmts, |stmt| Some(stmt));
+    }
+
+    /// 모듈 최상위 구문 (export 포함)
+    /// 바로 위에 i18n-ignore 주석이 있으면 구문 전체를 스킵
+    fn visit_mut_module_item(&mut self, item: &mut ModuleItem) {
+        if self.is_ignored(item.span()) {
+            self.record_ignored(item);
+            return;
+        }
+        item.visit_mut_children_with(self);
+    }
+
+    /// 함수 body 등의 구문
+    /// 바로 위에 i18n-ignore 주석이 있으면 구문 전체를 스킵
+    fn visit_mut_stmt(&mut self, stmt: &mut Stmt) {
+        if self.is_ignored(stmt.span()) {
+            self.record_ignored(stmt);
+            return;
+        }
+        stmt.visit_mut_children_with(self);
+    }
+
+    /// 객체 프로퍼티
+    /// 바로 위에 i18n-ignore 주석이 있으면 프로퍼티 전체를 스킵
+    fn visit_mut_prop_or_spread(&mut self, prop: &mut PropOrSpread) {
+        if self.is_ignored(prop.span()) {
+            self.record_ignored(prop);
+            return;
+        }
+        prop.visit_mut_children_with(self);
+    }
+
+    /// 클래스 멤버
+    /// 바로 위에 i18n-ignore 주석이 있으면 멤버 전체를 스킵
+    fn visit_mut_class_member(&mut self, member: &mut ClassMember) {
+        if self.is_ignored(member.span()) {
+            self.record_ignored(member);
+            return;
+        }
+        member.visit_mut_children_with(self);
+    }
+
+    /// JSX 요소 (표현식 위치)
+    /// `// i18n-ignore` 바로 다음의 JSX 요소는 통째로 스킵
+    fn visit_mut_jsx_element(&mut self, element: &mut JSXElement) {
+        if self.is_ignored(element.span) {
+            self.record_ignored(element);
+            return;
+        }
+        element.visit_mut_children_with(self);
     }
 }
 
 /// Module을 변환하고 결과 반환
 pub fn transform_module(module: &mut Module, source_code: String) -> (TransformResult, Vec<String>) {
-    let mut transformer = TranslationTransformer::new(source_code);
+    run_transformer(module, TranslationTransformer::new(source_code))
+}
+
+/// 주석 정보와 함께 Module을 변환하고 결과 반환
+/// i18n-ignore 주석이 붙은 구문, JSX 요소, 프로퍼티는 변환하지 않는다
+pub fn transform_module_with_comments(
+    module: &mut Module,
+    source_code: String,
+    comments: &dyn Comments,
+) -> (TransformResult, Vec<String>) {
+    run_transformer(module, TranslationTransformer::new(source_code).with_comments(comments))
+}
+
+/// server 모드 변환 (transform_module_with_comments + 훅 body는 변환하지 않음)
+/// 건너뛴 훅은 TransformResult::skipped_hooks에 기록
+pub fn transform_server_module_with_comments(
+    module: &mut Module,
+    source_code: String,
+    comments: &dyn Comments,
+) -> (TransformResult, Vec<String>) {
+    let transformer = TranslationTransformer::new(source_code)
+        .with_comments(comments)
+        .with_hooks_skipped();
+    run_transformer(module, transformer)
+}
+
+fn run_transformer(module: &mut Module, mut transformer: TranslationTransformer<'_>) -> (TransformResult, Vec<String>) {
     module.visit_mut_with(&mut transformer);
     (
-        TransformResult::new(transformer.was_modified),
+        TransformResult::new(transformer.was_modified)
+            .with_records(transformer.records)
+            .with_edits(transformer.edits)
+            .with_skipped_hooks(transformer.skipped_hooks),
         transformer.modified_functions,
     )
 }
+
+/// i18n-ignore 주석으로 건너뛴 노드 안의 후보 문자열을 수집하는 visitor
+/// 변환하지 않으므로 읽기 전용으로 방문한다
+pub struct IgnoredStringCollector<'a> {
+    records: &'a mut Vec<StringRecord>,
+}
+
+impl IgnoredStringCollector<'_> {
+    fn push(&mut self, span: Span, kind: StringKind, text: String) {
+        if RegexPatterns::korean_text().is_match(&text) {
+            self.records.push(StringRecord::skipped(span, kind, text, SkipReason::IgnoreComment));
+        }
+    }
+}
+
+impl Visit for IgnoredStringCollector<'_> {
+    fn visit_str(&mut self, str_lit: &Str) {
+        let text = str_lit.value.to_string_lossy().into_owned();
+        self.push(str_lit.span, StringKind::StringLiteral, text);
+    }
+
+    fn visit_jsx_attr_value(&mut self, value: &JSXAttrValue) {
+        match value {
+            JSXAttrValue::Str(str_lit) => {
+                let text = str_lit.value.to_string_lossy().into_owned();
+                self.push(str_lit.span, StringKind::JsxAttribute, text);
+            }
+            _ => value.visit_children_with(self),
+        }
+    }
+
+    fn visit_jsx_text(&mut self, text: &JSXText) {
+        let (_, body, _) = split_jsx_text(&text.value);
+        self.push(jsx_text_body_span(text), StringKind::JsxText, normalize_jsx_text(body));
+    }
+
+    fn visit_tpl(&mut self, tpl: &Tpl) {
+        if tpl_has_korean(tpl) {
+            self.push(tpl.span, StringKind::TemplateLiteral, tpl_source_text(tpl));
+        }
+        tpl.visit_children_with(self);
+    }
+
+    /// 이미 t()로 감싼 문자열과 모듈 경로는 후보가 아님
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        if !is_translation_call(call) && !is_module_source_call(call) {
+            call.visit_children_with(self);
+        }
+    }
+
+    fn visit_import_decl(&mut self, _import: &ImportDecl) {}
+
+    fn visit_ts_type(&mut self, _ty: &TsType) {}
+}
@@ -0,0 +1,119 @@
+//! 증분 실행 캐시
+//! 이전 실행에서 바뀌지 않은 파일을 기록해 두고, 입력이 같으면 파싱하지 않고 건너뛴다
+//!
+//! 항목은 파일 경로 → 내용 해시(파싱 옵션 포함)이고, 캐시 전체에 도구 버전과 설정 해시를 기록한다.
+//! 도구 버전이나 설정 해시가 다르면 모든 항목을 버린다.
+//! 변환해서 바뀐 파일은 기록하지 않는다 (다음 실행에서 바뀌지 않음을 확인한 뒤 기록).
+
+use crate::parser::ParseOptions;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// 캐시를 만든 도구 버전 (버전이 바뀌면 변환 결과가 달라질 수 있으므로 캐시를 버림)
+/// 패키지 버전 + 소스 해시(build.rs)이므로 패키지 버전을 올리지 않은 빌드끼리도 구분된다
+pub const CACHE_TOOL_VERSION: &str = concat!(env!("CARGO_PKG_VERSION"), "+", env!("T_WRAPPER_SOURCE_HASH"));
+
+/// 캐시 파일 내용
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CacheContents {
+    version: String,
+    config_hash: String,
+    /// 파일 경로 → 내용 해시
+    files: BTreeMap<String, String>,
+}
+
+/// 바뀌지 않는 파일 캐시
+#[derive(Debug)]
+pub struct TransformCache {
+    path: PathBuf,
+    contents: CacheContents,
+    dirty: bool,
+}
+
+impl TransformCache {
+    /// 캐시 파일 읽기
+    /// 파일이 없거나 읽을 수 없거나 도구 버전/설정 해시가 다르면 빈 캐시
+    pub fn load(path: impl Into<PathBuf>, config_hash: impl Into<String>) -> Self {
+        let path = path.into();
+        let config_hash = config_hash.into();
+        let files = fs::read_to_string(&path)
+            .ok()
+            .and_then(|json| serde_json::from_str::<CacheContents>(&json).ok())
+            .filter(|contents| contents.version == CACHE_TOOL_VERSION && contents.config_hash == config_hash)
+            .map(|contents| contents.files)
+            .unwrap_or_default();
+        // 버린 항목이 있으면 다음 save에서 새로 쓴다
+        let dirty = files.is_empty() && path.exists();
+        Self {
+            path,
+            contents: CacheContents {
+                version: CACHE_TOOL_VERSION.to_string(),
+                config_hash,
+                files,
+            },
+            dirty,
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn len(&self) -> usize {
+        self.contents.files.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.contents.files.is_empty()
+    }
+
+    /// 이전 실행에서 같은 내용으로 처리했고 바뀌지 않은 파일인지
+    pub fn is_unchanged(&self, file_path: &str, content_hash: &str) -> bool {
+        self.contents.files.get(file_path).is_some_and(|hash| hash == content_hash)
+    }
+
+    /// 바뀌지 않은 파일 기록
+    pub fn record_unchanged(&mut self, file_path: impl Into<String>, content_hash: impl Into<String>) {
+        let content_hash = content_hash.into();
+        let previous = self.contents.files.insert(file_path.into(), content_hash.clone());
+        self.dirty |= previous.as_ref() != Some(&content_hash);
+    }
+
+    /// 파일 기록 삭제 (변환해서 바뀐 파일)
+    pub fn remove(&mut self, file_path: &str) {
+        self.dirty |= self.contents.files.remove(file_path).is_some();
+    }
+
+    /// 바뀐 내용이 있으면 캐시 파일 저장 (디렉토리가 없으면 생성)
+    pub fn save(&mut self) -> Result<()> {
+        if !self.dirty {
+            return Ok(());
+        }
+        if let Some(parent) = self.path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&self.path, serde_json::to_string_pretty(&self.contents)?)?;
+        self.dirty = false;
+        Ok(())
+    }
+}
+
+/// 파일 내용 해시 (SHA-256, 16진수)
+/// 같은 코드라도 파싱 옵션(tsconfig의 jsx, decorators 등)이 다르면 결과가 다를 수 있으므로 함께 해시
+pub fn content_hash(code: &str, parse_options: &ParseOptions) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(format!("{:?}", parse_options).as_bytes());
+    hasher.update([0]);
+    hasher.update(code.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+/// 설정 해시 (SHA-256, 16진수)
+/// 직렬화되는 필드가 하나라도 바뀌면 해시가 달라진다
+pub fn config_hash(config: &impl Serialize) -> Result<String> {
+    Ok(format!("{:x}", Sha256::digest(serde_json::to_vec(config)?)))
+}
@@ -0,0 +1,250 @@
+//! 파일 단위 서버/클라이언트 컴포넌트 분류
+//! 디렉티브, import, 훅 사용, async default export를 AST로 확인하여
+//! config.mode가 없을 때 파일마다 client/server 모드를 선택
+
+use crate::ast_helpers::module_directives;
+use crate::constants::{RegexPatterns, StringConstants};
+use std::collections::HashSet;
+use std::fmt;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{Visit, VisitWith};
+
+/// 번역 함수 모드
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TranslationMode {
+    /// `const { t } = useTranslation();`
+    Client,
+    /// `const { t } = await getServerTranslation();`
+    Server,
+}
+
+impl TranslationMode {
+    /// ScriptConfig.mode 값 해석 ("client" | "server", 그 외는 None)
+    pub fn from_config(mode: Option<&str>) -> Option<Self> {
+        match mode {
+            Some("client") => Some(Self::Client),
+            Some("server") => Some(Self::Server),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Client => "client",
+            Self::Server => "server",
+        }
+    }
+}
+
+impl fmt::Display for TranslationMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 모드를 선택한 이유
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModeReason {
+    /// ScriptConfig.mode로 직접 지정
+    Configured,
+    /// `"use client"` 디렉티브
+    UseClientDirective,
+    /// `"use server"` 디렉티브
+    UseServerDirective,
+    /// `next/headers`, `server-only` 등 서버 전용 모듈 import
+    ServerOnlyImport(String),
+    /// `await getServerTranslation()` 호출 (별칭 포함, 함수 이름)
+    ServerTranslationCall(String),
+    /// `useState()` 등 클라이언트 훅 호출 (훅 이름)
+    ClientHook(String),
+    /// `export default async function`
+    AsyncDefaultExport,
+    /// 클라이언트 모듈이 import함 (import graph 전파, importer 경로)
+    ImportedByClient(String),
+    /// 아무 신호도 없어서 기본값 사용
+    NoSignal,
+}
+
+impl fmt::Display for ModeReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Configured => write!(f, "configured mode"),
+            Self::UseClientDirective => write!(f, "\"use client\" directive"),
+            Self::UseServerDirective => write!(f, "\"use server\" directive"),
+            Self::ServerOnlyImport(source) => write!(f, "imports \"{}\"", source),
+            Self::ServerTranslationCall(name) => write!(f, "calls {}()", name),
+            Self::ClientHook(name) => write!(f, "uses {}()", name),
+            Self::AsyncDefaultExport => write!(f, "async default export"),
+            Self::ImportedByClient(importer) => write!(f, "imported by client module {}", importer),
+            Self::NoSignal => write!(f, "no client/server signal"),
+        }
+    }
+}
+
+/// 파일 분류 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModeClassification {
+    pub mode: TranslationMode,
+    pub reason: ModeReason,
+}
+
+impl ModeClassification {
+    pub fn new(mode: TranslationMode, reason: ModeReason) -> Self {
+        Self { mode, reason }
+    }
+}
+
+/// 모듈을 client/server로 분류
+///
+/// 우선순위:
+/// 1. `"use client"` / `"use server"` 디렉티브
+/// 2. 서버 전용 모듈 import (`next/headers`, `server-only`)
+/// 3. 서버 번역 함수 호출 (`await getServerTranslation()`, import 별칭 포함)
+/// 4. 클라이언트 훅 호출 (`useState()`, `React.useEffect()` 등)
+/// 5. async default export
+/// 6. 신호가 없으면 `default_mode`
+pub fn classify_module(
+    module: &Module,
+    server_translation_function: &str,
+    default_mode: TranslationMode,
+) -> ModeClassification {
+    for directive in module_directives(module) {
+        if directive == StringConstants::USE_CLIENT_DIRECTIVE {
+            return ModeClassification::new(TranslationMode::Client, ModeReason::UseClientDirective);
+        }
+        if directive == StringConstants::USE_SERVER_DIRECTIVE {
+            return ModeClassification::new(TranslationMode::Server, ModeReason::UseServerDirective);
+        }
+    }
+
+    let mut server_fn_names = HashSet::from([server_translation_function.to_string()]);
+    for import_decl in module_imports(module) {
+        let source = import_decl.src.value.to_string_lossy();
+        if StringConstants::SERVER_ONLY_MODULES.contains(&source.as_ref()) {
+            return ModeClassification::new(
+                TranslationMode::Server,
+                ModeReason::ServerOnlyImport(source.into_owned()),
+            );
+        }
+        for spec in &import_decl.specifiers {
+            if let ImportSpecifier::Named(named) = spec {
+                if imported_name(named) == server_translation_function {
+                    server_fn_names.insert(named.local.sym.to_string());
+                }
+            }
+        }
+    }
+
+    let mut collector = CallCollector {
+        server_fn_names: &server_fn_names,
+        server_call: None,
+        client_hook: None,
+    };
+    module.visit_with(&mut collector);
+    if let Some(name) = collector.server_call {
+        return ModeClassification::new(TranslationMode::Server, ModeReason::ServerTranslationCall(name));
+    }
+    if let Some(name) = collector.client_hook {
+        return ModeClassification::new(TranslationMode::Client, ModeReason::ClientHook(name));
+    }
+
+    if has_async_default_export(module) {
+        return ModeClassification::new(TranslationMode::Server, ModeReason::AsyncDefaultExport);
+    }
+
+    ModeClassification::new(default_mode, ModeReason::NoSignal)
+}
+
+fn module_imports(module: &Module) -> impl Iterator<Item = &ImportDecl> {
+    module.body.iter().filter_map(|item| match item {
+        ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) if !import_decl.type_only => {
+            Some(import_decl)
+        }
+        _ => None,
+    })
+}
+
+fn imported_name(named: &ImportNamedSpecifier) -> String {
+    match &named.imported {
+        Some(ModuleExportName::Ident(ident)) => ident.sym.to_string(),
+        Some(ModuleExportName::Str(str_lit)) => str_lit.value.to_string_lossy().into_owned(),
+        None => named.local.sym.to_string(),
+    }
+}
+
+/// 서버 번역 함수 호출과 클라이언트 훅 호출을 수집 (처음 발견한 것만)
+struct CallCollector<'a> {
+    server_fn_names: &'a HashSet<String>,
+    server_call: Option<String>,
+    client_hook: Option<String>,
+}
+
+impl Visit for CallCollector<'_> {
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        if let Callee::Expr(callee) = &call.callee {
+            let name = match callee.as_ref() {
+                Expr::Ident(ident) => Some(ident.sym.to_string()),
+                // React.useState()
+                Expr::Member(MemberExpr {
+                    obj,
+                    prop: MemberProp::Ident(prop),
+                    ..
+                }) if matches!(obj.as_ref(), Expr::Ident(obj) if obj.sym == StringConstants::REACT_NAMESPACE) => {
+                    Some(prop.sym.to_string())
+                }
+                _ => None,
+            };
+            if let Some(name) = name {
+                if self.server_fn_names.contains(&name) {
+                    self.server_call.get_or_insert(name);
+                } else if RegexPatterns::react_hook().is_match(&name) {
+                    self.client_hook.get_or_insert(name);
+                }
+            }
+        }
+        call.visit_children_with(self);
+    }
+}
+
+/// `export default async function`, `export default async () => ...`,
+/// `const Page = async () => ...; export default Page;` 확인
+fn has_async_default_export(module: &Module) -> bool {
+    module.body.iter().any(|item| match item {
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(decl)) => {
+            matches!(&decl.decl, DefaultDecl::Fn(fn_expr) if fn_expr.function.is_async)
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(export)) => match export.expr.as_ref() {
+            Expr::Ident(ident) => is_async_binding(module, &ident.sym),
+            expr => is_async_fn_expr(expr),
+        },
+        _ => false,
+    })
+}
+
+fn is_async_fn_expr(expr: &Expr) -> bool {
+    match expr {
+        Expr::Arrow(arrow) => arrow.is_async,
+        Expr::Fn(fn_expr) => fn_expr.function.is_async,
+        Expr::Paren(ParenExpr { expr, .. }) => is_async_fn_expr(expr),
+        _ => false,
+    }
+}
+
+/// 최상위에 선언된 `name`이 async 함수인지 확인
+fn is_async_binding(module: &Module, name: &str) -> bool {
+    module.body.iter().any(|item| {
+        let decl = match item {
+            ModuleItem::Stmt(Stmt::Decl(decl)) => decl,
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => &export.decl,
+            _ => return false,
+        };
+        match decl {
+            Decl::Fn(func) => func.ident.sym == name && func.function.is_async,
+            Decl::Var(var) => var.decls.iter().any(|declarator| {
+                matches!(&declarator.name, Pat::Ident(binding) if binding.id.sym == name)
+                    && declarator.init.as_deref().is_some_and(is_async_fn_expr)
+            }),
+            _ => false,
+        }
+    })
+}
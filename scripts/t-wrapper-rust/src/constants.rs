@@ -1,5 +1,5 @@
-/// t-wrapper 상수 정의
-/// 모든 상수를 중앙화
+//! t-wrapper 상수 정의
+//! 모든 상수를 중앙화
 
 use std::sync::LazyLock;
 use regex::Regex;
@@ -13,6 +13,63 @@ impl ConsoleMessages {
     }
 
     pub const FATAL_ERROR: &'static str = "❌ Fatal error:";
+
+    /// config.mode 없이 파일별로 모드를 선택한 경우 선택 이유 출력
+    pub fn mode_selected(file_path: &str, mode: &str, reason: &str) -> String {
+        format!("🔎 {} → {} ({})", file_path, mode, reason)
+    }
+
+    pub fn unknown_report_format(format: &str) -> String {
+        format!("Unknown report format: {} (supported: json)", format)
+    }
+
+    /// --jobs 값이 1 이상의 정수가 아닌 경우
+    pub fn invalid_jobs(jobs: &str) -> String {
+        format!("Invalid --jobs value: {} (expected a positive integer)", jobs)
+    }
+
+    /// --max-file-size 값이 정수가 아닌 경우
+    pub fn invalid_max_file_size(size: &str) -> String {
+        format!("Invalid --max-file-size value: {} (expected a number of bytes)", size)
+    }
+
+    /// 증분 실행 캐시를 저장하지 못한 경우 (경고만 출력)
+    pub fn cache_write_failed(cache_path: &str, error: &anyhow::Error) -> String {
+        format!("⚠️  Failed to write cache {}: {}", cache_path, error)
+    }
+
+    /// server 모드에서 한국어 문자열이 있는 훅을 변환하지 않은 경우
+    pub fn server_hook_skipped(file_path: &str, hook_name: &str) -> String {
+        format!(
+            "⚠️  Skipped hook {} in {}: server mode would make it async (move the strings into a component or use client mode)",
+            hook_name, file_path
+        )
+    }
+
+    /// tsconfig.json의 `extends` 대상을 찾지 못한 경우 (경고만 출력하고 건너뜀)
+    pub fn tsconfig_extends_not_found(extends: &str, tsconfig_path: &str) -> String {
+        format!("⚠️  Cannot resolve tsconfig extends \"{}\" in {}", extends, tsconfig_path)
+    }
+
+    /// 감시 모드 시작
+    pub fn watch_started(dirs: &[String]) -> String {
+        format!("👀 Watching {} for changes (Ctrl+C to stop)", dirs.join(", "))
+    }
+
+    /// 감시 모드에서 파일을 변환해 저장한 경우
+    pub fn watch_updated(file_path: &str) -> String {
+        format!("✏️  Updated {}", file_path)
+    }
+
+    /// Chrome trace 파일을 저장한 경우
+    pub fn trace_written(trace_path: &str) -> String {
+        format!("🧭 Trace written to {} (open in chrome://tracing or ui.perfetto.dev)", trace_path)
+    }
+
+    /// dry-run diff를 patch 파일로 저장한 경우
+    pub fn patch_written(patch_path: &str) -> String {
+        format!("📝 Patch written to {}", patch_path)
+    }
 }
 
 /// CLI 옵션
@@ -23,23 +80,65 @@ impl CliOptions {
     pub const PATTERN_SHORT: &'static str = "-p";
     pub const DRY_RUN: &'static str = "--dry-run";
     pub const DRY_RUN_SHORT: &'static str = "-d";
+    pub const EXCLUDE: &'static str = "--exclude";
+    pub const EXCLUDE_SHORT: &'static str = "-e";
+    pub const GITIGNORE: &'static str = "--gitignore";
+    pub const PATCH_OUT: &'static str = "--patch-out";
+    pub const REPORT: &'static str = "--report";
+    pub const TRACE: &'static str = "--trace";
+    pub const MINIMAL_DIFF: &'static str = "--minimal-diff";
+    pub const JOBS: &'static str = "--jobs";
+    pub const MAX_FILE_SIZE: &'static str = "--max-file-size";
+    pub const NO_CACHE: &'static str = "--no-cache";
+    pub const WATCH: &'static str = "--watch";
+    pub const WATCH_SHORT: &'static str = "-w";
+    pub const JOBS_SHORT: &'static str = "-j";
     pub const HELP: &'static str = "--help";
     pub const HELP_SHORT: &'static str = "-h";
 }
 
+/// 리포트 형식 (`--report <format>`)
+pub struct ReportFormat;
+
+impl ReportFormat {
+    pub const JSON: &'static str = "json";
+
+    pub fn is_supported(format: &str) -> bool {
+        format == Self::JSON
+    }
+}
+
 /// CLI Help 메시지
 pub struct CliHelp;
 
 impl CliHelp {
     pub const USAGE: &'static str = "Usage: t-wrapper [options]";
     pub const OPTIONS: &'static str = "Options:
-  -p, --pattern <pattern>    Source file pattern (default: \"src/**/*.{js,jsx,ts,tsx}\")
-  -d, --dry-run             Preview changes without modifying files
+  -p, --pattern <pattern>    Source file pattern, repeatable (default: \"src/**/*.{js,jsx,ts,tsx}\")
+  -e, --exclude <pattern>    Exclude file pattern, repeatable (e.g. \"**/*.test.tsx\")
+      --gitignore           Skip files ignored by .gitignore
+  -d, --dry-run             Preview changes as a unified diff without modifying files
+      --patch-out <file>    Write the dry-run diff to a patch file (implies --dry-run)
+      --report json         Print a JSON report of every Korean string (wrapped or skipped)
+      --trace <file>        Write a Chrome trace-event JSON of the performance spans
+      --minimal-diff        Patch only the changed ranges, keeping the rest byte-for-byte
+  -j, --jobs <n>            Number of files processed in parallel (default: CPU cores)
+      --max-file-size <n>   Skip files larger than n bytes without parsing (default: 1048576, 0: no limit)
+      --no-cache            Process every file, ignoring the incremental cache (.i18nexus/cache.json)
+  -w, --watch               Keep running and re-wrap source files whenever they are saved
   -h, --help                Show this help message";
     pub const EXAMPLES: &'static str = "Examples:
   t-wrapper
   t-wrapper -p \"app/**/*.tsx\"
-  t-wrapper --dry-run";
+  t-wrapper -p \"app/**/*.tsx\" -p \"components/**/*.tsx\" -e \"**/*.{test,stories}.tsx\"
+  t-wrapper --dry-run
+  t-wrapper --patch-out i18n.patch
+  t-wrapper --minimal-diff
+  t-wrapper --jobs 4
+  t-wrapper --no-cache
+  t-wrapper --watch -p \"app/**/*.tsx\"
+  t-wrapper --dry-run --report json > i18n-report.json
+  t-wrapper --trace trace.json";
 }
 
 /// 문자열 상수
@@ -53,6 +152,24 @@ impl StringConstants {
     pub const TRANSLATION_FUNCTION: &'static str = "t";
     pub const USE_TRANSLATION: &'static str = "useTranslation";
     pub const GET_SERVER_TRANSLATION: &'static str = "getServerTranslation";
+    pub const REQUIRE: &'static str = "require";
+    pub const USE_CLIENT_DIRECTIVE: &'static str = "use client";
+    pub const USE_SERVER_DIRECTIVE: &'static str = "use server";
+    pub const TSCONFIG_FILE: &'static str = "tsconfig.json";
+    pub const GITIGNORE_FILE: &'static str = ".gitignore";
+    /// tsconfig `extends`에 패키지 이름을 쓴 경우 찾는 디렉토리
+    pub const NODE_MODULES_DIR: &'static str = "node_modules";
+    /// 증분 실행 캐시 파일 기본 경로
+    pub const CACHE_FILE: &'static str = ".i18nexus/cache.json";
+    /// 패턴과 상관없이 항상 건너뛰는 디렉토리
+    pub const DEFAULT_SKIP_DIRS: &'static [&'static str] = &["node_modules", ".next", "dist"];
+    /// import하면 서버 컴포넌트로 확정되는 모듈
+    pub const SERVER_ONLY_MODULES: &'static [&'static str] = &["server-only", "next/headers"];
+    pub const REACT_NAMESPACE: &'static str = "React";
+    /// 컴포넌트를 감싸는 HOC (`memo(...)`, `React.forwardRef(...)`)
+    pub const COMPONENT_WRAPPERS: &'static [&'static str] = &["memo", "forwardRef"];
+    /// 이름 없는 `export default function`의 기록용 이름
+    pub const DEFAULT_EXPORT_NAME: &'static str = "default";
     pub const COMPLETION_TITLE: &'static str = "Translation Wrapper Completed";
     pub const DEFAULT_ENV: &'static str = "production";
     pub const VARIABLE_KIND: &'static str = "const";
@@ -62,6 +179,87 @@ impl StringConstants {
     pub const MEMBER_SEPARATOR: &'static str = "_";
 }
 
+/// 환경 변수 이름
+pub struct EnvVars;
+
+impl EnvVars {
+    /// "false"면 성능 측정 비활성화
+    pub const PERF_MONITOR: &'static str = "I18N_PERF_MONITOR";
+    /// "true"면 완료 리포트 뒤에 상세 성능 리포트 출력
+    pub const PERF_VERBOSE: &'static str = "I18N_PERF_VERBOSE";
+    /// "true"면 측정 시작/종료를 모두 출력
+    pub const PERF_DEBUG: &'static str = "I18N_PERF_DEBUG";
+}
+
+/// 성능 측정 이름
+pub struct PerformanceNames;
+
+impl PerformanceNames {
+    pub const TOTAL: &'static str = "translation_wrapper:total";
+    pub const FILE_PROCESSING: &'static str = "file_processing";
+    pub const GLOB: &'static str = "glob";
+    pub const READ: &'static str = "read";
+    pub const PARSE: &'static str = "parse";
+    pub const TRANSFORM: &'static str = "transform";
+    pub const CODEGEN: &'static str = "codegen";
+    pub const WRITE: &'static str = "write";
+    /// 완료 리포트에 합계를 보여주는 단계 (실행 순서)
+    pub const PHASES: &'static [&'static str] = &[
+        Self::GLOB,
+        Self::READ,
+        Self::PARSE,
+        Self::TRANSFORM,
+        Self::CODEGEN,
+        Self::WRITE,
+    ];
+    /// 메타데이터의 파일 경로 키
+    pub const FILE_PATH: &'static str = "file_path";
+}
+
+/// 파싱 전 사전 필터 기준값
+pub struct PrefilterLimits;
+
+impl PrefilterLimits {
+    /// 기본 최대 파일 크기 (1MiB, 이보다 크면 손으로 쓴 소스가 아니라고 보고 건너뜀)
+    pub const DEFAULT_MAX_FILE_SIZE: u64 = 1024 * 1024;
+    /// `@generated`, `/* eslint-disable */`를 찾는 파일 앞부분 길이 (바이트)
+    pub const HEADER_LENGTH: usize = 2048;
+    /// 이보다 긴 줄이 있고
+    pub const MINIFIED_LONGEST_LINE: usize = 1000;
+    /// 평균 줄 길이도 이보다 길면 minified 파일로 판단
+    pub const MINIFIED_AVERAGE_LINE: usize = 200;
+}
+
+/// 감시 모드 설정
+pub struct WatchSettings;
+
+impl WatchSettings {
+    /// 마지막 이벤트 후 이 시간(ms) 동안 이벤트가 없으면 모아 둔 파일을 처리
+    /// (저장 한 번에 여러 이벤트가 오거나 여러 파일을 한 번에 저장하는 경우를 묶음)
+    pub const DEBOUNCE_MS: u64 = 200;
+}
+
+/// JSX 속성 이름
+pub struct JsxAttributes;
+
+impl JsxAttributes {
+    /// 화면에 표시되지 않아 번역하지 않는 속성
+    /// (placeholder, title, alt, aria-label 등 나머지 속성은 번역 대상)
+    pub const NON_TRANSLATABLE: &'static [&'static str] = &[
+        "className",
+        "class",
+        "id",
+        "key",
+        "htmlFor",
+        "name",
+        "type",
+        "href",
+        "src",
+        "style",
+        "data-testid",
+    ];
+}
+
 /// 정규식 패턴
 pub struct RegexPatterns;
 
@@ -87,12 +285,12 @@ impl RegexPatterns {
         &KOREAN_TEXT
     }
 
-    pub fn server_component() -> &'static Regex {
-        static SERVER_COMPONENT: LazyLock<Regex> = LazyLock::new(|| {
-            // await getServerTranslation() 패턴 확인
-            Regex::new(r"await\s+getServerTranslation\s*\(\)").unwrap()
+    /// 규칙 목록 없이 파일 전체를 끄는 `/* eslint-disable */` (번들/벤더 파일 표시)
+    pub fn eslint_disable_banner() -> &'static Regex {
+        static ESLINT_DISABLE_BANNER: LazyLock<Regex> = LazyLock::new(|| {
+            Regex::new(r"/\*\s*eslint-disable\s*\*/").unwrap()
         });
-        &SERVER_COMPONENT
+        &ESLINT_DISABLE_BANNER
     }
 }
 
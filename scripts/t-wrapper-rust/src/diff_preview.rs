@@ -0,0 +1,124 @@
+//! dry-run 미리보기
+//! 변환 전후 코드를 unified diff로 만들고, 변경 요약을 출력
+
+use similar::TextDiff;
+use std::io::IsTerminal;
+use std::path::Path;
+
+/// diff 앞뒤로 보여줄 변경되지 않은 줄 수 (git diff 기본값과 같음)
+const CONTEXT_LINES: usize = 3;
+
+const ANSI_RESET: &str = "\x1b[0m";
+const ANSI_BOLD: &str = "\x1b[1m";
+const ANSI_RED: &str = "\x1b[31m";
+const ANSI_GREEN: &str = "\x1b[32m";
+const ANSI_CYAN: &str = "\x1b[36m";
+
+/// diff 헤더에 쓸 경로
+/// 현재 디렉토리 기준 상대 경로 (`git apply`로 바로 적용할 수 있도록)
+pub fn diff_path_label(path: &Path) -> String {
+    let relative = std::env::current_dir()
+        .ok()
+        .and_then(|cwd| path.strip_prefix(cwd).ok().map(Path::to_path_buf))
+        .unwrap_or_else(|| path.to_path_buf());
+    relative
+        .to_string_lossy()
+        .replace('\\', "/")
+        .trim_start_matches('/')
+        .to_string()
+}
+
+/// git 형식 unified diff 생성 (`--- a/path`, `+++ b/path`)
+/// 변경이 없으면 빈 문자열
+pub fn unified_diff(path_label: &str, original: &str, modified: &str) -> String {
+    if original == modified {
+        return String::new();
+    }
+    let diff = TextDiff::from_lines(original, modified);
+    let mut unified = diff.unified_diff();
+    unified
+        .context_radius(CONTEXT_LINES)
+        .header(&format!("a/{}", path_label), &format!("b/{}", path_label));
+    format!("diff --git a/{0} b/{0}\n{1}", path_label, unified)
+}
+
+/// 터미널 출력용 색상 적용
+/// 헤더는 굵게, hunk 헤더는 cyan, 삭제 줄은 빨강, 추가 줄은 초록
+pub fn colorize_diff(diff: &str) -> String {
+    let mut output = String::with_capacity(diff.len() * 2);
+    for line in diff.split_inclusive('\n') {
+        let color = if line.starts_with("diff ") || line.starts_with("--- ") || line.starts_with("+++ ") {
+            ANSI_BOLD
+        } else if line.starts_with("@@") {
+            ANSI_CYAN
+        } else if line.starts_with('-') {
+            ANSI_RED
+        } else if line.starts_with('+') {
+            ANSI_GREEN
+        } else {
+            output.push_str(line);
+            continue;
+        };
+        let (content, newline) = match line.strip_suffix('\n') {
+            Some(content) => (content, "\n"),
+            None => (line, ""),
+        };
+        output.push_str(color);
+        output.push_str(content);
+        output.push_str(ANSI_RESET);
+        output.push_str(newline);
+    }
+    output
+}
+
+/// 표준 출력이 터미널이고 NO_COLOR가 설정되지 않았으면 색상 사용
+pub fn should_colorize() -> bool {
+    std::env::var_os("NO_COLOR").is_none() && std::io::stdout().is_terminal()
+}
+
+/// dry-run 결과 요약
+#[derive(Debug, Clone, Default)]
+pub struct DryRunSummary {
+    /// 처리 대상 파일 수
+    pub files_processed: usize,
+    /// 변경될 파일과 파일별 t()로 감싼 문자열 개수
+    pub modified_files: Vec<(String, usize)>,
+}
+
+impl DryRunSummary {
+    pub fn new(files_processed: usize) -> Self {
+        Self {
+            files_processed,
+            modified_files: Vec::new(),
+        }
+    }
+
+    pub fn add_modified_file(&mut self, path: impl Into<String>, wrapped_count: usize) {
+        self.modified_files.push((path.into(), wrapped_count));
+    }
+
+    pub fn wrapped_count(&self) -> usize {
+        self.modified_files.iter().map(|(_, count)| count).sum()
+    }
+
+    /// 요약 텍스트
+    /// ```text
+    /// [DRY RUN] Summary:
+    ///   - Files processed: 3
+    ///   - Files would be modified: 1
+    ///   - Strings wrapped: 2
+    ///     src/App.tsx (2)
+    /// ```
+    pub fn render(&self) -> String {
+        let mut output = format!(
+            "[DRY RUN] Summary:\n  - Files processed: {}\n  - Files would be modified: {}\n  - Strings wrapped: {}\n",
+            self.files_processed,
+            self.modified_files.len(),
+            self.wrapped_count()
+        );
+        for (path, count) in &self.modified_files {
+            output.push_str(&format!("    {} ({})\n", path, count));
+        }
+        output
+    }
+}
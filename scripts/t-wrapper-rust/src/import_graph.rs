@@ -0,0 +1,433 @@
+//! Import graph와 클라이언트 경계 전파
+//! Next.js App Router에서는 "use client"가 없는 컴포넌트도
+//! 클라이언트 모듈이 import하면 클라이언트 컴포넌트가 된다
+
+use crate::classifier::{ModeClassification, ModeReason, TranslationMode};
+use crate::constants::{ConsoleMessages, StringConstants};
+use anyhow::{bail, Context, Result};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{Visit, VisitWith};
+
+/// import 경로 해석 시 시도하는 확장자 (순서대로)
+const RESOLVE_EXTENSIONS: &[&str] = &["tsx", "ts", "jsx", "js", "mjs", "cjs"];
+
+/// tsconfig.json의 `compilerOptions.paths` 별칭
+#[derive(Debug, Clone, Default)]
+pub struct TsConfigPaths {
+    /// 별칭 대상 경로의 기준 디렉토리 (baseUrl, 없으면 tsconfig.json 위치)
+    base_dir: PathBuf,
+    /// (`"@/*"`, [`"./src/*"`]) 목록
+    paths: Vec<(String, Vec<String>)>,
+}
+
+impl TsConfigPaths {
+    /// tsconfig.json 읽기 (`extends`로 이어진 상위 설정 포함)
+    /// 주석과 trailing comma(JSONC)를 허용한다
+    pub fn load(tsconfig_path: &Path) -> Result<Self> {
+        Ok(Self::from_chain(&TsConfigChain::load(tsconfig_path)?))
+    }
+
+    /// 읽은 설정에서 별칭 추출
+    ///
+    /// TypeScript와 같이 `baseUrl`, `paths`는 각각 마지막으로 지정한 설정의 값을 쓰고,
+    /// 그 설정 파일의 위치를 기준으로 해석한다.
+    /// 대상 경로는 `baseUrl`이 있으면 그 기준, 없으면 `paths`를 지정한 설정 파일 위치 기준
+    pub fn from_chain(chain: &TsConfigChain) -> Self {
+        let base_url = chain
+            .compiler_option("baseUrl")
+            .and_then(|(config_dir, base_url)| Some(normalize_path(&config_dir.join(base_url.as_str()?))));
+        let paths = chain
+            .compiler_option("paths")
+            .and_then(|(config_dir, paths)| Some((config_dir, paths.as_object()?)));
+        let base_dir = base_url
+            .or_else(|| paths.map(|(config_dir, _)| config_dir.to_path_buf()))
+            .unwrap_or_else(|| chain.dir().to_path_buf());
+        let paths = paths
+            .map(|(_, paths)| {
+                paths
+                    .iter()
+                    .map(|(alias, targets)| {
+                        let targets = targets
+                            .as_array()
+                            .map(|targets| {
+                                targets.iter().filter_map(|t| t.as_str().map(str::to_string)).collect()
+                            })
+                            .unwrap_or_default();
+                        (alias.clone(), targets)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self { base_dir, paths }
+    }
+
+    /// `start_dir`에서 위로 올라가며 가장 가까운 tsconfig.json 찾기
+    pub fn find(start_dir: &Path) -> Option<PathBuf> {
+        start_dir
+            .ancestors()
+            .map(|dir| dir.join(StringConstants::TSCONFIG_FILE))
+            .find(|path| path.is_file())
+    }
+
+    /// 별칭을 후보 경로 목록으로 변환
+    /// `"@/*": ["./src/*"]`이면 `@/components/Button` → `<base>/src/components/Button`
+    pub fn resolve_alias(&self, specifier: &str) -> Vec<PathBuf> {
+        let mut candidates = Vec::new();
+        for (alias, targets) in &self.paths {
+            let captured = match alias.split_once('*') {
+                Some((prefix, suffix)) => specifier
+                    .strip_prefix(prefix)
+                    .and_then(|rest| rest.strip_suffix(suffix)),
+                None => (alias == specifier).then_some(""),
+            };
+            let Some(captured) = captured else {
+                continue;
+            };
+            for target in targets {
+                let target = target.replacen('*', captured, 1);
+                candidates.push(normalize_path(&self.base_dir.join(target)));
+            }
+        }
+        candidates
+    }
+}
+
+/// `extends`를 따라 읽은 tsconfig.json 목록
+/// 가장 먼 상위 설정부터 시작 파일 순서 (뒤의 설정이 앞의 설정을 덮어씀)
+#[derive(Debug, Clone, Default)]
+pub struct TsConfigChain {
+    /// (설정 파일이 있는 디렉토리, 내용)
+    configs: Vec<(PathBuf, Value)>,
+}
+
+impl TsConfigChain {
+    /// tsconfig.json과 `extends`(문자열 또는 배열)로 이어진 설정을 모두 읽기
+    ///
+    /// - `./base.json`, `../tsconfig.base`처럼 상대 경로는 설정 파일 위치 기준 (`.json` 생략 가능)
+    /// - `@tsconfig/next`처럼 패키지 이름은 위쪽 node_modules에서 찾음
+    /// - 찾지 못한 `extends`는 경고만 출력하고 건너뜀, 순환 참조는 에러
+    pub fn load(tsconfig_path: &Path) -> Result<Self> {
+        let mut chain = Self::default();
+        chain.push_with_parents(&normalize_path(tsconfig_path), &mut Vec::new())?;
+        Ok(chain)
+    }
+
+    fn push_with_parents(&mut self, tsconfig_path: &Path, stack: &mut Vec<PathBuf>) -> Result<()> {
+        if stack.iter().any(|visiting| visiting == tsconfig_path) {
+            bail!("Circular tsconfig extends: {}", tsconfig_path.display());
+        }
+        let content = fs::read_to_string(tsconfig_path)
+            .with_context(|| format!("Failed to read {}", tsconfig_path.display()))?;
+        let json: Value = serde_json::from_str(&strip_jsonc(&content))
+            .with_context(|| format!("Failed to parse {}", tsconfig_path.display()))?;
+        let config_dir = tsconfig_path.parent().unwrap_or(Path::new("")).to_path_buf();
+
+        let extends: Vec<&str> = match &json["extends"] {
+            Value::String(extends) => vec![extends.as_str()],
+            Value::Array(extends) => extends.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        };
+        stack.push(tsconfig_path.to_path_buf());
+        for extends in extends {
+            match resolve_tsconfig_extends(&config_dir, extends) {
+                Some(parent_path) => self.push_with_parents(&parent_path, stack)?,
+                None => eprintln!(
+                    "{}",
+                    ConsoleMessages::tsconfig_extends_not_found(extends, &tsconfig_path.display().to_string())
+                ),
+            }
+        }
+        stack.pop();
+
+        self.configs.push((config_dir, json));
+        Ok(())
+    }
+
+    /// 시작 설정 파일이 있는 디렉토리
+    pub fn dir(&self) -> &Path {
+        self.configs.last().map_or(Path::new(""), |(config_dir, _)| config_dir)
+    }
+
+    /// 마지막으로 지정한 `compilerOptions.<key>` 값과 그 설정 파일의 디렉토리
+    pub fn compiler_option(&self, key: &str) -> Option<(&Path, &Value)> {
+        self.configs.iter().rev().find_map(|(config_dir, json)| {
+            let value = json.get("compilerOptions")?.get(key)?;
+            (!value.is_null()).then_some((config_dir.as_path(), value))
+        })
+    }
+}
+
+/// `extends` 값을 설정 파일 경로로 변환
+fn resolve_tsconfig_extends(config_dir: &Path, extends: &str) -> Option<PathBuf> {
+    let existing_config = |path: PathBuf| {
+        let mut with_json = path.clone().into_os_string();
+        with_json.push(".json");
+        [path.clone(), PathBuf::from(with_json), path.join(StringConstants::TSCONFIG_FILE)]
+            .into_iter()
+            .find(|candidate| candidate.is_file())
+            .map(|candidate| normalize_path(&candidate))
+    };
+    if extends.starts_with('.') || Path::new(extends).is_absolute() {
+        return existing_config(config_dir.join(extends));
+    }
+    config_dir
+        .ancestors()
+        .find_map(|dir| existing_config(dir.join(StringConstants::NODE_MODULES_DIR).join(extends)))
+}
+
+/// 파일 간 import 관계
+#[derive(Debug, Default)]
+pub struct ImportGraph {
+    /// importer → 처리 대상 파일 중에서 해석된 import 목록
+    edges: BTreeMap<PathBuf, Vec<PathBuf>>,
+}
+
+impl ImportGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 모듈의 import를 해석하여 간선 추가
+    /// `files`(처리 대상 파일)에 포함된 경로만 간선으로 남긴다
+    pub fn add_module(
+        &mut self,
+        path: &Path,
+        module: &Module,
+        files: &HashSet<PathBuf>,
+        tsconfig: Option<&TsConfigPaths>,
+    ) {
+        let importer = normalize_path(path);
+        let imports: BTreeSet<PathBuf> = module_specifiers(module)
+            .iter()
+            .filter_map(|specifier| resolve_import(&importer, specifier, files, tsconfig))
+            .collect();
+        self.edges.insert(importer, imports.into_iter().collect());
+    }
+
+    /// `path`가 import하는 파일 목록
+    pub fn imports_of(&self, path: &Path) -> &[PathBuf] {
+        self.edges
+            .get(&normalize_path(path))
+            .map_or(&[], |imports| imports.as_slice())
+    }
+
+    /// 클라이언트 경계 전파
+    ///
+    /// 클라이언트 모듈("use client", 훅 사용)에서 시작해 import를 따라가며,
+    /// 스스로 아무 신호도 없는(NoSignal) 파일을 클라이언트로 바꾼다.
+    /// 도달하지 못한 파일은 자기 분류(서버 엔트리 기준 기본값)를 유지한다.
+    /// `"use server"` 모듈은 서버 액션 참조로만 번들되므로 따라가지 않는다.
+    pub fn propagate_client_boundaries(&self, classifications: &mut HashMap<PathBuf, ModeClassification>) {
+        let mut roots: Vec<&PathBuf> = classifications
+            .iter()
+            .filter(|(_, classification)| classification.mode == TranslationMode::Client)
+            .map(|(path, _)| path)
+            .collect();
+        roots.sort();
+
+        let mut queue: VecDeque<PathBuf> = roots.into_iter().cloned().collect();
+        let mut visited: HashSet<PathBuf> = queue.iter().cloned().collect();
+
+        while let Some(importer) = queue.pop_front() {
+            for imported in self.imports_of(&importer) {
+                if !visited.insert(imported.clone()) {
+                    continue;
+                }
+                let Some(classification) = classifications.get_mut(imported) else {
+                    continue;
+                };
+                match classification.reason {
+                    ModeReason::UseServerDirective => continue,
+                    ModeReason::NoSignal => {
+                        *classification = ModeClassification::new(
+                            TranslationMode::Client,
+                            ModeReason::ImportedByClient(importer.to_string_lossy().into_owned()),
+                        );
+                    }
+                    _ => {}
+                }
+                queue.push_back(imported.clone());
+            }
+        }
+    }
+}
+
+/// 모듈이 참조하는 모듈 경로 목록
+/// import/export from, `import("...")`, `require("...")` (import type 제외)
+pub fn module_specifiers(module: &Module) -> Vec<String> {
+    struct SpecifierCollector {
+        specifiers: Vec<String>,
+    }
+
+    impl Visit for SpecifierCollector {
+        fn visit_import_decl(&mut self, import_decl: &ImportDecl) {
+            if !import_decl.type_only {
+                self.specifiers.push(import_decl.src.value.to_string_lossy().into_owned());
+            }
+        }
+
+        fn visit_named_export(&mut self, export: &NamedExport) {
+            if let Some(src) = &export.src {
+                if !export.type_only {
+                    self.specifiers.push(src.value.to_string_lossy().into_owned());
+                }
+            }
+        }
+
+        fn visit_export_all(&mut self, export: &ExportAll) {
+            if !export.type_only {
+                self.specifiers.push(export.src.value.to_string_lossy().into_owned());
+            }
+        }
+
+        fn visit_call_expr(&mut self, call: &CallExpr) {
+            let is_module_call = match &call.callee {
+                Callee::Import(_) => true,
+                Callee::Expr(callee) => {
+                    matches!(callee.as_ref(), Expr::Ident(ident) if ident.sym == StringConstants::REQUIRE)
+                }
+                Callee::Super(_) => false,
+            };
+            if is_module_call {
+                if let Some(Expr::Lit(Lit::Str(str_lit))) = call.args.first().map(|arg| arg.expr.as_ref()) {
+                    self.specifiers.push(str_lit.value.to_string_lossy().into_owned());
+                }
+            }
+            call.visit_children_with(self);
+        }
+    }
+
+    let mut collector = SpecifierCollector { specifiers: Vec::new() };
+    module.visit_with(&mut collector);
+    collector.specifiers
+}
+
+/// import 경로를 처리 대상 파일 경로로 해석
+///
+/// - 상대 경로(`./`, `../`): importer 디렉토리 기준
+/// - 그 외: tsconfig `paths` 별칭 (패키지 import는 None)
+///
+/// 확장자 생략(`./Button` → `./Button.tsx`)과 디렉토리 index(`./ui` → `./ui/index.ts`)를 지원
+pub fn resolve_import(
+    importer: &Path,
+    specifier: &str,
+    files: &HashSet<PathBuf>,
+    tsconfig: Option<&TsConfigPaths>,
+) -> Option<PathBuf> {
+    let candidates = if specifier.starts_with("./") || specifier.starts_with("../") {
+        let dir = importer.parent().unwrap_or(Path::new(""));
+        vec![normalize_path(&dir.join(specifier))]
+    } else {
+        tsconfig.map(|tsconfig| tsconfig.resolve_alias(specifier)).unwrap_or_default()
+    };
+
+    candidates.iter().find_map(|candidate| {
+        if files.contains(candidate) {
+            return Some(candidate.clone());
+        }
+        let with_extension = RESOLVE_EXTENSIONS.iter().map(|ext| {
+            let mut file_name = candidate.as_os_str().to_owned();
+            file_name.push(".");
+            file_name.push(ext);
+            PathBuf::from(file_name)
+        });
+        let index_files = RESOLVE_EXTENSIONS
+            .iter()
+            .map(|ext| candidate.join(format!("index.{}", ext)));
+        with_extension.chain(index_files).find(|path| files.contains(path))
+    })
+}
+
+/// `.`, `..`를 정리한 경로 (파일 시스템을 보지 않는 순수 경로 연산)
+pub fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    normalized.push("..");
+                }
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+/// JSONC(주석, trailing comma)를 JSON으로 변환
+/// 문자열 안의 `//`, `/*`는 건드리지 않는다
+pub(crate) fn strip_jsonc(content: &str) -> String {
+    let mut output = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            output.push(c);
+            match c {
+                '\\' => output.extend(chars.next()),
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => {
+                in_string = true;
+                output.push(c);
+            }
+            '/' if chars.peek() == Some(&'/') => {
+                while chars.next_if(|&next| next != '\n').is_some() {}
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut prev = '\0';
+                for next in chars.by_ref() {
+                    if prev == '*' && next == '/' {
+                        break;
+                    }
+                    prev = next;
+                }
+            }
+            _ => output.push(c),
+        }
+    }
+
+    // trailing comma 제거: `,` 뒤에 공백만 있고 `}` 또는 `]`가 오는 경우
+    let mut result = String::with_capacity(output.len());
+    let chars: Vec<char> = output.chars().collect();
+    let mut in_string = false;
+    let mut index = 0;
+    while index < chars.len() {
+        let c = chars[index];
+        if in_string {
+            result.push(c);
+            if c == '\\' {
+                if let Some(&escaped) = chars.get(index + 1) {
+                    result.push(escaped);
+                    index += 1;
+                }
+            } else if c == '"' {
+                in_string = false;
+            }
+        } else if c == '"' {
+            in_string = true;
+            result.push(c);
+        } else if c == ',' {
+            let next = chars[index + 1..].iter().find(|next| !next.is_whitespace());
+            if !matches!(next, Some('}') | Some(']')) {
+                result.push(c);
+            }
+        } else {
+            result.push(c);
+        }
+        index += 1;
+    }
+    result
+}
@@ -1,8 +1,9 @@
-/// Import 관리 유틸리티
+//! Import 관리 유틸리티
 
+use crate::ast_helpers::{has_module_binding, is_directive};
 use crate::constants::StringConstants;
 use swc_ecma_ast::*;
-use swc_ecma_visit::{VisitMut, VisitMutWith};
+use swc_ecma_visit::{Visit, VisitWith};
 use swc_common::DUMMY_SP;
 
 /// useTranslation 훅을 생성하는 AST 노드 생성
@@ -10,6 +11,28 @@ use swc_common::DUMMY_SP;
 /// TypeScript 버전과 동일한 로직:
 /// const { t } = useTranslation();
 pub fn create_use_translation_hook() -> Stmt {
+    create_use_translation_hook_as(StringConstants::USE_TRANSLATION)
+}
+
+/// useTranslation이 다른 이름으로 import된 경우 그 이름으로 훅 호출 생성
+/// 예: `import { useTranslation as useT }` → const { t } = useT();
+pub fn create_use_translation_hook_as(hook_name: &str) -> Stmt {
+    create_t_binding(Expr::Call(CallExpr {
+        span: DUMMY_SP,
+        callee: Callee::Expr(Box::new(Expr::Ident(Ident {
+            span: DUMMY_SP,
+            sym: hook_name.into(),
+            optional: false,
+            ctxt: Default::default(),
+        }))),
+        args: vec![],
+        type_args: None,
+        ctxt: Default::default(),
+    }))
+}
+
+/// `const { t } = <init>;` 구문 생성
+pub fn create_t_binding(init: Expr) -> Stmt {
     Stmt::Decl(Decl::Var(Box::new(VarDecl {
         span: DUMMY_SP,
         kind: VarDeclKind::Const,
@@ -18,131 +41,420 @@ pub fn create_use_translation_hook() -> Stmt {
             span: DUMMY_SP,
             name: Pat::Object(ObjectPat {
                 span: DUMMY_SP,
-                props: vec![ObjectPatProp::KeyValue(KeyValuePatProp {
-                    key: PropName::Ident(IdentName {
-                        sym: StringConstants::TRANSLATION_FUNCTION.into(),
-                        span: DUMMY_SP,
-                    }),
-                    value: Box::new(Pat::Ident(BindingIdent {
-                        id: Ident {
-                            span: DUMMY_SP,
-                            sym: StringConstants::TRANSLATION_FUNCTION.into(),
-                            optional: false,
-                            ctxt: Default::default(),
-                        },
-                        type_ann: None,
-                    })),
-                })],
+                props: vec![shorthand_pat_prop(StringConstants::TRANSLATION_FUNCTION)],
                 optional: false,
                 type_ann: None,
             }),
-            init: Some(Box::new(Expr::Call(CallExpr {
-                span: DUMMY_SP,
-                callee: Callee::Expr(Box::new(Expr::Ident(Ident {
-                    span: DUMMY_SP,
-                    sym: StringConstants::USE_TRANSLATION.into(),
-                    optional: false,
-                    ctxt: Default::default(),
-                }))),
-                args: vec![],
-                type_args: None,
-                ctxt: Default::default(),
-            }))),
+            init: Some(Box::new(init)),
             definite: false,
         }],
         ctxt: Default::default(),
     })))
 }
 
-/// AST에 useTranslation import가 필요한지 확인하고 추가
+/// 구조 분해 shorthand 속성 `{ name }`
+/// (KeyValue로 만들면 `{ t: t }`로 출력됨)
+fn shorthand_pat_prop(name: &str) -> ObjectPatProp {
+    ObjectPatProp::Assign(AssignPatProp {
+        span: DUMMY_SP,
+        key: BindingIdent {
+            id: Ident {
+                span: DUMMY_SP,
+                sym: name.into(),
+                optional: false,
+                ctxt: Default::default(),
+            },
+            type_ann: None,
+        },
+        value: None,
+    })
+}
+
+/// 구조 분해 속성 `{ name }` 또는 별칭 `{ name: local_name }`
+fn destructured_pat_prop(name: &str, local_name: &str) -> ObjectPatProp {
+    if name == local_name {
+        return shorthand_pat_prop(name);
+    }
+    ObjectPatProp::KeyValue(KeyValuePatProp {
+        key: PropName::Ident(IdentName::new(name.into(), DUMMY_SP)),
+        value: Box::new(Pat::Ident(BindingIdent {
+            id: Ident {
+                span: DUMMY_SP,
+                sym: local_name.into(),
+                optional: false,
+                ctxt: Default::default(),
+            },
+            type_ann: None,
+        })),
+    })
+}
+
+/// import specifier가 가져오는 원래 이름
+/// `{ a }` → a, `{ a as b }` → a, `{ "a" as b }` → a
+fn imported_name_of(named: &ImportNamedSpecifier) -> String {
+    match &named.imported {
+        Some(ModuleExportName::Ident(ident)) => ident.sym.to_string(),
+        Some(ModuleExportName::Str(str_lit)) => str_lit.value.to_string_lossy().into_owned(),
+        None => named.local.sym.to_string(),
+    }
+}
+
+fn local_name_of(spec: &ImportSpecifier) -> &str {
+    match spec {
+        ImportSpecifier::Named(named) => &named.local.sym,
+        ImportSpecifier::Default(default) => &default.local.sym,
+        ImportSpecifier::Namespace(namespace) => &namespace.local.sym,
+    }
+}
+
+/// 값 import 구문 목록 (`import type`은 값을 가져오지 않으므로 제외)
+fn value_imports(module: &Module) -> impl Iterator<Item = &ImportDecl> {
+    module.body.iter().filter_map(|item| match item {
+        ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) if !import_decl.type_only => {
+            Some(import_decl)
+        }
+        _ => None,
+    })
+}
+
+/// `require("source")` 호출이면 source 반환
+fn require_source(expr: &Expr) -> Option<String> {
+    let Expr::Call(call) = expr else {
+        return None;
+    };
+    let Callee::Expr(callee) = &call.callee else {
+        return None;
+    };
+    if !matches!(callee.as_ref(), Expr::Ident(ident) if ident.sym == StringConstants::REQUIRE) {
+        return None;
+    }
+    match call.args.first().map(|arg| arg.expr.as_ref()) {
+        Some(Expr::Lit(Lit::Str(str_lit))) => Some(str_lit.value.to_string_lossy().into_owned()),
+        _ => None,
+    }
+}
+
+/// 최상위 `const { ... } = require("source")` 구조 분해 패턴 목록
+fn require_patterns<'a>(module: &'a Module, source: &'a str) -> impl Iterator<Item = &'a ObjectPat> {
+    module
+        .body
+        .iter()
+        .filter_map(|item| match item {
+            ModuleItem::Stmt(Stmt::Decl(Decl::Var(var))) => Some(var.decls.iter()),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(move |decl| match (&decl.name, decl.init.as_deref()) {
+            (Pat::Object(object), Some(init)) if require_source(init).as_deref() == Some(source) => {
+                Some(object)
+            }
+            _ => None,
+        })
+}
+
+/// 구조 분해 속성이 `name`을 꺼내면 로컬 이름 반환
+/// `{ a }` → a, `{ a: b }` → b, `{ a = 1 }` → a
+fn destructured_local_name(prop: &ObjectPatProp, name: &str) -> Option<String> {
+    match prop {
+        ObjectPatProp::Assign(assign) if assign.key.sym == name => Some(name.to_string()),
+        ObjectPatProp::KeyValue(key_value) => {
+            let key = match &key_value.key {
+                PropName::Ident(ident) => ident.sym.to_string(),
+                PropName::Str(str_lit) => str_lit.value.to_string_lossy().into_owned(),
+                _ => return None,
+            };
+            match key_value.value.as_ref() {
+                Pat::Ident(binding) if key == name => Some(binding.id.sym.to_string()),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// CommonJS 파일인지 확인
+/// import/export 구문이 없고 `require("...")`를 호출하면 CommonJS로 본다
+pub fn is_commonjs_module(module: &Module) -> bool {
+    struct RequireFinder {
+        found: bool,
+    }
+
+    impl Visit for RequireFinder {
+        fn visit_expr(&mut self, expr: &Expr) {
+            if self.found {
+                return;
+            }
+            if require_source(expr).is_some() {
+                self.found = true;
+                return;
+            }
+            expr.visit_children_with(self);
+        }
+    }
+
+    if module.body.iter().any(|item| matches!(item, ModuleItem::ModuleDecl(_))) {
+        return false;
+    }
+    let mut finder = RequireFinder { found: false };
+    module.visit_with(&mut finder);
+    finder.found
+}
+
+/// `source`에서 `imported_name`을 가져오는 import의 로컬 이름 찾기
+/// 
+/// - `import { useTranslation } from "i18nexus"` → Some("useTranslation")
+/// - `import { useTranslation as useT } from "i18nexus"` → Some("useT")
+/// - `const { useTranslation: useT } = require("i18nexus")` → Some("useT")
+/// - import가 없으면 None
+pub fn find_import_local_name(module: &Module, source: &str, imported_name: &str) -> Option<String> {
+    let imported = value_imports(module)
+        .filter(|import_decl| import_decl.src.value.to_string_lossy() == source)
+        .flat_map(|import_decl| import_decl.specifiers.iter())
+        .find_map(|spec| match spec {
+            ImportSpecifier::Named(named) if !named.is_type_only && imported_name_of(named) == imported_name => {
+                Some(named.local.sym.to_string())
+            }
+            _ => None,
+        });
+    imported.or_else(|| {
+        require_patterns(module, source)
+            .flat_map(|object| object.props.iter())
+            .find_map(|prop| destructured_local_name(prop, imported_name))
+    })
+}
+
+/// 모듈 최상위에서 `name`을 이미 쓰고 있는지 확인 (값 import, 변수/함수 선언)
+fn is_name_taken(module: &Module, name: &str) -> bool {
+    value_imports(module)
+        .flat_map(|import_decl| import_decl.specifiers.iter())
+        .any(|spec| local_name_of(spec) == name)
+        || has_module_binding(module, name)
+}
+
+/// `source`의 `imported_name`을 가리킬 로컬 이름
+///
+/// - 이미 가져왔으면 (별칭 포함) 그 이름
+/// - 다른 모듈의 import나 선언이 같은 이름을 쓰고 있으면 `_name`, `_name2`, ... 중 비어 있는 이름
+/// - 그 외에는 `imported_name`
+///
+/// ensure_named_import/ensure_named_require가 같은 규칙으로 별칭을 정하므로
+/// 바인딩(`const { t } = _useTranslation()`)을 만들 때도 이 이름을 사용한다
+pub fn resolve_import_local_name(module: &Module, source: &str, imported_name: &str) -> String {
+    if let Some(local_name) = find_import_local_name(module, source, imported_name) {
+        return local_name;
+    }
+    if !is_name_taken(module, imported_name) {
+        return imported_name.to_string();
+    }
+    (1..)
+        .map(|index| match index {
+            1 => format!("_{}", imported_name),
+            index => format!("_{}{}", imported_name, index),
+        })
+        .find(|alias| !is_name_taken(module, alias))
+        .unwrap_or_default()
+}
+
+/// Named import 보장
 /// 
 /// TypeScript 버전과 동일한 로직:
-/// 1. AST를 traverse하여 ImportDeclaration 확인
-/// 2. 같은 소스의 import가 있고 useTranslation이 있으면 false 반환
-/// 3. 같은 소스의 import가 있지만 useTranslation이 없으면 specifier 추가
-/// 4. import가 없으면 새로 생성
-pub fn add_import_if_needed(module: &mut Module, translation_import_source: &str) -> bool {
-    let mut has_import = false;
-    let mut has_use_translation = false;
-
-    // TODO: SWC AST traverse로 구현
-    // module.visit_mut_with(&mut ImportVisitor {
-    //     translation_import_source,
-    //     has_import: &mut has_import,
-    //     has_use_translation: &mut has_use_translation,
-    // });
-
-    // 기존 import 확인
-    // TODO: Wtf8Atom을 문자열로 변환하는 올바른 방법 찾기
-    for stmt in &module.body {
-        // TODO: import 확인 로직 구현
-        // if let ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) = stmt {
-        //     // Wtf8Atom 처리 필요
-        //     // if import_decl.src.value == translation_import_source {
-        //     //     has_import = true;
-        //     //     // specifiers 확인
-        //     //     for spec in &import_decl.specifiers {
-        //     //         if let ImportSpecifier::Named(named) = spec {
-        //     //             if named.local.sym == StringConstants::USE_TRANSLATION {
-        //     //                 has_use_translation = true;
-        //     //             }
-        //     //         }
-        //     //     }
-        //     // }
-        // }
-        let _ = stmt;
-    }
-
-    // useTranslation이 없으면 추가
-    if has_import && !has_use_translation {
-        // TODO: 기존 import에 specifier 추가
-        // if let Some(import_decl) = find_import_declaration(module, translation_import_source) {
-        //     import_decl.specifiers.push(ImportSpecifier::Named(NamedImportSpecifier {
-        //         local: Ident {
-        //             sym: StringConstants::USE_TRANSLATION.into(),
-        //             ...
-        //         },
-        //         imported: None,
-        //         ...
-        //     }));
-        //     return true;
-        // }
+/// 1. 같은 소스의 import에 specifier가 이미 있으면 (별칭 포함) false 반환
+/// 2. 같은 소스의 import가 있지만 specifier가 없으면 specifier 추가
+/// 3. 없으면 마지막 import 바로 뒤(import가 없으면 디렉티브("use client" 등) 바로 뒤)에 새로 생성
+/// 
+/// 추가 규칙:
+/// - 다른 import나 선언이 같은 로컬 이름을 이미 쓰고 있으면 별칭으로 추가 (resolve_import_local_name)
+/// - `import * as ns` 구문에는 named specifier를 붙일 수 없으므로 새 import 생성
+/// - default import(`import i18n from "..."`)에는 `import i18n, { useTranslation }` 형태로 추가
+/// - CommonJS 파일(is_commonjs_module)은 ensure_named_require로 처리
+/// 
+/// 반환값: import를 수정했으면 true (반복 실행해도 중복되지 않음)
+pub fn ensure_named_import(module: &mut Module, source: &str, imported_name: &str) -> bool {
+    if is_commonjs_module(module) {
+        return ensure_named_require(module, source, imported_name);
+    }
+    if find_import_local_name(module, source, imported_name).is_some() {
+        return false;
+    }
+    let local_name = resolve_import_local_name(module, source, imported_name);
+
+    // 별칭이면 `import { useTranslation as _useTranslation }`
+    let imported = (local_name != imported_name).then(|| {
+        ModuleExportName::Ident(Ident {
+            span: DUMMY_SP,
+            sym: imported_name.into(),
+            optional: false,
+            ctxt: Default::default(),
+        })
+    });
+    let specifier = ImportSpecifier::Named(ImportNamedSpecifier {
+        span: DUMMY_SP,
+        local: Ident {
+            span: DUMMY_SP,
+            sym: local_name.into(),
+            optional: false,
+            ctxt: Default::default(),
+        },
+        imported,
+        is_type_only: false,
+    });
+
+    // 같은 소스의 기존 import에 specifier 추가
+    let existing = module.body.iter_mut().find_map(|item| match item {
+        ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl))
+            if !import_decl.type_only
+                && import_decl.src.value.to_string_lossy() == source
+                && !import_decl
+                    .specifiers
+                    .iter()
+                    .any(|spec| matches!(spec, ImportSpecifier::Namespace(_))) =>
+        {
+            Some(import_decl)
+        }
+        _ => None,
+    });
+    if let Some(import_decl) = existing {
+        import_decl.specifiers.push(specifier);
         return true;
     }
 
     // import가 아예 없으면 새로 생성
-    if !has_import {
-        // TODO: 새 import declaration 생성 및 추가
-        // let import_decl = ImportDecl {
-        //     src: Box::new(Str {
-        //         value: translation_import_source.into(),
-        //         ...
-        //     }),
-        //     specifiers: vec![ImportSpecifier::Named(NamedImportSpecifier {
-        //         local: Ident {
-        //             sym: StringConstants::USE_TRANSLATION.into(),
-        //             ...
-        //         },
-        //         imported: None,
-        //         ...
-        //     })],
-        //     ...
-        // };
-        // module.body.insert(0, ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)));
+    let index = insertion_index(module, true);
+    let import_decl = ImportDecl {
+        span: DUMMY_SP,
+        specifiers: vec![specifier],
+        src: Box::new(Str {
+            span: DUMMY_SP,
+            value: source.into(),
+            raw: None,
+        }),
+        type_only: false,
+        with: None,
+        phase: Default::default(),
+    };
+    module
+        .body
+        .insert(index, ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)));
+    true
+}
+
+/// CommonJS 구조 분해 require 보장
+/// 
+/// 1. `const { name } = require("source")`가 이미 있으면 (별칭 포함) false 반환
+/// 2. 같은 소스의 구조 분해 require가 있으면 속성 추가 (`...rest`보다 앞에)
+/// 3. 없으면 디렉티브("use strict" 등) 바로 뒤에 `const { name } = require("source");` 생성
+/// 
+/// 최상위에 같은 이름의 바인딩이 이미 있으면 별칭으로 추가 (`{ useTranslation: _useTranslation }`)
+pub fn ensure_named_require(module: &mut Module, source: &str, name: &str) -> bool {
+    if find_import_local_name(module, source, name).is_some() {
+        return false;
+    }
+    let prop = destructured_pat_prop(name, &resolve_import_local_name(module, source, name));
+
+    // 같은 소스의 기존 구조 분해 require에 속성 추가
+    let existing = module
+        .body
+        .iter_mut()
+        .filter_map(|item| match item {
+            ModuleItem::Stmt(Stmt::Decl(Decl::Var(var))) => Some(var.decls.iter_mut()),
+            _ => None,
+        })
+        .flatten()
+        .find_map(|decl| match (&mut decl.name, decl.init.as_deref()) {
+            (Pat::Object(object), Some(init)) if require_source(init).as_deref() == Some(source) => {
+                Some(object)
+            }
+            _ => None,
+        });
+    if let Some(object) = existing {
+        let index = object
+            .props
+            .iter()
+            .position(|prop| matches!(prop, ObjectPatProp::Rest(_)))
+            .unwrap_or(object.props.len());
+        object.props.insert(index, prop);
         return true;
     }
 
-    false
+    let require_call = Expr::Call(CallExpr {
+        span: DUMMY_SP,
+        callee: Callee::Expr(Box::new(Expr::Ident(Ident {
+            span: DUMMY_SP,
+            sym: StringConstants::REQUIRE.into(),
+            optional: false,
+            ctxt: Default::default(),
+        }))),
+        args: vec![ExprOrSpread {
+            spread: None,
+            expr: Box::new(Expr::Lit(Lit::Str(Str {
+                span: DUMMY_SP,
+                value: source.into(),
+                raw: None,
+            }))),
+        }],
+        type_args: None,
+        ctxt: Default::default(),
+    });
+    let index = insertion_index(module, false);
+    let require_decl = Stmt::Decl(Decl::Var(Box::new(VarDecl {
+        span: DUMMY_SP,
+        kind: VarDeclKind::Const,
+        declare: false,
+        decls: vec![VarDeclarator {
+            span: DUMMY_SP,
+            name: Pat::Object(ObjectPat {
+                span: DUMMY_SP,
+                props: vec![prop],
+                optional: false,
+                type_ann: None,
+            }),
+            init: Some(Box::new(require_call)),
+            definite: false,
+        }],
+        ctxt: Default::default(),
+    })));
+    module.body.insert(index, ModuleItem::Stmt(require_decl));
+    true
+}
+
+/// 새 import/require 구문을 넣을 위치
+///
+/// - `after_imports`이고 기존 import가 있으면 마지막 import 바로 뒤
+///   (파일 앞 라이선스/저작권 주석은 첫 import에 붙어 있으므로 그대로 맨 위에 남음)
+/// - 그 외에는 디렉티브("use strict" 등) 바로 뒤
+///   (맨 앞에 넣어도 파일 헤더 주석은 Session::generate_code가 맨 위에 유지)
+fn insertion_index(module: &Module, after_imports: bool) -> usize {
+    let last_import = module
+        .body
+        .iter()
+        .rposition(|item| matches!(item, ModuleItem::ModuleDecl(ModuleDecl::Import(_))))
+        .filter(|_| after_imports);
+    match last_import {
+        Some(last_import) => last_import + 1,
+        None => module
+            .body
+            .iter()
+            .take_while(|item| item.as_stmt().is_some_and(is_directive))
+            .count(),
+    }
+}
+
+/// AST에 useTranslation import가 필요한지 확인하고 추가
+/// 
+/// TypeScript 버전과 동일한 로직 (ensure_named_import에 위임):
+/// 1. 같은 소스의 import가 있고 useTranslation이 있으면 false 반환
+/// 2. 같은 소스의 import가 있지만 useTranslation이 없으면 specifier 추가
+/// 3. import가 없으면 새로 생성
+pub fn add_import_if_needed(module: &mut Module, translation_import_source: &str) -> bool {
+    ensure_named_import(module, translation_import_source, StringConstants::USE_TRANSLATION)
 }
 
 /// Server translation function import 추가
+/// addImportIfNeeded와 같은 로직이지만 server_function_name을 사용
 pub fn add_server_translation_import(
     module: &mut Module,
     translation_import_source: &str,
     server_function_name: &str,
 ) -> bool {
-    // TODO: server translation function import 추가
-    // addImportIfNeeded와 유사한 로직이지만 server_function_name을 사용
-    let _ = (module, translation_import_source, server_function_name);
-    true
+    ensure_named_import(module, translation_import_source, server_function_name)
 }
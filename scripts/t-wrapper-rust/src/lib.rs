@@ -1,51 +1,87 @@
+pub mod cache;
 pub mod constants;
+pub mod diff_preview;
 pub mod ast_helpers;
 pub mod ast_transformers;
+pub mod classifier;
+pub mod import_graph;
 pub mod import_manager;
 pub mod translation_wrapper;
 pub mod parser;
+pub mod performance_monitor;
+pub mod performance_reporter;
+pub mod prefilter;
+pub mod report;
+pub mod session;
+pub mod source_files;
+pub mod text_edits;
+pub mod watch;
 
+pub use cache::*;
 pub use constants::*;
+pub use diff_preview::*;
 pub use ast_helpers::*;
 pub use ast_transformers::*;
+pub use classifier::*;
+pub use import_graph::*;
 pub use import_manager::*;
 pub use translation_wrapper::*;
 pub use parser::*;
+pub use performance_monitor::*;
+pub use performance_reporter::*;
+pub use prefilter::*;
+pub use report::*;
+pub use session::*;
+pub use source_files::*;
+pub use text_edits::*;
+pub use watch::*;
 
 /// runTranslationWrapper 함수
 /// TypeScript 버전과 동일한 로직:
 /// 1. TranslationWrapper 생성
 /// 2. processFiles 호출
-/// 3. 성능 리포트 출력 (TODO)
-/// 4. 성능 데이터 플러시 (TODO)
+/// 3. `report`가 "json"이면 변환 리포트를 JSON으로 표준 출력에 출력
+///    (이때 다른 안내 메시지는 표준 에러로 출력)
+/// 4. 성능 리포트 출력 (I18N_PERF_VERBOSE=true면 상세 리포트도 출력)
+/// 5. `trace_out`이 있으면 Chrome trace-event JSON 저장
+/// 6. 성능 데이터 플러시
 pub fn run_translation_wrapper(config: ScriptConfig) -> anyhow::Result<()> {
+    let is_json_report = config.report.as_deref() == Some(ReportFormat::JSON);
+    let trace_out = config.trace_out.clone();
     let wrapper = TranslationWrapper::new(Some(config));
 
-    // TODO: PerformanceMonitor 추가
-    // let start_time = std::time::Instant::now();
+    let start_time = std::time::Instant::now();
 
-    let processed_files = wrapper.process_files()?;
+    let (processed_files, report) = wrapper.process_files_with_report()?;
+    if is_json_report {
+        println!("{}", report.to_json()?);
+    }
 
-    // TODO: PerformanceMonitor
-    // let end_time = std::time::Instant::now();
-    // let total_time = end_time.duration_since(start_time);
+    let total_time = start_time.elapsed();
 
-    // TODO: 완료 리포트 출력
-    // let report = wrapper.performance_monitor.get_report();
-    // PerformanceReporter::print_completion_report(
-    //     report,
-    //     processed_files,
-    //     total_time,
-    //     StringConstants::COMPLETION_TITLE,
-    // );
+    // 완료 리포트 출력
+    let performance_report = wrapper.performance_monitor.get_report();
+    if wrapper.performance_monitor.is_enabled() {
+        wrapper.log(&PerformanceReporter::render_completion_report(
+            &performance_report,
+            &processed_files,
+            total_time,
+            StringConstants::COMPLETION_TITLE,
+        ));
 
-    // TODO: 상세 리포트 출력 (verbose mode인 경우)
-    // if std::env::var("I18N_PERF_VERBOSE").unwrap_or_default() == "true" {
-    //     wrapper.print_performance_report(true);
-    // }
+        // 상세 리포트 출력 (verbose mode인 경우)
+        if std::env::var(EnvVars::PERF_VERBOSE).is_ok_and(|value| value == "true") {
+            wrapper.log(&PerformanceReporter::render_report(&performance_report, true));
+        }
+    }
 
-    // TODO: 성능 데이터 플러시
-    // wrapper.flush_performance_data().await?;
+    if let Some(trace_out) = &trace_out {
+        PerformanceReporter::write_chrome_trace(&performance_report, std::path::Path::new(trace_out))?;
+        wrapper.log(&ConsoleMessages::trace_written(trace_out));
+    }
+
+    // 성능 데이터 플러시
+    wrapper.performance_monitor.flush();
 
     Ok(())
 }
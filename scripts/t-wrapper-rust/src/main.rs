@@ -1,28 +1,107 @@
-/// t-wrapper Rust CLI
-/// SWC를 사용하여 AST 변환 수행
+//! t-wrapper Rust CLI
+//! SWC를 사용하여 AST 변환 수행
 
-use t_wrapper_rust::{run_translation_wrapper, ScriptConfig};
-use t_wrapper_rust::constants::{ConsoleMessages, CliOptions, CliHelp};
+use t_wrapper_rust::{run_translation_wrapper, run_watch_mode, ScriptConfig};
+use t_wrapper_rust::constants::{ConsoleMessages, CliOptions, CliHelp, EnvVars, ReportFormat, StringConstants};
 use std::env;
 
 fn main() {
     let args: Vec<String> = env::args().skip(1).collect();
-    let mut config = ScriptConfig::default();
+    // TypeScript 버전과 동일: I18N_PERF_MONITOR=false가 아니면 성능 측정
+    let mut config = ScriptConfig {
+        enable_performance_monitoring: env::var(EnvVars::PERF_MONITOR).map_or(true, |value| value != "false"),
+        cache_file: Some(StringConstants::CACHE_FILE.to_string()),
+        ..Default::default()
+    };
 
     // TypeScript 버전과 동일한 로직:
     // CLI 옵션 파싱
+    // -p를 여러 번 주면 첫 번째는 source_pattern, 나머지는 include_patterns
+    let mut has_pattern = false;
+    let mut watch = false;
     let mut i = 0;
     while i < args.len() {
         match args[i].as_str() {
             CliOptions::PATTERN | CliOptions::PATTERN_SHORT => {
                 if i + 1 < args.len() {
-                    config.source_pattern = args[i + 1].clone();
+                    if has_pattern {
+                        config.include_patterns.push(args[i + 1].clone());
+                    } else {
+                        config.source_pattern = args[i + 1].clone();
+                        has_pattern = true;
+                    }
                     i += 1;
                 }
             }
+            CliOptions::EXCLUDE | CliOptions::EXCLUDE_SHORT => {
+                if i + 1 < args.len() {
+                    config.exclude_patterns.push(args[i + 1].clone());
+                    i += 1;
+                }
+            }
+            CliOptions::GITIGNORE => {
+                config.respect_gitignore = true;
+            }
             CliOptions::DRY_RUN | CliOptions::DRY_RUN_SHORT => {
                 config.dry_run = true;
             }
+            CliOptions::PATCH_OUT => {
+                if i + 1 < args.len() {
+                    config.patch_out = Some(args[i + 1].clone());
+                    config.dry_run = true;
+                    i += 1;
+                }
+            }
+            CliOptions::REPORT => {
+                if i + 1 < args.len() {
+                    if !ReportFormat::is_supported(&args[i + 1]) {
+                        eprintln!("{}", ConsoleMessages::unknown_report_format(&args[i + 1]));
+                        std::process::exit(1);
+                    }
+                    config.report = Some(args[i + 1].clone());
+                    i += 1;
+                }
+            }
+            CliOptions::TRACE => {
+                if i + 1 < args.len() {
+                    config.trace_out = Some(args[i + 1].clone());
+                    i += 1;
+                }
+            }
+            CliOptions::JOBS | CliOptions::JOBS_SHORT => {
+                if i + 1 < args.len() {
+                    match args[i + 1].parse::<usize>() {
+                        Ok(jobs) if jobs > 0 => config.jobs = Some(jobs),
+                        _ => {
+                            eprintln!("{}", ConsoleMessages::invalid_jobs(&args[i + 1]));
+                            std::process::exit(1);
+                        }
+                    }
+                    i += 1;
+                }
+            }
+            CliOptions::MAX_FILE_SIZE => {
+                if i + 1 < args.len() {
+                    match args[i + 1].parse::<u64>() {
+                        Ok(0) => config.max_file_size = None,
+                        Ok(size) => config.max_file_size = Some(size),
+                        Err(_) => {
+                            eprintln!("{}", ConsoleMessages::invalid_max_file_size(&args[i + 1]));
+                            std::process::exit(1);
+                        }
+                    }
+                    i += 1;
+                }
+            }
+            CliOptions::NO_CACHE => {
+                config.cache_file = None;
+            }
+            CliOptions::WATCH | CliOptions::WATCH_SHORT => {
+                watch = true;
+            }
+            CliOptions::MINIMAL_DIFF => {
+                config.minimal_diff = true;
+            }
             CliOptions::HELP | CliOptions::HELP_SHORT => {
                 println!(
                     "\n{}\n\n{}\n\n{}",
@@ -42,7 +121,13 @@ fn main() {
 
     // TypeScript 버전과 동일한 로직:
     // runTranslationWrapper 호출 및 에러 처리
-    if let Err(e) = run_translation_wrapper(config) {
+    // --watch면 처음 한 번 처리한 뒤 저장된 파일을 계속 처리
+    let result = if watch {
+        run_watch_mode(config)
+    } else {
+        run_translation_wrapper(config)
+    };
+    if let Err(e) = result {
         eprintln!("{} {}", ConsoleMessages::FATAL_ERROR, e);
         std::process::exit(1);
     }
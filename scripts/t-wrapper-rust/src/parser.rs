@@ -1,47 +1,255 @@
-/// SWC 파서 모듈
-/// TypeScript/JavaScript 파일을 AST로 파싱
+//! SWC 파서 모듈
+//! TypeScript/JavaScript 파일을 AST로 파싱
 
-use std::sync::Arc;
-use swc::{Compiler, config::IsModule};
 use swc_common::{
-    errors::Handler,
-    FileName, SourceMap, GLOBALS, sync::Lrc,
+    comments::{Comments, SingleThreadedComments},
+    BytePos, FileName, SourceFile, SourceMap, Span, Spanned, GLOBALS, sync::Lrc,
 };
-use swc_ecma_parser::Syntax;
-use swc_ecma_ast::{EsVersion, Module, Program};
-use anyhow::{Result, Context};
+use swc_ecma_codegen::text_writer::WriteJs;
+use swc_ecma_parser::{
+    parse_file_as_commonjs, parse_file_as_module, parse_file_as_program, EsSyntax, Syntax, TsSyntax,
+};
+use swc_ecma_ast::{EsVersion, Module, ModuleDecl, ModuleItem, Program, Script};
+use crate::import_graph::TsConfigChain;
+use crate::session::line_col;
+use anyhow::Result;
+use serde_json::Value;
+use std::path::Path;
+
+/// 이름 없이 파싱할 때 사용하는 파일 이름
+pub(crate) const DEFAULT_FILE_NAME: &str = "input.tsx";
+
+/// 소스 종류 (Babel의 sourceType과 같은 의미)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceType {
+    /// ES 모듈 (strict mode)
+    Module,
+    /// CommonJS 스크립트 (`require`, `module.exports`, 최상위 `return` 허용)
+    Script,
+    /// import/export가 있으면 모듈, 없으면 스크립트
+    Unambiguous,
+}
 
 /// 파싱 옵션
 #[derive(Debug, Clone)]
 pub struct ParseOptions {
-    pub tsx: bool,
+    /// TypeScript 문법 (false면 ECMAScript)
+    pub typescript: bool,
+    /// JSX 허용
+    /// `.ts`에서는 꺼야 `<T>value` 타입 단언을 파싱할 수 있다
+    pub jsx: bool,
     pub decorators: bool,
+    pub target: EsVersion,
+    pub source_type: SourceType,
+    /// 에러 메시지에 표시할 파일 이름 (None이면 `input.tsx`)
+    pub file_name: Option<String>,
 }
 
 impl Default for ParseOptions {
     fn default() -> Self {
         Self {
-            tsx: true,
+            typescript: true,
+            jsx: true,
             decorators: true,
+            target: EsVersion::Es2020,
+            source_type: SourceType::Module,
+            file_name: None,
+        }
+    }
+}
+
+impl ParseOptions {
+    /// 확장자에 맞는 문법 선택
+    ///
+    /// - `.ts`: TypeScript (JSX 없음)
+    /// - `.tsx`: TypeScript + JSX
+    /// - `.js`, `.jsx`: ECMAScript + JSX, 모듈/스크립트 자동 판별
+    /// - `.mjs`: ECMAScript 모듈 (JSX 없음)
+    /// - `.cjs`: CommonJS 스크립트 (JSX 없음)
+    /// - 그 외: 기본값 (TypeScript + JSX, 모듈)
+    pub fn for_path(path: &Path) -> Self {
+        let mut options = Self {
+            file_name: Some(path.to_string_lossy().into_owned()),
+            ..Self::default()
+        };
+        let extension = path.extension().and_then(|ext| ext.to_str()).unwrap_or_default();
+        match extension {
+            "ts" => options.jsx = false,
+            "tsx" => {}
+            "js" | "jsx" => {
+                options.typescript = false;
+                options.decorators = false;
+                options.source_type = SourceType::Unambiguous;
+            }
+            "mjs" | "cjs" => {
+                options.typescript = false;
+                options.jsx = false;
+                options.decorators = false;
+                if extension == "cjs" {
+                    options.source_type = SourceType::Script;
+                }
+            }
+            _ => {}
+        }
+        options
+    }
+
+    /// tsconfig.json의 compilerOptions 반영
+    ///
+    /// - `jsx`: 설정되어 있으면 `.js` 파일에서 JSX 허용
+    ///   (`.js`는 확장자 기본값으로 이미 허용하므로, 설정이 없어도 끄지 않는다.
+    ///   React 프로젝트의 tsconfig는 대부분 `.js`용 jsx를 따로 지정하지 않음)
+    /// - `target`: 파서 target
+    /// - `experimentalDecorators`: JavaScript 파일에서도 데코레이터 허용
+    pub fn with_tsconfig(mut self, compiler_options: &TsConfigCompilerOptions) -> Self {
+        let is_plain_js = self
+            .file_name
+            .as_deref()
+            .is_some_and(|file_name| Path::new(file_name).extension().is_some_and(|ext| ext == "js"));
+        if is_plain_js && compiler_options.jsx.is_some() {
+            self.jsx = true;
+        }
+        if let Some(target) = compiler_options.target {
+            self.target = target;
+        }
+        if compiler_options.experimental_decorators == Some(true) {
+            self.decorators = true;
         }
+        self
     }
 }
 
+/// 파싱에 영향을 주는 tsconfig.json의 compilerOptions
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TsConfigCompilerOptions {
+    /// `"preserve"`, `"react-jsx"` 등
+    pub jsx: Option<String>,
+    pub target: Option<EsVersion>,
+    pub experimental_decorators: Option<bool>,
+}
+
+impl TsConfigCompilerOptions {
+    /// tsconfig.json 읽기 (JSONC 허용, `extends`로 이어진 상위 설정 포함)
+    pub fn load(tsconfig_path: &Path) -> Result<Self> {
+        Ok(Self::from_chain(&TsConfigChain::load(tsconfig_path)?))
+    }
+
+    /// 읽은 설정에서 파서 옵션 추출 (옵션마다 마지막으로 지정한 설정의 값)
+    pub fn from_chain(chain: &TsConfigChain) -> Self {
+        let option = |key: &str| chain.compiler_option(key).map(|(_, value)| value);
+        Self {
+            jsx: option("jsx").and_then(Value::as_str).map(str::to_string),
+            target: option("target").and_then(Value::as_str).and_then(parse_es_version),
+            experimental_decorators: option("experimentalDecorators").and_then(Value::as_bool),
+        }
+    }
+}
+
+/// tsconfig `target` 값을 EsVersion으로 변환 (대소문자 무시, 모르는 값은 None)
+pub fn parse_es_version(target: &str) -> Option<EsVersion> {
+    let version = match target.to_ascii_lowercase().as_str() {
+        "es3" => EsVersion::Es3,
+        "es5" => EsVersion::Es5,
+        "es6" | "es2015" => EsVersion::Es2015,
+        "es2016" => EsVersion::Es2016,
+        "es2017" => EsVersion::Es2017,
+        "es2018" => EsVersion::Es2018,
+        "es2019" => EsVersion::Es2019,
+        "es2020" => EsVersion::Es2020,
+        "es2021" => EsVersion::Es2021,
+        "es2022" => EsVersion::Es2022,
+        "es2023" | "es2024" | "esnext" => EsVersion::EsNext,
+        _ => return None,
+    };
+    Some(version)
+}
+
 /// AST를 코드로 변환
 /// 
 /// SWC 코드 생성 API를 사용하여 AST를 JavaScript/TypeScript 코드로 변환합니다.
+/// 주석은 출력하지 않습니다. 원본 주석을 유지하려면 generate_code_with_comments를 사용하세요.
 pub fn generate_code(module: &Module) -> Result<String> {
+    emit_module(module, &Default::default(), None)
+}
+
+/// 주석을 유지하며 AST를 코드로 변환
+/// 
+/// `cm`과 `comments`는 parse_file_with_source_map에 넘겼던 것과 같아야 합니다.
+/// (주석은 BytePos로 노드에 연결되므로 파싱한 SourceMap 기준이어야 제자리에 출력됨)
+/// 출력한 주석은 `comments`에서 빠지므로 같은 `comments`로 두 번 출력하면 주석이 사라집니다.
+pub fn generate_code_with_comments(
+    module: &Module,
+    cm: &Lrc<SourceMap>,
+    comments: &SingleThreadedComments,
+) -> Result<String> {
+    emit_module(module, cm, Some(comments))
+}
+
+/// 코드 생성 (Session::generate_code와 공유)
+/// SWC는 빈 줄을 출력하지 않으므로 import 블록과 나머지 코드를 따로 출력해 사이에 빈 줄 하나를 넣는다
+pub(crate) fn emit_module(
+    module: &Module,
+    cm: &Lrc<SourceMap>,
+    comments: Option<&dyn Comments>,
+) -> Result<String> {
+    let code_start = module
+        .body
+        .iter()
+        .rposition(|item| matches!(item, ModuleItem::ModuleDecl(ModuleDecl::Import(_))))
+        .map(|last_import| last_import + 1)
+        .filter(|&code_start| code_start < module.body.len());
+    let Some(code_start) = code_start else {
+        return emit_module_items(&module_part(module, module.body.clone(), module.span.hi), cm, comments);
+    };
+
+    let mut imports = module.body.clone();
+    let code = imports.split_off(code_start);
+    // 파일 끝 주석(module.span.hi)은 코드 쪽에서 출력
+    let imports_hi = imports.last().map_or(module.span.hi, |import| import.span().hi);
+    let imports = emit_module_items(&module_part(module, imports, imports_hi), cm, comments)?;
+    let code = emit_module_items(
+        &Module {
+            shebang: None,
+            ..module_part(module, code, module.span.hi)
+        },
+        cm,
+        comments,
+    )?;
+    Ok(format!("{}\n{}", imports, code))
+}
+
+/// `body`만 담은 모듈
+/// span 시작을 첫 구문에 맞춰, 원본 첫 구문의 주석이 모듈 주석으로 출력되어
+/// 앞에 새로 넣은 구문(DUMMY_SP) 위로 올라가지 않게 한다
+fn module_part(module: &Module, body: Vec<ModuleItem>, hi: BytePos) -> Module {
+    let span = match body.first() {
+        Some(first) => Span {
+            lo: first.span().lo,
+            hi,
+        },
+        None => module.span,
+    };
+    Module {
+        span,
+        body,
+        shebang: module.shebang.clone(),
+    }
+}
+
+fn emit_module_items(
+    module: &Module,
+    cm: &Lrc<SourceMap>,
+    comments: Option<&dyn Comments>,
+) -> Result<String> {
     use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
-    use swc_common::SourceMap;
-    
-    let cm = Arc::new(SourceMap::default());
+
     let mut buf = Vec::new();
-    let writer = JsWriter::new(cm.clone(), "\n", &mut buf, None);
+    let writer = CommentLineWriter::new(JsWriter::new(cm.clone(), "\n", &mut buf, None), cm.clone());
     
     let mut emitter = Emitter {
         cfg: swc_ecma_codegen::Config::default(),
         cm: cm.clone(),
-        comments: None,
+        comments,
         wr: writer,
     };
     
@@ -54,50 +262,283 @@ pub fn generate_code(module: &Module) -> Result<String> {
 
 /// 파일을 AST로 파싱
 /// 
-/// SWC 고수준 API를 사용하여 파싱합니다.
-/// GLOBALS.set 패턴을 사용해야 합니다.
+/// 주석이 필요 없는 경우에 사용합니다.
+/// 주석(i18n-ignore 등)이 필요하면 parse_file_with_comments를 사용하세요.
 pub fn parse_file(code: &str, options: ParseOptions) -> Result<Module> {
-    let cm = Arc::new(SourceMap::default());
-    let emitter = Box::new(swc_common::errors::emitter::EmitterWriter::new(
-        Box::new(std::io::stderr()),
-        None,
-        false,
-        false,
-    ));
-    let handler = Handler::with_emitter(false, true, emitter);
+    parse_file_with_comments(code, options).map(|(module, _)| module)
+}
+
+/// 파일을 AST로 파싱하고 주석도 함께 반환
+/// 
+/// 파일마다 새 SourceMap을 사용합니다.
+/// 주석을 유지한 채 다시 출력하려면 parse_file_with_source_map이나 Session을 사용하세요.
+pub fn parse_file_with_comments(
+    code: &str,
+    options: ParseOptions,
+) -> Result<(Module, SingleThreadedComments)> {
+    parse_file_with_source_map(&Default::default(), code, options)
+}
+
+/// 주어진 SourceMap에 파일을 추가하고 AST와 주석 반환
+/// 
+/// swc_ecma_parser를 직접 사용하여 파싱합니다.
+/// GLOBALS.set 패턴을 사용해야 합니다.
+/// 반환되는 주석의 위치(BytePos)는 반환된 Module의 span과 같은 기준입니다.
+/// 같은 `cm`으로 generate_code_with_comments를 호출하면 주석이 제자리에 출력됩니다.
+/// 스크립트(CommonJS)로 파싱한 경우에도 구문만 담은 Module로 반환하므로
+/// 변환/코드 생성은 모듈과 같은 경로를 사용합니다.
+pub fn parse_file_with_source_map(
+    cm: &Lrc<SourceMap>,
+    code: &str,
+    options: ParseOptions,
+) -> Result<(Module, SingleThreadedComments)> {
+    let comments = SingleThreadedComments::default();
 
     GLOBALS.set(&Default::default(), || {
-        let compiler = Compiler::new(cm.clone());
-        
-        let filename: Lrc<FileName> = FileName::Custom("input.tsx".into()).into();
+        let file_name = options.file_name.as_deref().unwrap_or(DEFAULT_FILE_NAME);
+        let filename: Lrc<FileName> = FileName::Custom(file_name.into()).into();
         let source = cm.new_source_file(filename, code.to_string());
+        let module = parse_source_file(cm, &source, &options, &comments)?;
+        Ok((module, comments))
+    })
+}
+
+/// SourceMap에 추가된 파일을 파싱 (GLOBALS는 호출하는 쪽에서 설정)
+/// 주석은 `comments`에 추가된다
+pub(crate) fn parse_source_file(
+    cm: &SourceMap,
+    source: &SourceFile,
+    options: &ParseOptions,
+    comments: &SingleThreadedComments,
+) -> Result<Module> {
+    let file_name = options.file_name.as_deref().unwrap_or(DEFAULT_FILE_NAME);
+    let syntax = if options.typescript {
+        Syntax::Typescript(TsSyntax {
+            tsx: options.jsx,
+            decorators: options.decorators,
+            ..Default::default()
+        })
+    } else {
+        Syntax::Es(EsSyntax {
+            jsx: options.jsx,
+            decorators: options.decorators,
+            ..Default::default()
+        })
+    };
 
-        let syntax = if options.tsx {
-            // TSX 파싱을 위한 설정
-            // Syntax::Typescript의 기본값이 TSX를 지원함
-            Syntax::Typescript(Default::default())
-        } else {
-            Syntax::Es(Default::default())
+    // 에러 위치는 실제 파일 경로 기준 (`src/app/page.tsx:3:5`)
+    let parse_error = |e: &swc_ecma_parser::error::Error| {
+        let loc = line_col(cm, e.span().lo);
+        anyhow::anyhow!(
+            "Parse error: {} ({}:{}:{})",
+            e.kind().msg(),
+            file_name,
+            loc.line,
+            loc.column
+        )
+    };
+
+    let mut recovered_errors = Vec::new();
+    let module = match options.source_type {
+        SourceType::Module => parse_file_as_module(
+            source,
+            syntax,
+            options.target,
+            Some(comments),
+            &mut recovered_errors,
+        ),
+        SourceType::Script => parse_file_as_commonjs(
+            source,
+            syntax,
+            options.target,
+            Some(comments),
+            &mut recovered_errors,
+        )
+        .map(script_into_module),
+        SourceType::Unambiguous => parse_file_as_program(
+            source,
+            syntax,
+            options.target,
+            Some(comments),
+            &mut recovered_errors,
+        )
+        .map(|program| match program {
+            Program::Module(module) => module,
+            Program::Script(script) => script_into_module(script),
+        }),
+    }
+    .map_err(|e| parse_error(&e))?;
+
+    // 복구 가능한 에러도 실패로 취급 (잘못된 코드를 다시 쓰지 않기 위해)
+    if let Some(e) = recovered_errors.first() {
+        return Err(parse_error(e));
+    }
+
+    Ok(module)
+}
+
+/// 스크립트를 구문만 담은 Module로 변환
+/// import/export가 없는 Module은 스크립트와 똑같이 출력된다
+fn script_into_module(script: Script) -> Module {
+    Module {
+        span: script.span,
+        body: script.body.into_iter().map(ModuleItem::Stmt).collect(),
+        shebang: script.shebang,
+    }
+}
+
+/// 블록 주석 뒤 줄바꿈을 유지하는 writer
+///
+/// SWC emitter는 블록 주석 뒤에 항상 공백을 쓰므로
+/// `/** JSDoc */` 다음 줄의 선언이 주석과 같은 줄로 붙어 버린다.
+/// 원본에서 주석 바로 뒤가 줄바꿈이었으면
+/// leading 주석은 공백 대신 줄바꿈을 쓰고, trailing 주석은 공백을 생략한다
+/// (바로 뒤에 emitter가 줄바꿈을 씀).
+/// (emitter가 주석 앞뒤로 add_srcmap을 호출하는 것을 이용해 원본 위치를 얻음)
+struct CommentLineWriter<W: WriteJs> {
+    inner: W,
+    cm: Lrc<SourceMap>,
+    /// 마지막 add_srcmap 위치
+    last_pos: Option<BytePos>,
+    /// trailing 주석 출력 중 (앞에 공백을 먼저 씀)
+    is_trailing_comment: bool,
+    /// 방금 출력한 블록 주석의 끝 위치와 trailing 여부
+    block_comment_hi: Option<(BytePos, bool)>,
+}
+
+impl<W: WriteJs> CommentLineWriter<W> {
+    fn new(inner: W, cm: Lrc<SourceMap>) -> Self {
+        Self {
+            inner,
+            cm,
+            last_pos: None,
+            is_trailing_comment: false,
+            block_comment_hi: None,
+        }
+    }
+
+    /// 원본에서 `pos` 뒤에 공백/탭만 있고 줄이 끝나는지 확인
+    fn is_followed_by_newline(&self, pos: BytePos) -> bool {
+        let Ok(location) = self.cm.try_lookup_byte_offset(pos) else {
+            return false;
         };
+        location.sf.src[location.pos.0 as usize..]
+            .trim_start_matches([' ', '\t'])
+            .starts_with(['\n', '\r'])
+    }
+}
+
+impl<W: WriteJs> WriteJs for CommentLineWriter<W> {
+    fn increase_indent(&mut self) -> swc_ecma_codegen::Result {
+        self.inner.increase_indent()
+    }
+
+    fn decrease_indent(&mut self) -> swc_ecma_codegen::Result {
+        self.inner.decrease_indent()
+    }
+
+    fn write_semi(&mut self, span: Option<Span>) -> swc_ecma_codegen::Result {
+        self.block_comment_hi = None;
+        self.inner.write_semi(span)
+    }
 
-        let parsed = compiler
-            .parse_js(
-                source,
-                &handler,
-                EsVersion::Es2020,
-                syntax,
-                IsModule::Bool(true),
-                Some(compiler.comments()),
-            )
-            .map_err(|e| {
-                let msg = format!("Parse error: {:?}", e);
-                anyhow::anyhow!(msg)
-            })
-            .context("Failed to parse file")?;
-
-        match parsed {
-            Program::Module(module) => Ok(module),
-            Program::Script(_) => Err(anyhow::anyhow!("Expected module, got script")),
+    fn write_space(&mut self) -> swc_ecma_codegen::Result {
+        match self.block_comment_hi.take() {
+            Some((hi, is_trailing)) if self.is_followed_by_newline(hi) => {
+                if is_trailing {
+                    Ok(())
+                } else {
+                    self.inner.write_line()
+                }
+            }
+            _ => self.inner.write_space(),
         }
-    })
+    }
+
+    fn write_keyword(&mut self, span: Option<Span>, s: &'static str) -> swc_ecma_codegen::Result {
+        self.block_comment_hi = None;
+        self.inner.write_keyword(span, s)
+    }
+
+    fn write_operator(&mut self, span: Option<Span>, s: &str) -> swc_ecma_codegen::Result {
+        self.block_comment_hi = None;
+        self.inner.write_operator(span, s)
+    }
+
+    fn write_param(&mut self, s: &str) -> swc_ecma_codegen::Result {
+        self.block_comment_hi = None;
+        self.inner.write_param(s)
+    }
+
+    fn write_property(&mut self, s: &str) -> swc_ecma_codegen::Result {
+        self.block_comment_hi = None;
+        self.inner.write_property(s)
+    }
+
+    fn write_line(&mut self) -> swc_ecma_codegen::Result {
+        self.block_comment_hi = None;
+        self.inner.write_line()
+    }
+
+    fn write_lit(&mut self, span: Span, s: &str) -> swc_ecma_codegen::Result {
+        self.block_comment_hi = None;
+        self.inner.write_lit(span, s)
+    }
+
+    fn write_comment(&mut self, s: &str) -> swc_ecma_codegen::Result {
+        self.block_comment_hi = None;
+        match s {
+            " " => self.is_trailing_comment = true,
+            "/*" => self.last_pos = None,
+            "//" => {
+                self.last_pos = None;
+                self.is_trailing_comment = false;
+            }
+            "*/" => {
+                // emitter가 "*/" 직전에 `hi - 2` 위치로 add_srcmap을 호출함
+                let hi = self.last_pos.take().map(|pos| pos + BytePos(2));
+                let is_trailing = std::mem::take(&mut self.is_trailing_comment);
+                self.block_comment_hi = hi.map(|hi| (hi, is_trailing));
+            }
+            _ => {}
+        }
+        self.inner.write_comment(s)
+    }
+
+    fn write_str_lit(&mut self, span: Span, s: &str) -> swc_ecma_codegen::Result {
+        self.block_comment_hi = None;
+        self.inner.write_str_lit(span, s)
+    }
+
+    fn write_str(&mut self, s: &str) -> swc_ecma_codegen::Result {
+        self.block_comment_hi = None;
+        self.inner.write_str(s)
+    }
+
+    fn write_symbol(&mut self, span: Span, s: &str) -> swc_ecma_codegen::Result {
+        self.block_comment_hi = None;
+        self.inner.write_symbol(span, s)
+    }
+
+    fn write_punct(&mut self, span: Option<Span>, s: &'static str) -> swc_ecma_codegen::Result {
+        self.block_comment_hi = None;
+        self.inner.write_punct(span, s)
+    }
+
+    fn care_about_srcmap(&self) -> bool {
+        self.inner.care_about_srcmap()
+    }
+
+    fn add_srcmap(&mut self, pos: BytePos) -> swc_ecma_codegen::Result {
+        self.last_pos = Some(pos);
+        self.inner.add_srcmap(pos)
+    }
+
+    fn commit_pending_semi(&mut self) -> swc_ecma_codegen::Result {
+        self.inner.commit_pending_semi()
+    }
+
+    fn can_ignore_invalid_unicodes(&mut self) -> bool {
+        self.inner.can_ignore_invalid_unicodes()
+    }
 }
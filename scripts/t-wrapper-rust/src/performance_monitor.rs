@@ -0,0 +1,268 @@
+//! Performance Monitoring System
+//! `scripts/common/performance-monitor.ts` 포팅
+//!
+//! 이름 단위 start/end 측정, measure_sync, 단계별(glob, read, parse, transform, codegen, write) 측정.
+//! 여러 스레드에서 같은 모니터를 공유할 수 있도록 내부 상태는 Mutex로 보호한다.
+//! 디버그 출력은 JSON 리포트와 섞이지 않도록 표준 에러로 보낸다.
+
+use crate::constants::EnvVars;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+use std::thread::ThreadId;
+use std::time::{Duration, Instant};
+
+/// 메트릭 메타데이터 (출력 순서를 고정하기 위해 BTreeMap)
+pub type Metadata = BTreeMap<String, String>;
+
+/// 이 시간보다 오래 걸린 작업은 경고 출력
+const SLOW_OPERATION: Duration = Duration::from_secs(1);
+
+/// 측정 하나의 결과
+#[derive(Debug, Clone, PartialEq)]
+pub struct PerformanceMetric {
+    pub name: String,
+    pub duration: Duration,
+    /// 모니터 생성 시점 기준 시작 시각 (Chrome trace의 ts)
+    pub start_offset: Duration,
+    /// 측정한 스레드 번호 (처음 본 순서대로 1부터, Chrome trace의 tid)
+    pub thread: u64,
+    pub metadata: Metadata,
+}
+
+impl PerformanceMetric {
+    pub fn duration_ms(&self) -> f64 {
+        self.duration.as_secs_f64() * 1000.0
+    }
+}
+
+/// 리포트 요약
+#[derive(Debug, Clone, PartialEq)]
+pub struct PerformanceSummary {
+    pub average_duration: Duration,
+    pub slowest_operation: String,
+    pub fastest_operation: String,
+    pub total_operations: usize,
+}
+
+/// 성능 리포트
+#[derive(Debug, Clone, PartialEq)]
+pub struct PerformanceReport {
+    /// 모든 메트릭 시간의 합 (중첩된 측정은 중복 합산됨)
+    pub total_duration: Duration,
+    pub metrics: Vec<PerformanceMetric>,
+    pub summary: PerformanceSummary,
+}
+
+impl PerformanceReport {
+    /// 이름이 같은 메트릭의 합계와 개수
+    pub fn total_for(&self, name: &str) -> (Duration, usize) {
+        self.metrics
+            .iter()
+            .filter(|metric| metric.name == name)
+            .fold((Duration::ZERO, 0), |(total, count), metric| (total + metric.duration, count + 1))
+    }
+}
+
+/// measure 시작 시각
+/// 이름으로 찾지 않으므로 같은 이름을 여러 스레드에서 동시에 측정해도 섞이지 않는다
+#[derive(Debug, Clone, Copy)]
+pub struct PerformanceTimer {
+    started_at: Instant,
+}
+
+/// 성능 모니터
+#[derive(Debug)]
+pub struct PerformanceMonitor {
+    enabled: bool,
+    debug: bool,
+    origin: Instant,
+    metrics: Mutex<Vec<PerformanceMetric>>,
+    start_times: Mutex<HashMap<String, Instant>>,
+    threads: Mutex<HashMap<ThreadId, u64>>,
+}
+
+impl PerformanceMonitor {
+    /// 비활성화되어 있으면 모든 측정이 아무것도 하지 않는다
+    /// I18N_PERF_DEBUG=true면 측정 시작/종료를 출력
+    pub fn new(enabled: bool) -> Self {
+        let debug = std::env::var(EnvVars::PERF_DEBUG).is_ok_and(|value| value == "true");
+        if debug && enabled {
+            eprintln!("[Performance Monitor] ✅ Initialized");
+        }
+        Self {
+            enabled,
+            debug,
+            origin: Instant::now(),
+            metrics: Mutex::new(Vec::new()),
+            start_times: Mutex::new(HashMap::new()),
+            threads: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// 이름 단위 측정 시작
+    pub fn start(&self, name: &str, metadata: &[(&str, &str)]) {
+        if !self.enabled {
+            return;
+        }
+        self.start_times.lock().unwrap().insert(name.to_string(), Instant::now());
+        if self.debug {
+            eprintln!("[Performance Monitor] 🎯 Started: {} {:?}", name, to_metadata(metadata));
+        }
+    }
+
+    /// 이름 단위 측정 종료
+    /// start하지 않은 이름이면 경고 후 None
+    pub fn end(&self, name: &str, metadata: &[(&str, &str)]) -> Option<PerformanceMetric> {
+        if !self.enabled {
+            return None;
+        }
+        let Some(started_at) = self.start_times.lock().unwrap().remove(name) else {
+            eprintln!("⚠️  Performance measurement not started for: {}", name);
+            return None;
+        };
+        Some(self.record(name, started_at, metadata))
+    }
+
+    /// 이름과 상관없는 측정 시작 (병렬 처리용)
+    pub fn timer(&self) -> PerformanceTimer {
+        PerformanceTimer {
+            started_at: Instant::now(),
+        }
+    }
+
+    /// timer로 시작한 측정 종료
+    pub fn finish(&self, name: &str, timer: PerformanceTimer, metadata: &[(&str, &str)]) -> Option<PerformanceMetric> {
+        if !self.enabled {
+            return None;
+        }
+        Some(self.record(name, timer.started_at, metadata))
+    }
+
+    /// 동기 함수 실행 시간 측정
+    pub fn measure_sync<T>(&self, name: &str, metadata: &[(&str, &str)], f: impl FnOnce() -> T) -> T {
+        let timer = self.timer();
+        let result = f();
+        self.finish(name, timer, metadata);
+        result
+    }
+
+    /// Result를 반환하는 함수의 실행 시간 측정
+    /// Err면 메타데이터에 error=true 추가
+    pub fn measure_result<T, E>(
+        &self,
+        name: &str,
+        metadata: &[(&str, &str)],
+        f: impl FnOnce() -> Result<T, E>,
+    ) -> Result<T, E> {
+        let timer = self.timer();
+        let result = f();
+        if result.is_err() {
+            let mut metadata = metadata.to_vec();
+            metadata.push(("error", "true"));
+            self.finish(name, timer, &metadata);
+        } else {
+            self.finish(name, timer, metadata);
+        }
+        result
+    }
+
+    fn record(&self, name: &str, started_at: Instant, metadata: &[(&str, &str)]) -> PerformanceMetric {
+        let duration = started_at.elapsed();
+        let metric = PerformanceMetric {
+            name: name.to_string(),
+            duration,
+            start_offset: started_at.saturating_duration_since(self.origin),
+            thread: self.thread_number(),
+            metadata: to_metadata(metadata),
+        };
+
+        // 느린 작업 경고 (1초 이상)
+        if duration > SLOW_OPERATION {
+            eprintln!(
+                "[Performance Monitor] 🐌 Slow operation detected: {} ({:.2}ms) {:?}",
+                name,
+                metric.duration_ms(),
+                metric.metadata
+            );
+        } else if self.debug {
+            eprintln!(
+                "[Performance Monitor] ✅ Finished: {} ({:.2}ms) {:?}",
+                name,
+                metric.duration_ms(),
+                metric.metadata
+            );
+        }
+
+        self.metrics.lock().unwrap().push(metric.clone());
+        metric
+    }
+
+    fn thread_number(&self) -> u64 {
+        let mut threads = self.threads.lock().unwrap();
+        let next = threads.len() as u64 + 1;
+        *threads.entry(std::thread::current().id()).or_insert(next)
+    }
+
+    /// 성능 리포트 생성 (메트릭은 시작 시각 순)
+    pub fn get_report(&self) -> PerformanceReport {
+        let mut metrics = self.metrics.lock().unwrap().clone();
+        metrics.sort_by_key(|metric| metric.start_offset);
+
+        let (Some(slowest), Some(fastest)) = (
+            metrics.iter().max_by_key(|metric| metric.duration),
+            metrics.iter().min_by_key(|metric| metric.duration),
+        ) else {
+            return PerformanceReport {
+                total_duration: Duration::ZERO,
+                metrics,
+                summary: PerformanceSummary {
+                    average_duration: Duration::ZERO,
+                    slowest_operation: "N/A".to_string(),
+                    fastest_operation: "N/A".to_string(),
+                    total_operations: 0,
+                },
+            };
+        };
+
+        let total_duration: Duration = metrics.iter().map(|metric| metric.duration).sum();
+        let summary = PerformanceSummary {
+            average_duration: total_duration / metrics.len() as u32,
+            slowest_operation: format!("{} ({:.2}ms)", slowest.name, slowest.duration_ms()),
+            fastest_operation: format!("{} ({:.2}ms)", fastest.name, fastest.duration_ms()),
+            total_operations: metrics.len(),
+        };
+        PerformanceReport {
+            total_duration,
+            metrics,
+            summary,
+        }
+    }
+
+    /// 메트릭 초기화
+    pub fn reset(&self) {
+        self.metrics.lock().unwrap().clear();
+        self.start_times.lock().unwrap().clear();
+    }
+
+    /// 성능 데이터 플러시 (프로세스 종료 전 호출)
+    /// 외부로 보내는 데이터가 없으므로 디버그 모드에서 수집 개수만 출력
+    pub fn flush(&self) {
+        if self.debug && self.enabled {
+            eprintln!(
+                "[Performance Monitor] Metrics collected: {}",
+                self.metrics.lock().unwrap().len()
+            );
+        }
+    }
+}
+
+fn to_metadata(metadata: &[(&str, &str)]) -> Metadata {
+    metadata
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
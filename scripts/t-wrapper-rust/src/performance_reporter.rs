@@ -0,0 +1,155 @@
+//! Performance Reporter
+//! `scripts/common/performance-reporter.ts` 포팅
+//!
+//! 리포트를 문자열로 만들어 반환하고, 출력 위치(표준 출력/에러)는 호출하는 쪽에서 정한다.
+
+use crate::constants::PerformanceNames;
+use crate::performance_monitor::{PerformanceMetric, PerformanceReport};
+use anyhow::Result;
+use serde_json::{json, Value};
+use std::fmt::Write;
+use std::path::Path;
+use std::time::Duration;
+
+const LINE_WIDTH: usize = 80;
+/// 완료 리포트에 보여줄 느린 파일 수
+const SLOWEST_FILES: usize = 3;
+
+pub struct PerformanceReporter;
+
+impl PerformanceReporter {
+    /// 성능 리포트
+    /// verbose면 모든 메트릭을 느린 순서로 나열
+    pub fn render_report(report: &PerformanceReport, verbose: bool) -> String {
+        if report.metrics.is_empty() {
+            return "📊 Performance monitoring disabled or no metrics collected\n".to_string();
+        }
+
+        let mut output = String::new();
+        let _ = writeln!(output, "\n📊 Performance Report");
+        let _ = writeln!(output, "{}", "═".repeat(LINE_WIDTH));
+        let _ = writeln!(output, "⏱️  Total Duration: {:.2}ms", as_ms(report.total_duration));
+        let _ = writeln!(output, "📈 Total Operations: {}", report.summary.total_operations);
+        let _ = writeln!(output, "📊 Average Duration: {:.2}ms", as_ms(report.summary.average_duration));
+        let _ = writeln!(output, "🐌 Slowest: {}", report.summary.slowest_operation);
+        let _ = writeln!(output, "⚡ Fastest: {}", report.summary.fastest_operation);
+
+        if verbose {
+            let _ = writeln!(output, "\n📋 Detailed Metrics:");
+            let _ = writeln!(output, "{}", "─".repeat(LINE_WIDTH));
+
+            // 느린 순서로 정렬
+            let mut sorted: Vec<&PerformanceMetric> = report.metrics.iter().collect();
+            sorted.sort_by_key(|metric| std::cmp::Reverse(metric.duration));
+            for (index, metric) in sorted.iter().enumerate() {
+                let _ = writeln!(
+                    output,
+                    "{}. {:<40} {:>12}",
+                    index + 1,
+                    metric.name,
+                    format!("{:.2}ms", metric.duration_ms())
+                );
+                if !metric.metadata.is_empty() {
+                    let _ = writeln!(output, "   Metadata: {:?}", metric.metadata);
+                }
+            }
+        }
+
+        let _ = writeln!(output, "{}", "═".repeat(LINE_WIDTH));
+        output
+    }
+
+    /// 작업 완료 리포트
+    /// 전체 시간, 파일당 평균, 단계별 합계, 가장 느린 파일 top 3
+    pub fn render_completion_report(
+        report: &PerformanceReport,
+        processed_files: &[String],
+        total_time: Duration,
+        title: &str,
+    ) -> String {
+        let (file_processing_time, _) = report.total_for(PerformanceNames::FILE_PROCESSING);
+        let avg_time_per_file = as_ms(file_processing_time) / processed_files.len().max(1) as f64;
+
+        let mut output = String::new();
+        let _ = writeln!(output, "\n{}", "═".repeat(LINE_WIDTH));
+        let _ = writeln!(output, "✅ {}", title);
+        let _ = writeln!(output, "{}", "═".repeat(LINE_WIDTH));
+
+        let _ = writeln!(output, "\n📊 Overall Statistics:");
+        let _ = writeln!(output, "   Total Time:        {:.0}ms", as_ms(total_time));
+        let _ = writeln!(output, "   Files Processed:   {} files", processed_files.len());
+        let _ = writeln!(output, "   Avg per File:      {:.1}ms/file", avg_time_per_file);
+
+        let phases: Vec<(&str, Duration, usize)> = PerformanceNames::PHASES
+            .iter()
+            .map(|phase| {
+                let (total, count) = report.total_for(phase);
+                (*phase, total, count)
+            })
+            .filter(|(_, _, count)| *count > 0)
+            .collect();
+        if !phases.is_empty() {
+            let _ = writeln!(output, "\n⏱️  Phase Timings:");
+            for (phase, total, count) in phases {
+                let _ = writeln!(output, "   {:<18} {:>10.1}ms  ({} ops)", phase, as_ms(total), count);
+            }
+        }
+
+        // 가장 느린 파일 top 3
+        let mut slowest_files: Vec<&PerformanceMetric> = report
+            .metrics
+            .iter()
+            .filter(|metric| metric.name == PerformanceNames::FILE_PROCESSING)
+            .collect();
+        slowest_files.sort_by_key(|metric| std::cmp::Reverse(metric.duration));
+        if !slowest_files.is_empty() {
+            let _ = writeln!(output, "\n🐌 Slowest Files:");
+            for (index, metric) in slowest_files.iter().take(SLOWEST_FILES).enumerate() {
+                let file_name = metric
+                    .metadata
+                    .get(PerformanceNames::FILE_PATH)
+                    .and_then(|path| Path::new(path).file_name())
+                    .map_or("unknown".into(), |name| name.to_string_lossy());
+                let _ = writeln!(output, "   {}. {:<40} {:.1}ms", index + 1, file_name, metric.duration_ms());
+            }
+        }
+
+        let _ = writeln!(output, "{}", "═".repeat(LINE_WIDTH));
+        output
+    }
+
+    /// Chrome trace-event 형식 (chrome://tracing, ui.perfetto.dev에서 열 수 있음)
+    /// 메트릭 하나가 complete event(`"ph": "X"`) 하나, 시간 단위는 마이크로초
+    pub fn chrome_trace(report: &PerformanceReport) -> Value {
+        let events: Vec<Value> = report
+            .metrics
+            .iter()
+            .map(|metric| {
+                json!({
+                    "name": metric.name,
+                    "cat": "t-wrapper",
+                    "ph": "X",
+                    "ts": metric.start_offset.as_micros() as u64,
+                    "dur": metric.duration.as_micros() as u64,
+                    "pid": 1,
+                    "tid": metric.thread,
+                    "args": metric.metadata,
+                })
+            })
+            .collect();
+        json!({
+            "traceEvents": events,
+            "displayTimeUnit": "ms",
+        })
+    }
+
+    /// Chrome trace-event JSON 파일 저장
+    pub fn write_chrome_trace(report: &PerformanceReport, path: &Path) -> Result<()> {
+        std::fs::write(path, serde_json::to_string(&Self::chrome_trace(report))?)?;
+        Ok(())
+    }
+}
+
+fn as_ms(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
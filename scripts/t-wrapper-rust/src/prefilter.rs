@@ -0,0 +1,82 @@
+//! 파싱 전 사전 필터
+//! 바뀔 수 없는 파일(한국어가 없음)과 변환하면 안 되는 파일(생성/번들/minified/너무 큰 파일)을
+//! SWC로 파싱하기 전에 바이트 단위 검사로 거른다
+
+use crate::constants::{PrefilterLimits, RegexPatterns};
+use serde::Serialize;
+
+/// 파싱하지 않고 건너뛴 이유
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FileSkipReason {
+    /// max_file_size보다 큰 파일
+    TooLarge,
+    /// 앞부분에 `@generated` 표시가 있는 생성 파일
+    Generated,
+    /// 앞부분에 `/* eslint-disable */`가 있는 번들/벤더 파일
+    EslintDisabled,
+    /// 줄이 매우 긴 minified 파일
+    Minified,
+    /// 한국어(가-힣)가 없어서 바뀔 수 없는 파일
+    NoKorean,
+    /// 이전 실행 이후 내용과 설정이 같아 바뀌지 않는 파일 (증분 실행 캐시)
+    Cached,
+}
+
+/// 파일 내용으로 건너뛸 이유 판단 (건너뛰지 않으면 None)
+/// 생성/번들/minified 파일은 한국어가 있어도 건너뛴다
+pub fn prefilter_skip_reason(code: &str) -> Option<FileSkipReason> {
+    let header = file_header(code);
+    if header.contains("@generated") {
+        return Some(FileSkipReason::Generated);
+    }
+    if RegexPatterns::eslint_disable_banner().is_match(header) {
+        return Some(FileSkipReason::EslintDisabled);
+    }
+    if is_minified(code) {
+        return Some(FileSkipReason::Minified);
+    }
+    if !may_contain_korean(code.as_bytes()) {
+        return Some(FileSkipReason::NoKorean);
+    }
+    None
+}
+
+/// 한국어가 있을 수 있는지 바이트 단위로 검사
+///
+/// 가-힣(U+AC00..=U+D7A3)의 UTF-8 인코딩(`EA B0 80` ..= `ED 9E A3`)을 찾는다.
+/// 이스케이프(`\uAC00`, `&#44032;`)로 쓴 한국어는 바이트로 알 수 없으므로
+/// `\u`나 `&#`가 있으면 있다고 본다 (파싱해서 확인).
+pub fn may_contain_korean(bytes: &[u8]) -> bool {
+    bytes.iter().enumerate().any(|(index, &byte)| match byte {
+        0xEA => bytes.get(index + 1).is_some_and(|&next| next >= 0xB0),
+        0xEB | 0xEC => true,
+        0xED => bytes.get(index + 1).is_some_and(|&next| next <= 0x9E),
+        b'\\' => bytes.get(index + 1) == Some(&b'u'),
+        b'&' => bytes.get(index + 1) == Some(&b'#'),
+        _ => false,
+    })
+}
+
+/// minified 파일인지 줄 길이로 판단
+/// 아주 긴 줄이 있고 평균 줄 길이도 길어야 한다 (긴 data URI 한 줄이 있는 일반 파일은 제외)
+pub fn is_minified(code: &str) -> bool {
+    let mut line_count = 0;
+    let mut longest_line = 0;
+    for line in code.lines() {
+        line_count += 1;
+        longest_line = longest_line.max(line.len());
+    }
+    line_count > 0
+        && longest_line > PrefilterLimits::MINIFIED_LONGEST_LINE
+        && code.len() / line_count > PrefilterLimits::MINIFIED_AVERAGE_LINE
+}
+
+/// `@generated`, `/* eslint-disable */`를 찾을 파일 앞부분
+fn file_header(code: &str) -> &str {
+    let mut end = code.len().min(PrefilterLimits::HEADER_LENGTH);
+    while !code.is_char_boundary(end) {
+        end -= 1;
+    }
+    &code[..end]
+}
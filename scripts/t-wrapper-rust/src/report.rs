@@ -0,0 +1,82 @@
+//! 변환 리포트
+//! 파일별 변환 후보 문자열 기록을 모아 `--report json`으로 출력
+
+use crate::ast_transformers::{StringRecord, TransformResult};
+use crate::prefilter::FileSkipReason;
+use anyhow::Result;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// 전체 요약
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ReportSummary {
+    pub files_processed: usize,
+    pub files_modified: usize,
+    pub strings_wrapped: usize,
+    pub strings_skipped: usize,
+    /// 파싱 전에 건너뛴 파일 수 (사전 필터)
+    pub files_skipped: usize,
+    /// 건너뛴 이유별 파일 수
+    pub files_skipped_by_reason: BTreeMap<FileSkipReason, usize>,
+}
+
+/// 파일 하나의 리포트
+#[derive(Debug, Clone, Serialize)]
+pub struct FileReport {
+    pub file: String,
+    pub modified: bool,
+    pub wrapped: usize,
+    pub skipped: usize,
+    pub strings: Vec<StringRecord>,
+}
+
+/// 실행 전체의 변환 리포트
+/// 후보 문자열이 하나도 없는 파일은 files에 넣지 않는다
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct TransformReport {
+    pub summary: ReportSummary,
+    pub files: Vec<FileReport>,
+}
+
+impl TransformReport {
+    pub fn new(files_processed: usize) -> Self {
+        Self {
+            summary: ReportSummary {
+                files_processed,
+                ..Default::default()
+            },
+            files: Vec::new(),
+        }
+    }
+
+    /// 파일 변환 결과 추가 (TransformResult::locate로 위치를 채운 뒤 호출)
+    pub fn add_file(&mut self, file: impl Into<String>, result: &TransformResult) {
+        let wrapped = result.wrapped_count();
+        let skipped = result.skipped_count();
+        if result.was_modified {
+            self.summary.files_modified += 1;
+        }
+        self.summary.strings_wrapped += wrapped;
+        self.summary.strings_skipped += skipped;
+        if result.records.is_empty() {
+            return;
+        }
+        self.files.push(FileReport {
+            file: file.into(),
+            modified: result.was_modified,
+            wrapped,
+            skipped,
+            strings: result.records.clone(),
+        });
+    }
+
+    /// 사전 필터로 건너뛴 파일 추가
+    pub fn add_skipped_file(&mut self, reason: FileSkipReason) {
+        self.summary.files_skipped += 1;
+        *self.summary.files_skipped_by_reason.entry(reason).or_default() += 1;
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
@@ -0,0 +1,288 @@
+//! 처리 세션
+//! 한 번의 실행에서 SourceMap, 주석 저장소, GLOBALS를 하나씩만 만들어 모든 파일이 공유
+//!
+//! 모든 파일이 같은 SourceMap에 추가되므로 BytePos가 실행 전체에서 겹치지 않는다.
+//! 그래서 파싱에서 얻은 span을 그대로 코드 생성, 주석 조회(i18n-ignore), 리포트/진단의 줄/열 변환에 쓸 수 있다.
+
+use crate::parser::{emit_module, parse_source_file, ParseOptions, DEFAULT_FILE_NAME};
+use anyhow::Result;
+use std::sync::Mutex;
+use swc_common::comments::{
+    Comment, CommentKind, Comments, SingleThreadedComments, SingleThreadedCommentsMapInner,
+};
+use swc_common::{sync::Lrc, BytePos, FileName, Globals, SourceFile, SourceMap, Spanned, DUMMY_SP, GLOBALS};
+use swc_ecma_ast::Module;
+
+/// 1부터 시작하는 줄/열 (열은 문자 단위)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineCol {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// SourceMap 기준 위치를 줄/열로 변환
+pub fn line_col(cm: &SourceMap, pos: BytePos) -> LineCol {
+    let loc = cm.lookup_char_pos(pos);
+    LineCol {
+        line: loc.line,
+        column: loc.col.0 + 1,
+    }
+}
+
+/// 실행 전체가 공유하는 주석 저장소
+/// 파일마다 파싱한 주석을 한 번에 옮겨 담고, 여러 스레드에서 조회/출력할 수 있도록 Mutex로 보호
+#[derive(Debug, Default)]
+pub struct SessionComments {
+    leading: Mutex<SingleThreadedCommentsMapInner>,
+    trailing: Mutex<SingleThreadedCommentsMapInner>,
+}
+
+impl SessionComments {
+    /// 파일 하나를 파싱하며 모은 주석을 저장소로 옮김
+    fn extend(&self, comments: SingleThreadedComments) {
+        let (leading, trailing) = comments.take_all();
+        self.leading.lock().unwrap().extend(leading.take());
+        self.trailing.lock().unwrap().extend(trailing.take());
+    }
+}
+
+impl Comments for SessionComments {
+    fn add_leading(&self, pos: BytePos, cmt: Comment) {
+        self.leading.lock().unwrap().entry(pos).or_default().push(cmt);
+    }
+
+    fn add_leading_comments(&self, pos: BytePos, comments: Vec<Comment>) {
+        self.leading.lock().unwrap().entry(pos).or_default().extend(comments);
+    }
+
+    fn has_leading(&self, pos: BytePos) -> bool {
+        self.leading
+            .lock()
+            .unwrap()
+            .get(&pos)
+            .is_some_and(|comments| !comments.is_empty())
+    }
+
+    fn move_leading(&self, from: BytePos, to: BytePos) {
+        if let Some(mut comments) = self.take_leading(from) {
+            if from < to {
+                comments.extend(self.take_leading(to).unwrap_or_default());
+            }
+            self.add_leading_comments(to, comments);
+        }
+    }
+
+    fn take_leading(&self, pos: BytePos) -> Option<Vec<Comment>> {
+        self.leading.lock().unwrap().remove(&pos)
+    }
+
+    fn get_leading(&self, pos: BytePos) -> Option<Vec<Comment>> {
+        self.leading.lock().unwrap().get(&pos).cloned()
+    }
+
+    fn add_trailing(&self, pos: BytePos, cmt: Comment) {
+        self.trailing.lock().unwrap().entry(pos).or_default().push(cmt);
+    }
+
+    fn add_trailing_comments(&self, pos: BytePos, comments: Vec<Comment>) {
+        self.trailing.lock().unwrap().entry(pos).or_default().extend(comments);
+    }
+
+    fn has_trailing(&self, pos: BytePos) -> bool {
+        self.trailing
+            .lock()
+            .unwrap()
+            .get(&pos)
+            .is_some_and(|comments| !comments.is_empty())
+    }
+
+    fn move_trailing(&self, from: BytePos, to: BytePos) {
+        if let Some(mut comments) = self.take_trailing(from) {
+            if from < to {
+                comments.extend(self.take_trailing(to).unwrap_or_default());
+            }
+            self.add_trailing_comments(to, comments);
+        }
+    }
+
+    fn take_trailing(&self, pos: BytePos) -> Option<Vec<Comment>> {
+        self.trailing.lock().unwrap().remove(&pos)
+    }
+
+    fn get_trailing(&self, pos: BytePos) -> Option<Vec<Comment>> {
+        self.trailing.lock().unwrap().get(&pos).cloned()
+    }
+
+    fn add_pure_comment(&self, pos: BytePos) {
+        let mut leading = self.leading.lock().unwrap();
+        let comments = leading.entry(pos).or_default();
+        let pure = Comment {
+            kind: CommentKind::Block,
+            span: DUMMY_SP,
+            text: "#__PURE__".into(),
+        };
+        if !comments.iter().any(|comment| comment.text == pure.text) {
+            comments.push(pure);
+        }
+    }
+}
+
+/// 세션에 추가한 파일 하나
+/// 파일 안 바이트 오프셋과 세션 전체 기준 BytePos, 줄/열을 서로 변환한다
+#[derive(Clone)]
+pub struct SourceFileHandle {
+    cm: Lrc<SourceMap>,
+    file: Lrc<SourceFile>,
+}
+
+impl std::fmt::Debug for SourceFileHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SourceFileHandle")
+            .field("name", &self.name())
+            .field("start_pos", &self.file.start_pos)
+            .finish()
+    }
+}
+
+impl SourceFileHandle {
+    /// 파싱할 때 사용한 파일 이름 (ParseOptions::file_name)
+    pub fn name(&self) -> String {
+        self.file.name.to_string()
+    }
+
+    /// 원본 코드
+    pub fn source(&self) -> &str {
+        &self.file.src
+    }
+
+    /// 파일 첫 바이트의 BytePos (TextEdits::apply의 base)
+    pub fn start_pos(&self) -> BytePos {
+        self.file.start_pos
+    }
+
+    /// BytePos → 파일 안 바이트 오프셋
+    pub fn offset(&self, pos: BytePos) -> usize {
+        (pos - self.file.start_pos).0 as usize
+    }
+
+    /// 파일 안 바이트 오프셋 → BytePos
+    pub fn pos(&self, offset: usize) -> BytePos {
+        self.file.start_pos + BytePos(offset as u32)
+    }
+
+    /// BytePos → 줄/열
+    pub fn line_col(&self, pos: BytePos) -> LineCol {
+        let loc = self.cm.lookup_char_pos_with(self.file.clone(), pos);
+        LineCol {
+            line: loc.line,
+            column: loc.col.0 + 1,
+        }
+    }
+
+    /// 파일 안 바이트 오프셋 → 줄/열
+    pub fn offset_line_col(&self, offset: usize) -> LineCol {
+        self.line_col(self.pos(offset))
+    }
+}
+
+/// 처리 세션
+/// 여러 스레드에서 동시에 파일을 추가/파싱/출력할 수 있다
+#[derive(Default)]
+pub struct Session {
+    cm: Lrc<SourceMap>,
+    comments: SessionComments,
+    globals: Globals,
+}
+
+impl Session {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn source_map(&self) -> &Lrc<SourceMap> {
+        &self.cm
+    }
+
+    /// 모든 파일의 주석 (i18n-ignore 판단, 코드 생성에 사용)
+    pub fn comments(&self) -> &SessionComments {
+        &self.comments
+    }
+
+    /// 세션의 GLOBALS를 설정하고 실행
+    pub fn with_globals<R>(&self, f: impl FnOnce() -> R) -> R {
+        GLOBALS.set(&self.globals, f)
+    }
+
+    /// 파일을 세션에 추가하고 파싱
+    /// 파일 이름은 `options.file_name` (진단 메시지와 SourceFileHandle::name에 사용)
+    /// 파싱에 실패해도 파일은 세션에 남는다
+    pub fn parse_file(&self, code: &str, options: &ParseOptions) -> Result<(Module, SourceFileHandle)> {
+        let file_name = options.file_name.as_deref().unwrap_or(DEFAULT_FILE_NAME);
+        let filename: Lrc<FileName> = FileName::Custom(file_name.into()).into();
+        let file = self.cm.new_source_file(filename, code.to_string());
+
+        let comments = SingleThreadedComments::default();
+        let module = self.with_globals(|| parse_source_file(&self.cm, &file, options, &comments))?;
+        self.comments.extend(comments);
+        Ok((
+            module,
+            SourceFileHandle {
+                cm: self.cm.clone(),
+                file,
+            },
+        ))
+    }
+
+    /// 원본 주석을 유지하며 코드 생성
+    /// 출력한 주석은 저장소에서 빠지므로 같은 Module을 두 번 출력하면 두 번째에는 주석이 없다
+    ///
+    /// 파일 헤더 주석(라이선스/저작권 등)은 첫 구문 앞에 새 구문을 넣어도 맨 위(shebang 다음)에 원문 그대로 출력
+    pub fn generate_code(&self, module: &Module) -> Result<String> {
+        let header = self.take_header_comments(module);
+        let code = emit_module(module, &self.cm, Some(&self.comments))?;
+        let Some(header) = header else {
+            return Ok(code);
+        };
+        let (shebang, code) = match module.shebang {
+            Some(_) => code.split_once('\n').map_or((code.as_str(), ""), |(shebang, rest)| (shebang, rest)),
+            None => ("", code.as_str()),
+        };
+        let shebang = if shebang.is_empty() { String::new() } else { format!("{}\n", shebang) };
+        Ok(format!("{}{}\n\n{}", shebang, header, code))
+    }
+
+    /// 원본 첫 구문의 leading 주석 중 파일 헤더를 저장소에서 꺼내 원문 텍스트로 반환
+    ///
+    /// 헤더는 빈 줄로 구문과 떨어진 주석까지로 본다.
+    /// 구문 바로 위에 붙은 주석(JSDoc, eslint-disable-next-line 등)은 구문과 함께 출력되도록 남긴다
+    fn take_header_comments(&self, module: &Module) -> Option<String> {
+        let first_pos = module.body.iter().map(|item| item.span().lo).find(|pos| !pos.is_dummy())?;
+        let comments = self.comments.get_leading(first_pos)?;
+        let file = self.cm.lookup_byte_offset(first_pos).sf;
+        let source = file.src.as_str();
+        let offset = |pos: BytePos| (pos.0 - file.start_pos.0) as usize;
+        let is_blank_line_between = |lo: BytePos, hi: BytePos| {
+            source
+                .get(offset(lo)..offset(hi))
+                .is_some_and(|gap| gap.matches('\n').count() >= 2)
+        };
+
+        let header_len = (0..comments.len()).rev().find(|&index| {
+            let next = comments.get(index + 1).map_or(first_pos, |comment| comment.span.lo);
+            is_blank_line_between(comments[index].span.hi, next)
+        })? + 1;
+        let header = source.get(offset(comments[0].span.lo)..offset(comments[header_len - 1].span.hi))?.to_string();
+
+        self.comments.take_leading(first_pos);
+        let attached = comments[header_len..].to_vec();
+        if !attached.is_empty() {
+            self.comments.add_leading_comments(first_pos, attached);
+        }
+        Some(header)
+    }
+
+    /// 세션 전체 기준 BytePos → 줄/열
+    pub fn line_col(&self, pos: BytePos) -> LineCol {
+        line_col(&self.cm, pos)
+    }
+}
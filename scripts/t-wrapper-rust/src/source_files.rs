@@ -0,0 +1,253 @@
+//! 처리 대상 파일 수집
+//! glob 크레이트는 `{a,b}` 패턴을 지원하지 않으므로 brace expansion 후 패턴별로 매칭
+
+use crate::constants::StringConstants;
+use crate::import_graph::normalize_path;
+use anyhow::{bail, Context, Result};
+use glob::{glob_with, MatchOptions, Pattern};
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use ignore::Match;
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+
+/// 파일 수집 옵션
+#[derive(Debug, Clone, Default)]
+pub struct SourceFileOptions {
+    /// include 패턴 목록 (`src/**/*.{ts,tsx}`)
+    pub include_patterns: Vec<String>,
+    /// exclude 패턴 목록 (`**/*.test.tsx`, `**/*.stories.tsx`)
+    pub exclude_patterns: Vec<String>,
+    /// `.gitignore`에 해당하는 파일 제외
+    pub respect_gitignore: bool,
+}
+
+/// glob 매칭 옵션
+/// `*`는 경로 구분자를 넘지 않고, `**`만 디렉토리를 넘는다
+fn match_options() -> MatchOptions {
+    MatchOptions {
+        case_sensitive: true,
+        require_literal_separator: true,
+        require_literal_leading_dot: false,
+    }
+}
+
+/// `{a,b}` 패턴 확장 (중첩 지원)
+///
+/// - `src/**/*.{ts,tsx}` → [`src/**/*.ts`, `src/**/*.tsx`]
+/// - `{app,pages}/**/*.{js,jsx}` → 4개
+/// - 짝이 맞지 않는 `{`나 `,`가 없는 `{a}`는 그대로 둔다
+pub fn expand_braces(pattern: &str) -> Vec<String> {
+    let Some((open, close)) = find_brace_group(pattern) else {
+        return vec![pattern.to_string()];
+    };
+    let prefix = &pattern[..open];
+    let suffix = &pattern[close + 1..];
+    split_alternatives(&pattern[open + 1..close])
+        .into_iter()
+        .flat_map(|alternative| expand_braces(&format!("{}{}{}", prefix, alternative, suffix)))
+        .collect()
+}
+
+/// 확장할 첫 번째 `{...}` 그룹의 위치 (최상위 `,`가 있는 그룹만)
+fn find_brace_group(pattern: &str) -> Option<(usize, usize)> {
+    let bytes = pattern.as_bytes();
+    let mut start = 0;
+    while let Some(offset) = pattern[start..].find('{') {
+        let open = start + offset;
+        let mut depth = 0;
+        let mut has_comma = false;
+        for (index, &byte) in bytes.iter().enumerate().skip(open) {
+            match byte {
+                b'{' => depth += 1,
+                b'}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        if has_comma {
+                            return Some((open, index));
+                        }
+                        break;
+                    }
+                }
+                b',' if depth == 1 => has_comma = true,
+                _ => {}
+            }
+        }
+        start = open + 1;
+    }
+    None
+}
+
+/// 최상위 `,` 기준으로 분리 (`a,{b,c}` → [`a`, `{b,c}`])
+fn split_alternatives(group: &str) -> Vec<&str> {
+    let mut alternatives = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (index, c) in group.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            ',' if depth == 0 => {
+                alternatives.push(&group[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    alternatives.push(&group[start..]);
+    alternatives
+}
+
+/// 기본으로 건너뛰는 디렉토리(node_modules, .next, dist) 아래의 경로인지 확인
+///
+/// `base`(패턴의 glob 문자 앞 디렉토리) 아래의 경로 부분만 본다.
+/// `/home/me/dist/app/src/**/*.ts`처럼 패턴 자체가 이런 이름의 디렉토리 안을 가리켜도 파일을 찾을 수 있다
+pub fn is_in_default_skip_dir(path: &Path, base: &Path) -> bool {
+    let below_base = path.strip_prefix(base).unwrap_or(path);
+    below_base.components().any(|component| {
+        StringConstants::DEFAULT_SKIP_DIRS
+            .iter()
+            .any(|dir| component.as_os_str() == *dir)
+    })
+}
+
+/// 파일 시스템을 보지 않고 만든 절대 경로 (`.`, `..` 정리)
+/// 감시 모드에서 이벤트 경로와 수집한 파일 경로를 비교할 때 사용
+pub fn absolute_path(path: &Path) -> PathBuf {
+    normalize_path(&std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf()))
+}
+
+/// brace expansion을 마친 패턴 하나에서 glob 문자가 나오기 전까지의 디렉토리
+/// (`*.tsx`처럼 디렉토리가 없으면 빈 경로)
+fn pattern_base_dir(pattern: &str) -> PathBuf {
+    let path = Path::new(pattern);
+    let mut base: PathBuf = path
+        .components()
+        .take_while(|component| {
+            !component
+                .as_os_str()
+                .to_string_lossy()
+                .contains(['*', '?', '['])
+        })
+        .collect();
+    // glob 문자가 없는 패턴은 파일 하나이므로 그 디렉토리
+    if base == path {
+        base.pop();
+    }
+    base
+}
+
+/// include 패턴에서 glob 문자가 나오기 전까지의 디렉토리 (감시 모드에서 감시할 디렉토리)
+///
+/// - `src/**/*.{ts,tsx}` → `src`
+/// - `*.tsx` → `.`
+/// - 다른 디렉토리 아래에 있는 디렉토리는 상위 디렉토리를 감시하므로 제외
+pub fn pattern_base_dirs(include_patterns: &[String]) -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = include_patterns
+        .iter()
+        .flat_map(|pattern| expand_braces(pattern))
+        .map(|pattern| {
+            let base = pattern_base_dir(&pattern);
+            if base.as_os_str().is_empty() {
+                PathBuf::from(".")
+            } else {
+                base
+            }
+        })
+        .collect();
+    dirs.sort();
+    dirs.dedup();
+    let mut roots: Vec<PathBuf> = Vec::new();
+    for dir in dirs {
+        if !roots.iter().any(|root| absolute_path(&dir).starts_with(absolute_path(root))) {
+            roots.push(dir);
+        }
+    }
+    roots
+}
+
+/// 처리 대상 파일 수집
+///
+/// 1. include 패턴마다 brace expansion 후 glob 매칭
+/// 2. node_modules, .next, dist 아래 파일 제외 (패턴의 glob 문자 앞 디렉토리 아래 경로만 확인)
+/// 3. exclude 패턴에 매칭되는 파일 제외 (전체 경로 또는 현재 디렉토리 기준 상대 경로)
+/// 4. respect_gitignore면 `.gitignore`에 해당하는 파일 제외
+///
+/// 결과는 정렬되어 있고 중복이 없다
+pub fn collect_source_files(options: &SourceFileOptions) -> Result<Vec<PathBuf>> {
+    let excludes = options
+        .exclude_patterns
+        .iter()
+        .flat_map(|pattern| expand_braces(pattern))
+        .map(|pattern| {
+            Pattern::new(&pattern).with_context(|| format!("Invalid exclude pattern: {}", pattern))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let current_dir = std::env::current_dir().ok();
+
+    let mut files = BTreeSet::new();
+    for pattern in options.include_patterns.iter().flat_map(|pattern| expand_braces(pattern)) {
+        let entries = match glob_with(&pattern, match_options()) {
+            Ok(entries) => entries,
+            Err(e) => bail!("Invalid source pattern {}: {}", pattern, e),
+        };
+        let base = pattern_base_dir(&pattern);
+        for path in entries.filter_map(|entry| entry.ok()) {
+            if path.is_file() && !is_in_default_skip_dir(&path, &base) {
+                files.insert(path);
+            }
+        }
+    }
+
+    let is_excluded = |path: &Path| {
+        let relative = current_dir
+            .as_deref()
+            .and_then(|current_dir| path.strip_prefix(current_dir).ok());
+        excludes.iter().any(|exclude| {
+            exclude.matches_path_with(path, match_options())
+                || relative.is_some_and(|relative| exclude.matches_path_with(relative, match_options()))
+        })
+    };
+
+    let mut gitignores = GitignoreCache::default();
+    Ok(files
+        .into_iter()
+        .filter(|path| !is_excluded(path))
+        .filter(|path| !options.respect_gitignore || !gitignores.is_ignored(path))
+        .collect())
+}
+
+/// 디렉토리별 `.gitignore` 캐시
+#[derive(Default)]
+struct GitignoreCache {
+    by_dir: HashMap<PathBuf, Option<Gitignore>>,
+}
+
+impl GitignoreCache {
+    /// 파일에서 가까운 `.gitignore`부터 저장소 루트(`.git`이 있는 디렉토리)까지 확인
+    /// 가까운 파일의 규칙(`!` 포함)이 우선한다
+    fn is_ignored(&mut self, path: &Path) -> bool {
+        let absolute = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+        for dir in absolute.ancestors().skip(1) {
+            let gitignore = self.by_dir.entry(dir.to_path_buf()).or_insert_with(|| {
+                let gitignore_path = dir.join(StringConstants::GITIGNORE_FILE);
+                if !gitignore_path.is_file() {
+                    return None;
+                }
+                let mut builder = GitignoreBuilder::new(dir);
+                builder.add(&gitignore_path);
+                builder.build().ok()
+            });
+            if let Some(gitignore) = gitignore {
+                match gitignore.matched_path_or_any_parents(&absolute, false) {
+                    Match::Ignore(_) => return true,
+                    Match::Whitelist(_) => return false,
+                    Match::None => {}
+                }
+            }
+            if dir.join(".git").exists() {
+                break;
+            }
+        }
+        false
+    }
+}
@@ -0,0 +1,277 @@
+//! 원본 소스 텍스트 패치 (minimal diff 출력)
+//! 모듈 전체를 다시 출력하지 않고, 바뀐 노드의 원본 span 범위만 교체하여
+//! 건드리지 않은 코드는 바이트 단위로 그대로 유지
+
+use anyhow::{bail, Result};
+use swc_common::{BytePos, EqIgnoreSpan, Span, Spanned};
+use swc_ecma_ast::ModuleItem;
+use swc_ecma_codegen::{to_code, Node};
+
+/// 원본 텍스트의 `[lo, hi)` 범위를 `text`로 교체 (lo == hi면 삽입)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    pub lo: BytePos,
+    pub hi: BytePos,
+    pub text: String,
+    /// 최상위 구문 삽입 (import/require/디렉티브)
+    /// 같은 위치의 다른 삽입(`async ` 등 구문 안쪽 편집)보다 먼저 적용
+    pub is_module_item: bool,
+    /// 코드 생성 결과 (적용할 때 둘째 줄부터 원본 줄의 들여쓰기로 맞춤, SourceText::reindent)
+    pub is_generated: bool,
+}
+
+impl TextEdit {
+    fn is_insert(&self) -> bool {
+        self.lo == self.hi
+    }
+}
+
+/// 원본 위치(BytePos) 기준 편집 목록
+#[derive(Debug, Clone, Default)]
+pub struct TextEdits {
+    edits: Vec<TextEdit>,
+}
+
+impl TextEdits {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// span 범위를 교체
+    /// 범위 안에 이미 기록된 편집은 제거한다
+    /// (바깥 노드를 다시 출력하면 안쪽 변경도 함께 출력되므로)
+    pub fn replace(&mut self, span: Span, text: impl Into<String>) {
+        self.edits.retain(|edit| {
+            let is_inside = span.lo <= edit.lo && edit.hi <= span.hi;
+            let is_boundary_insert = edit.is_insert() && (edit.lo == span.lo || edit.lo == span.hi);
+            !is_inside || is_boundary_insert
+        });
+        self.edits.push(TextEdit {
+            lo: span.lo,
+            hi: span.hi,
+            text: text.into(),
+            is_module_item: false,
+            is_generated: false,
+        });
+    }
+
+    /// span 범위를 코드 생성 결과(node_code)로 교체
+    /// 여러 줄이면 적용할 때 둘째 줄부터 원본 줄의 들여쓰기와 들여쓰기 단위로 바꾼다
+    /// (`t("...", {\n    name\n})`가 2칸 들여쓰기 파일에 4칸으로 들어가지 않도록)
+    pub fn replace_code(&mut self, span: Span, text: impl Into<String>) {
+        self.replace(span, text);
+        if let Some(edit) = self.edits.last_mut() {
+            edit.is_generated = true;
+        }
+    }
+
+    /// `pos` 위치에 삽입
+    /// 같은 위치의 삽입은 기록한 순서대로 적용된다
+    pub fn insert(&mut self, pos: BytePos, text: impl Into<String>) {
+        self.edits.push(TextEdit {
+            lo: pos,
+            hi: pos,
+            text: text.into(),
+            is_module_item: false,
+            is_generated: false,
+        });
+    }
+
+    /// `pos` 위치에 최상위 구문 삽입
+    /// 같은 위치에서는 기록 순서와 상관없이 insert보다 먼저 적용된다
+    /// (첫 구문 앞의 import가 그 구문의 `async ` 삽입보다 앞에 와야 함)
+    pub fn insert_module_item(&mut self, pos: BytePos, text: impl Into<String>) {
+        self.edits.push(TextEdit {
+            lo: pos,
+            hi: pos,
+            text: text.into(),
+            is_module_item: true,
+            is_generated: false,
+        });
+    }
+
+    pub fn extend(&mut self, other: TextEdits) {
+        self.edits.extend(other.edits);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.edits.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.edits.len()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &TextEdit> {
+        self.edits.iter()
+    }
+
+    /// 원본 텍스트에 편집 적용
+    ///
+    /// `base`는 원본 파일의 SourceMap 시작 위치 (SourceFile::start_pos)
+    /// 같은 위치에서는 최상위 구문 삽입, 나머지 삽입(기록 순서), 교체 순으로 적용된다.
+    /// 범위가 겹치거나 원본 밖이면 에러
+    pub fn apply(&self, source: &str, base: BytePos) -> Result<String> {
+        let mut edits: Vec<&TextEdit> = self.edits.iter().collect();
+        edits.sort_by_key(|edit| (edit.lo, !edit.is_insert(), !edit.is_module_item));
+
+        let source_text = SourceText::new(source, base);
+        let mut output = String::with_capacity(source.len() + edits.len() * 16);
+        let mut cursor = 0;
+        for edit in edits {
+            let (Some(start), Some(end)) = (offset_of(base, edit.lo), offset_of(base, edit.hi)) else {
+                bail!("Text edit out of range: {:?}..{:?}", edit.lo, edit.hi);
+            };
+            if start < cursor || end > source.len() || !source.is_char_boundary(start) || !source.is_char_boundary(end) {
+                bail!("Overlapping or invalid text edit at {}..{}", start, end);
+            }
+            output.push_str(&source[cursor..start]);
+            if edit.is_generated {
+                output.push_str(&source_text.reindent(&edit.text, edit.lo));
+            } else {
+                output.push_str(&edit.text);
+            }
+            cursor = end;
+        }
+        output.push_str(&source[cursor..]);
+        Ok(output)
+    }
+}
+
+fn offset_of(base: BytePos, pos: BytePos) -> Option<usize> {
+    pos.0.checked_sub(base.0).map(|offset| offset as usize)
+}
+
+/// 원본 텍스트와 SourceMap 시작 위치
+/// 삽입할 코드의 들여쓰기를 원본에 맞추는 데 사용
+#[derive(Debug, Clone, Copy)]
+pub struct SourceText<'a> {
+    pub code: &'a str,
+    pub base: BytePos,
+}
+
+impl<'a> SourceText<'a> {
+    pub fn new(code: &'a str, base: BytePos) -> Self {
+        Self { code, base }
+    }
+
+    fn offset(&self, pos: BytePos) -> usize {
+        offset_of(self.base, pos).unwrap_or(0).min(self.code.len())
+    }
+
+    fn line_start(&self, pos: BytePos) -> usize {
+        let offset = self.offset(pos);
+        self.code[..offset].rfind('\n').map_or(0, |index| index + 1)
+    }
+
+    /// `pos`가 있는 줄의 들여쓰기
+    pub fn line_indent(&self, pos: BytePos) -> &'a str {
+        let start = self.line_start(pos);
+        let line = &self.code[start..];
+        let width = line.len() - line.trim_start_matches([' ', '\t']).len();
+        &line[..width]
+    }
+
+    /// 한 단계 들여쓰기
+    /// `pos`가 있는 줄과 그보다 덜 들여쓴 가장 가까운 앞 줄의 차이 (`<div>` 8칸 안의 `<h1>` 12칸 → 4칸).
+    /// 그런 줄이 없으면 탭을 쓰는 줄은 탭, 아니면 공백 2칸
+    pub fn indent_unit(&self, pos: BytePos) -> &'a str {
+        let indent = self.line_indent(pos);
+        let start = self.line_start(pos);
+        let outer = self.code[..start]
+            .lines()
+            .rev()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.len() - line.trim_start_matches([' ', '\t']).len())
+            .find(|width| *width < indent.len());
+        match outer {
+            Some(width) => &indent[width..],
+            None if indent.starts_with('\t') => "\t",
+            None => "  ",
+        }
+    }
+
+    /// `pos`가 있는 줄의 끝 위치 (줄바꿈 바로 앞, 같은 줄의 trailing 주석 뒤)
+    pub fn line_end(&self, pos: BytePos) -> BytePos {
+        let offset = self.offset(pos);
+        let end = self.code[offset..]
+            .find('\n')
+            .map_or(self.code.len(), |index| offset + index);
+        let end = if self.code[..end].ends_with('\r') { end - 1 } else { end };
+        self.base + BytePos(end as u32)
+    }
+
+    /// 코드 생성 결과의 둘째 줄부터를 `pos`가 있는 줄 기준으로 다시 들여쓰기
+    ///
+    /// 코드 생성기는 한 단계를 공백 4칸으로 출력하므로, 4칸마다 indent_unit 하나로 바꾸고
+    /// 앞에 원본 줄의 들여쓰기를 붙인다.
+    /// 템플릿 리터럴 안의 줄바꿈은 문자열 내용이므로 백틱이 있으면 그대로 둔다
+    pub fn reindent(&self, code: &str, pos: BytePos) -> String {
+        if !code.contains('\n') || code.contains('`') {
+            return code.to_string();
+        }
+        let indent = self.line_indent(pos);
+        let unit = self.indent_unit(pos);
+        let mut lines = code.split('\n');
+        let mut output = lines.next().unwrap_or_default().to_string();
+        for line in lines {
+            let content = line.trim_start_matches(' ');
+            let width = line.len() - content.len();
+            output.push('\n');
+            if !content.is_empty() {
+                output.push_str(indent);
+                output.push_str(&unit.repeat(width / 4));
+                output.push_str(&" ".repeat(width % 4));
+            }
+            output.push_str(content);
+        }
+        output
+    }
+
+    /// 두 위치가 같은 줄인지 확인
+    pub fn is_same_line(&self, a: BytePos, b: BytePos) -> bool {
+        let (a, b) = (self.offset(a), self.offset(b));
+        let (start, end) = if a <= b { (a, b) } else { (b, a) };
+        !self.code[start..end].contains('\n')
+    }
+}
+
+/// 노드를 코드로 출력 (주석 없이, 끝 줄바꿈 제거)
+pub fn node_code(node: &impl Node) -> String {
+    to_code(node).trim_end().to_string()
+}
+
+/// 모듈 최상위 구문 목록의 변경을 편집으로 기록
+///
+/// "use client" 디렉티브 추가, import/require 추가·수정처럼
+/// 최상위 구문 단위로 일어난 변경만 대상으로 한다.
+/// - span이 같은 구문은 내용이 바뀌었을 때만 그 구문을 다시 출력
+/// - 새 구문은 앞 원본 구문이 있으면 그 줄 끝(trailing 주석 뒤)에, 없으면 다음 원본 구문 바로 앞(주석 뒤)에 한 줄로 삽입
+///   (앞 구문과 다음 구문이 같은 줄이면 다음 구문 바로 앞)
+pub fn record_module_item_edits(
+    before: &[ModuleItem],
+    after: &[ModuleItem],
+    source: SourceText<'_>,
+    edits: &mut TextEdits,
+) {
+    let mut index = 0;
+    for item in after {
+        if let Some(original) = before.get(index).filter(|original| original.span() == item.span()) {
+            if !original.eq_ignore_span(item) {
+                edits.replace_code(original.span(), node_code(item));
+            }
+            index += 1;
+            continue;
+        }
+        match (before.get(index), index.checked_sub(1).and_then(|prev| before.get(prev))) {
+            (Some(next), Some(prev)) if source.is_same_line(prev.span().hi, next.span().lo) => {
+                edits.insert_module_item(next.span().lo, format!("{}\n", node_code(item)))
+            }
+            (_, Some(prev)) => {
+                edits.insert_module_item(source.line_end(prev.span().hi), format!("\n{}", node_code(item)))
+            }
+            (Some(next), None) => edits.insert_module_item(next.span().lo, format!("{}\n", node_code(item))),
+            (None, None) => edits.insert_module_item(source.base, format!("{}\n", node_code(item))),
+        }
+    }
+}
@@ -1,26 +1,96 @@
-/// TranslationWrapper 구조체
-/// 한국어 문자열을 t() 함수로 변환하고 useTranslation 훅을 추가
+//! TranslationWrapper 구조체
+//! 한국어 문자열을 t() 함수로 변환하고 useTranslation 훅을 추가
 
-use crate::ast_helpers::is_react_component;
-use crate::ast_transformers::{transform_function_body, transform_module};
-use crate::import_manager::{add_import_if_needed, create_use_translation_hook, add_server_translation_import};
-use crate::constants::StringConstants;
-use crate::parser::{parse_file, generate_code, ParseOptions};
+use crate::ast_helpers::{
+    component_fn_mut, has_call_to, has_param_binding, has_top_level_binding, is_directive,
+    module_directives, ComponentFnMut,
+};
+use crate::cache::{config_hash, content_hash, TransformCache};
+use crate::ast_transformers::{
+    transform_module_with_comments, transform_server_module_with_comments, TransformResult,
+};
+use crate::classifier::{classify_module, ModeClassification, ModeReason, TranslationMode};
+use crate::constants::{ConsoleMessages, PerformanceNames, PrefilterLimits, StringConstants};
+use crate::report::TransformReport;
+use crate::diff_preview::{colorize_diff, diff_path_label, should_colorize, unified_diff, DryRunSummary};
+use crate::import_graph::{normalize_path, ImportGraph, TsConfigChain, TsConfigPaths};
+use crate::import_manager::{
+    add_import_if_needed, add_server_translation_import, create_t_binding,
+    create_use_translation_hook_as, ensure_named_require, is_commonjs_module,
+    resolve_import_local_name,
+};
+use crate::performance_monitor::PerformanceMonitor;
+use crate::prefilter::{prefilter_skip_reason, FileSkipReason};
+use crate::parser::{parse_file, ParseOptions, SourceType, TsConfigCompilerOptions};
+use crate::session::{Session, SourceFileHandle};
+use crate::source_files::{absolute_path, collect_source_files, SourceFileOptions};
+use crate::text_edits::{node_code, record_module_item_edits, SourceText, TextEdits};
 use anyhow::Result;
-use glob::glob;
+use rayon::prelude::*;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
 use std::fs;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use swc_common::{BytePos, Span, Spanned, DUMMY_SP};
 use swc_ecma_ast::*;
+use swc_ecma_visit::{VisitMut, VisitMutWith};
 
 /// 설정 구조체
-#[derive(Debug, Clone)]
+///
+/// 직렬화한 값의 해시가 증분 실행 캐시의 설정 해시가 된다.
+/// 변환 결과에 영향이 없는 실행 옵션(파일 선택, 출력 방식, 성능 측정 등)만 `#[serde(skip)]`으로 제외하므로
+/// 새 필드는 기본적으로 캐시 무효화 대상이다.
+#[derive(Debug, Clone, Serialize)]
 pub struct ScriptConfig {
+    /// 처리 대상 파일 패턴 (`{a,b}` brace expansion 지원)
+    #[serde(skip)]
     pub source_pattern: String,
+    /// source_pattern 외에 추가로 처리할 패턴
+    #[serde(skip)]
+    pub include_patterns: Vec<String>,
+    /// 제외할 파일 패턴 (`**/*.test.tsx`, `**/*.stories.tsx`)
+    /// node_modules, .next, dist는 지정하지 않아도 항상 제외
+    #[serde(skip)]
+    pub exclude_patterns: Vec<String>,
+    /// `.gitignore`에 해당하는 파일 제외
+    #[serde(skip)]
+    pub respect_gitignore: bool,
+    #[serde(skip)]
     pub dry_run: bool,
+    /// dry_run일 때 diff를 콘솔 대신 이 경로에 하나의 patch 파일로 저장
+    /// `git apply <patch>`로 그대로 적용 가능
+    #[serde(skip)]
+    pub patch_out: Option<String>,
+    /// 파일 전체를 다시 출력하지 않고 바뀐 범위만 원본 텍스트에 패치
+    /// 변환하지 않은 코드(따옴표, 세미콜론, 줄바꿈 등)는 바이트 단위로 그대로 유지
+    pub minimal_diff: bool,
+    /// 변환 리포트 형식 ("json"이면 run_translation_wrapper가 JSON 리포트를 표준 출력에 출력)
+    /// 리포트는 모든 후보 문자열을 기록해야 하므로 캐시로 파일을 건너뛰지 않는다
+    #[serde(skip)]
+    pub report: Option<String>,
+    /// 성능 측정 결과를 Chrome trace-event JSON으로 저장할 경로
+    /// 지정하면 enable_performance_monitoring과 상관없이 측정
+    #[serde(skip)]
+    pub trace_out: Option<String>,
     pub translation_import_source: String,
+    #[serde(skip)]
     pub enable_performance_monitoring: bool,
+    /// 이보다 큰 파일(바이트)은 읽지 않고 건너뜀 (None이면 제한 없음)
+    #[serde(skip)]
+    pub max_file_size: Option<u64>,
+    /// 동시에 처리할 파일 수 (스레드 수)
+    /// None이면 CPU 코어 수, 1이면 순서대로 하나씩 처리
+    #[serde(skip)]
+    pub jobs: Option<usize>,
+    /// 증분 실행 캐시 파일 경로 (None이면 캐시 사용 안 함, CLI 기본값: `.i18nexus/cache.json`)
+    /// 이전 실행 이후 내용과 설정이 같은 파일은 파싱하지 않고 건너뛴다
+    #[serde(skip)]
+    pub cache_file: Option<String>,
     /// 번역 함수 모드 (기능적 선택)
     /// - "client": useTranslation() 사용
     /// - "server": getServerTranslation() 사용
+    /// - None: 파일마다 AST로 분류 (classify_module)
     pub mode: Option<String>, // "client" | "server"
     /// 프레임워크 타입
     /// - "nextjs": Next.js App Router 환경
@@ -37,9 +107,19 @@ impl Default for ScriptConfig {
     fn default() -> Self {
         Self {
             source_pattern: "src/**/*.{js,jsx,ts,tsx}".to_string(),
+            include_patterns: Vec::new(),
+            exclude_patterns: Vec::new(),
+            respect_gitignore: false,
             dry_run: false,
+            patch_out: None,
+            minimal_diff: false,
+            report: None,
+            trace_out: None,
             translation_import_source: "i18nexus".to_string(),
             enable_performance_monitoring: false,
+            max_file_size: Some(PrefilterLimits::DEFAULT_MAX_FILE_SIZE),
+            jobs: None,
+            cache_file: None,
             mode: None,
             framework: None,
             server_translation_function: None,
@@ -47,251 +127,936 @@ impl Default for ScriptConfig {
     }
 }
 
+/// 변환된 컴포넌트 body 맨 앞에 t 바인딩을 추가하는 visitor
+/// - client 모드: `const { t } = useTranslation();`
+/// - server 모드: `const { t } = await getServerTranslation();` + 컴포넌트를 async로 변경
+///   (훅은 transform_server_module_with_comments가 변환하지 않으므로 modified_functions에 없음)
+///
+/// TranslationTransformer와 같은 규칙으로 컴포넌트를 찾고,
+/// modified_functions에 기록된 컴포넌트만 처리한다
+struct TranslationBindingInserter<'a> {
+    modified_functions: &'a [String],
+    /// 추가할 바인딩 구문
+    binding: Stmt,
+    /// 바인딩을 만드는 함수의 로컬 이름 (이미 호출하고 있으면 스킵)
+    /// `import { useTranslation as useT }`이면 useT
+    binding_fn_name: String,
+    /// 바인딩이 await를 쓰므로 컴포넌트를 async로 바꿔야 하는지
+    make_async: bool,
+    was_binding_added: bool,
+    /// 원본 텍스트 (있으면 삽입 위치를 edits에 기록)
+    source: Option<SourceText<'a>>,
+    /// 바인딩/async 삽입 목록 (minimal diff 출력용)
+    edits: TextEdits,
+}
+
+impl<'a> TranslationBindingInserter<'a> {
+    fn new(modified_functions: &'a [String], binding: Stmt, binding_fn_name: String) -> Self {
+        Self {
+            modified_functions,
+            binding,
+            binding_fn_name,
+            make_async: false,
+            was_binding_added: false,
+            source: None,
+            edits: TextEdits::new(),
+        }
+    }
+
+    fn with_async(mut self) -> Self {
+        self.make_async = true;
+        self
+    }
+
+    fn with_source(mut self, source: SourceText<'a>) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    /// `async` 키워드 삽입 기록 (`function`, 화살표 함수 시작 위치)
+    fn record_async(&mut self, pos: BytePos) {
+        if self.source.is_some() && !pos.is_dummy() {
+            self.edits.insert(pos, "async ");
+        }
+    }
+
+    /// block body 맨 앞(디렉티브 뒤)에 바인딩 삽입 기록
+    /// 첫 구문이 다음 줄에 있으면 그 들여쓰기에 맞춰 새 줄로, 같은 줄이면 `{` 뒤에 이어서 삽입
+    fn record_block_binding(&mut self, body: &BlockStmt, index: usize) {
+        let Some(source) = self.source else {
+            return;
+        };
+        let binding = node_code(&self.binding);
+        let anchor = match index.checked_sub(1) {
+            Some(last_directive) => body.stmts[last_directive].span().hi,
+            None => body.span.lo + BytePos(1),
+        };
+        let text = match body.stmts.get(index) {
+            Some(next) if !source.is_same_line(anchor, next.span().lo) => {
+                format!("\n{}{}", source.line_indent(next.span().lo), binding)
+            }
+            Some(_) => format!(" {}", binding),
+            None => format!(
+                "\n{}{}{}",
+                source.line_indent(body.span.lo),
+                source.indent_unit(body.span.lo),
+                binding
+            ),
+        };
+        self.edits.insert(anchor, text);
+    }
+
+    /// concise body를 block으로 감싸는 삽입 기록
+    /// `() => <div />` → `() => {\n  const { t } = ...;\n  return <div />;\n}`
+    fn record_concise_binding(&mut self, arrow_span: Span, body_span: Span) {
+        let Some(source) = self.source else {
+            return;
+        };
+        let indent = source.line_indent(arrow_span.lo);
+        let inner = format!("{}{}", indent, source.indent_unit(arrow_span.lo));
+        self.edits.insert(
+            body_span.lo,
+            format!("{{\n{}{}\n{}return ", inner, node_code(&self.binding), inner),
+        );
+        self.edits.insert(body_span.hi, format!(";\n{}}}", indent));
+    }
+
+    fn is_modified(&self, name: &str) -> bool {
+        self.modified_functions.iter().any(|modified| modified == name)
+    }
+
+    /// 컴포넌트 body에 바인딩 추가
+    /// concise body(`() => <div />`)는 block으로 감싼 후 return 유지
+    /// 매개변수로 이미 t를 받으면 (`withTranslation`의 `function Foo({ t })`) 중복 선언이 되므로 스킵
+    fn insert_binding(&mut self, component: ComponentFnMut<'_>) {
+        match component {
+            ComponentFnMut::Function(function) => {
+                let params = function.params.iter().map(|param| &param.pat);
+                if has_param_binding(params, StringConstants::TRANSLATION_FUNCTION) {
+                    return;
+                }
+                if let Some(body) = &mut function.body {
+                    if self.insert_into_block(body) && self.make_async && !function.is_async {
+                        function.is_async = true;
+                        self.record_async(function.span.lo);
+                    }
+                }
+            }
+            ComponentFnMut::Arrow(arrow) => {
+                if has_param_binding(&arrow.params, StringConstants::TRANSLATION_FUNCTION) {
+                    return;
+                }
+                if let BlockStmtOrExpr::BlockStmt(body) = arrow.body.as_mut() {
+                    if self.insert_into_block(body) && self.make_async && !arrow.is_async {
+                        arrow.is_async = true;
+                        self.record_async(arrow.span.lo);
+                    }
+                    return;
+                }
+                let BlockStmtOrExpr::Expr(original) = std::mem::replace(
+                    arrow.body.as_mut(),
+                    BlockStmtOrExpr::BlockStmt(BlockStmt::default()),
+                ) else {
+                    return;
+                };
+                self.record_concise_binding(arrow.span, original.span());
+                *arrow.body = BlockStmtOrExpr::BlockStmt(BlockStmt {
+                    span: DUMMY_SP,
+                    ctxt: Default::default(),
+                    stmts: vec![
+                        self.binding.clone(),
+                        Stmt::Return(ReturnStmt {
+                            span: DUMMY_SP,
+                            arg: Some(original),
+                        }),
+                    ],
+                });
+                if self.make_async && !arrow.is_async {
+                    arrow.is_async = true;
+                    self.record_async(arrow.span.lo);
+                }
+                self.was_binding_added = true;
+            }
+        }
+    }
+
+    /// 이미 바인딩 함수를 호출하거나 t 바인딩이 있으면 스킵
+    /// 디렉티브("use strict" 등)가 있으면 그 뒤에 추가
+    fn insert_into_block(&mut self, body: &mut BlockStmt) -> bool {
+        if has_call_to(body, &self.binding_fn_name)
+            || has_top_level_binding(body, StringConstants::TRANSLATION_FUNCTION)
+        {
+            return false;
+        }
+        let index = body.stmts.iter().take_while(|stmt| is_directive(stmt)).count();
+        self.record_block_binding(body, index);
+        body.stmts.insert(index, self.binding.clone());
+        self.was_binding_added = true;
+        true
+    }
+}
+
+impl VisitMut for TranslationBindingInserter<'_> {
+    fn visit_mut_fn_decl(&mut self, func: &mut FnDecl) {
+        if self.is_modified(&func.ident.sym) {
+            self.insert_binding(ComponentFnMut::Function(&mut func.function));
+        }
+        func.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_var_declarator(&mut self, declarator: &mut VarDeclarator) {
+        if let (Pat::Ident(binding), Some(init)) = (&declarator.name, &mut declarator.init) {
+            if self.is_modified(&binding.id.sym) {
+                if let Some(component) = component_fn_mut(init) {
+                    self.insert_binding(component);
+                }
+            }
+        }
+        declarator.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_export_default_decl(&mut self, decl: &mut ExportDefaultDecl) {
+        if let DefaultDecl::Fn(fn_expr) = &mut decl.decl {
+            let name = fn_expr
+                .ident
+                .as_ref()
+                .map_or(StringConstants::DEFAULT_EXPORT_NAME, |ident| ident.sym.as_ref());
+            if self.is_modified(name) {
+                self.insert_binding(ComponentFnMut::Function(&mut fn_expr.function));
+            }
+        }
+        decl.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_export_default_expr(&mut self, export: &mut ExportDefaultExpr) {
+        if self.is_modified(StringConstants::DEFAULT_EXPORT_NAME) {
+            if let Some(component) = component_fn_mut(&mut export.expr) {
+                self.insert_binding(component);
+            }
+        }
+        export.visit_mut_children_with(self);
+    }
+}
+
+/// 파싱된 파일 (모드 결정 후 변환)
+struct ParsedFile {
+    path: PathBuf,
+    /// 세션에 추가된 원본 파일 (원본 코드, 줄/열 변환)
+    file: SourceFileHandle,
+    ast: Module,
+    /// minimal diff 결과를 같은 문법으로 다시 검증하기 위해 보관
+    parse_options: ParseOptions,
+    /// import graph를 위해서만 파싱한 파일 (한국어가 없거나 캐시에 있어 변환하지 않음)
+    graph_only: bool,
+    /// 증분 실행 캐시에 기록할 내용 해시 (캐시를 사용하지 않으면 None)
+    content_hash: Option<String>,
+}
+
+/// 파일 읽기 + 사전 필터 + 파싱 결과
+enum LoadedFile {
+    Parsed(ParsedFile),
+    /// 사전 필터로 건너뜀 (파싱 옵션은 import graph용으로 다시 파싱할 때 사용)
+    Skipped(PathBuf, ParseOptions, FileSkipReason),
+    /// 파싱 실패 (에러 메시지에 파일 경로와 위치가 포함됨)
+    Failed(anyhow::Error),
+}
+
+/// 파일 하나의 변환 결과
+/// 변환은 병렬로 하고, 출력과 집계는 파일 순서대로 하기 위해 메시지를 모아 둔다
+struct FileOutcome {
+    path: PathBuf,
+    /// 위치(줄/열)를 채운 변환 결과
+    transform_result: TransformResult,
+    /// 안내 메시지 (모드 선택 등)
+    messages: Vec<String>,
+    /// 경고 메시지 (표준 에러로 출력)
+    warnings: Vec<String>,
+    /// dry_run일 때 diff 헤더 경로와 unified diff
+    diff: Option<(String, String)>,
+    /// 증분 실행 캐시에 기록할 내용 해시
+    content_hash: Option<String>,
+}
+
+/// 증분 실행 캐시의 파일 키 (정규화한 경로)
+fn cache_key(file_path: &Path) -> String {
+    normalize_path(file_path).to_string_lossy().to_string()
+}
+
+/// 파일별로 가장 가까운 tsconfig.json을 찾아 읽는 캐시
+/// 같은 디렉토리와 같은 tsconfig.json은 한 번만 찾고 읽는다 (`extends` 포함)
+#[derive(Default)]
+struct TsConfigCache {
+    /// 디렉토리 → 가장 가까운 tsconfig.json 경로
+    path_by_dir: HashMap<PathBuf, Option<PathBuf>>,
+    chains: HashMap<PathBuf, Option<Rc<TsConfigChain>>>,
+    paths: HashMap<PathBuf, Option<Rc<TsConfigPaths>>>,
+    compiler_options: HashMap<PathBuf, Option<Rc<TsConfigCompilerOptions>>>,
+}
+
+impl TsConfigCache {
+    fn find(&mut self, file_path: &Path) -> Option<PathBuf> {
+        let dir = file_path.parent().unwrap_or(Path::new("")).to_path_buf();
+        self.path_by_dir
+            .entry(dir)
+            .or_insert_with_key(|dir| TsConfigPaths::find(dir))
+            .clone()
+    }
+
+    /// 읽기 실패는 한 번만 출력하고 None으로 캐시
+    fn chain(&mut self, tsconfig_path: &Path) -> Option<Rc<TsConfigChain>> {
+        self.chains
+            .entry(tsconfig_path.to_path_buf())
+            .or_insert_with_key(|tsconfig_path| match TsConfigChain::load(tsconfig_path) {
+                Ok(chain) => Some(Rc::new(chain)),
+                Err(e) => {
+                    eprintln!("❌ {:#}", e);
+                    None
+                }
+            })
+            .clone()
+    }
+
+    /// import 별칭 (`compilerOptions.paths`)
+    fn paths(&mut self, file_path: &Path) -> Option<Rc<TsConfigPaths>> {
+        let tsconfig_path = self.find(file_path)?;
+        if !self.paths.contains_key(&tsconfig_path) {
+            let paths = self.chain(&tsconfig_path).map(|chain| Rc::new(TsConfigPaths::from_chain(&chain)));
+            self.paths.insert(tsconfig_path.clone(), paths);
+        }
+        self.paths[&tsconfig_path].clone()
+    }
+
+    /// 파서 옵션 (`jsx`, `target`, `experimentalDecorators`)
+    fn compiler_options(&mut self, file_path: &Path) -> Option<Rc<TsConfigCompilerOptions>> {
+        let tsconfig_path = self.find(file_path)?;
+        if !self.compiler_options.contains_key(&tsconfig_path) {
+            let compiler_options = self
+                .chain(&tsconfig_path)
+                .map(|chain| Rc::new(TsConfigCompilerOptions::from_chain(&chain)));
+            self.compiler_options.insert(tsconfig_path.clone(), compiler_options);
+        }
+        self.compiler_options[&tsconfig_path].clone()
+    }
+}
+
 /// TranslationWrapper 구조체
 pub struct TranslationWrapper {
     config: ScriptConfig,
+    pub performance_monitor: PerformanceMonitor,
 }
 
 impl TranslationWrapper {
     pub fn new(config: Option<ScriptConfig>) -> Self {
+        let config = config.unwrap_or_default();
+        let performance_monitor =
+            PerformanceMonitor::new(config.enable_performance_monitoring || config.trace_out.is_some());
         Self {
-            config: config.unwrap_or_default(),
+            config,
+            performance_monitor,
+        }
+    }
+
+    fn is_nextjs_framework(&self) -> bool {
+        self.config.framework.as_deref() == Some("nextjs")
+    }
+
+    /// source_pattern + include_patterns, exclude_patterns로 파일 수집 옵션 구성
+    fn source_file_options(&self) -> SourceFileOptions {
+        SourceFileOptions {
+            include_patterns: std::iter::once(self.config.source_pattern.clone())
+                .chain(self.config.include_patterns.iter().cloned())
+                .collect(),
+            exclude_patterns: self.config.exclude_patterns.clone(),
+            respect_gitignore: self.config.respect_gitignore,
         }
     }
 
+    /// 파일의 번역 함수 모드 선택
+    /// config.mode가 있으면 그대로 사용하고, 없으면 AST로 파일별 분류
+    /// 아무 신호가 없는 파일은 Next.js(App Router)면 서버 컴포넌트, 그 외에는 클라이언트로 처리
+    fn select_mode(&self, module: &Module) -> ModeClassification {
+        if let Some(mode) = TranslationMode::from_config(self.config.mode.as_deref()) {
+            return ModeClassification::new(mode, ModeReason::Configured);
+        }
+        let default_mode = if self.is_nextjs_framework() {
+            TranslationMode::Server
+        } else {
+            TranslationMode::Client
+        };
+        classify_module(module, self.server_translation_function(), default_mode)
+    }
+
+    /// 전체 파일의 모드 결정
+    /// config.mode가 없으면 파일별 분류 후 import graph로 클라이언트 경계를 전파
+    fn classify_files(
+        &self,
+        files: &[ParsedFile],
+        tsconfigs: &mut TsConfigCache,
+    ) -> HashMap<PathBuf, ModeClassification> {
+        let mut classifications: HashMap<PathBuf, ModeClassification> = files
+            .iter()
+            .map(|file| (normalize_path(&file.path), self.select_mode(&file.ast)))
+            .collect();
+        if TranslationMode::from_config(self.config.mode.as_deref()).is_some() {
+            return classifications;
+        }
+
+        let paths: HashSet<PathBuf> = classifications.keys().cloned().collect();
+        let mut graph = ImportGraph::new();
+        for file in files {
+            let tsconfig = tsconfigs.paths(&file.path);
+            graph.add_module(&file.path, &file.ast, &paths, tsconfig.as_deref());
+        }
+
+        graph.propagate_client_boundaries(&mut classifications);
+        classifications
+    }
+
+    /// server 모드에서 사용할 번역 함수 이름 (기본값: getServerTranslation)
+    fn server_translation_function(&self) -> &str {
+        self.config
+            .server_translation_function
+            .as_deref()
+            .unwrap_or(StringConstants::GET_SERVER_TRANSLATION)
+    }
+
     /// 'use client' 디렉티브 보장
     /// TypeScript 버전과 동일한 로직:
     /// 이미 존재하면 패스, 없으면 추가
-    fn ensure_use_client_directive(&self, _module: &mut Module) {
-        // TODO: SWC AST로 구현
-        // let has_directive = module.body.iter().any(|item| {
-        //     if let ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) = item {
-        //         // directive 확인 로직
-        //     }
-        //     false
-        // });
-        // if !has_directive {
-        //     // directive 추가
-        // }
-    }
-
-    /// Named import 보장
-    /// TypeScript 버전과 동일한 로직:
-    /// 같은 소스의 import가 있고 specifier가 있으면 true 반환
-    /// 같은 소스의 import가 있지만 specifier가 없으면 추가
-    /// import가 없으면 새로 생성
-    fn ensure_named_import(&self, _module: &mut Module, _source: &str, _imported_name: &str) -> bool {
-        // TODO: SWC AST로 구현
-        // let mut has_source = false;
-        // let mut has_specifier = false;
-        // for item in &module.body {
-        //     if let ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) = item {
-        //         // Wtf8Atom 처리 필요
-        //         // if import_decl.src.value == source {
-        //         //     has_source = true;
-        //         //     // specifier 확인 및 추가
-        //         // }
-        //     }
-        // }
-        // if !has_source {
-        //     // 새 import declaration 생성 및 추가
-        // }
-        true
+    /// 
+    /// 추가 규칙:
+    /// - "use server" 파일에는 추가하지 않음
+    /// - 모듈 맨 앞(import보다 위)에 추가. shebang은 Module.shebang으로 따로 출력되므로 항상 그 아래
+    /// - 첫 구문의 시작 위치를 span으로 써서, 라이선스 주석 등 첫 구문의 leading comment가
+    ///   디렉티브보다 먼저 출력되도록 함
+    fn ensure_use_client_directive(&self, module: &mut Module) {
+        let directives = module_directives(module);
+        if directives.iter().any(|directive| {
+            directive == StringConstants::USE_CLIENT_DIRECTIVE
+                || directive == StringConstants::USE_SERVER_DIRECTIVE
+        }) {
+            return;
+        }
+
+        let first_pos = module.body.first().map_or(DUMMY_SP.lo, |item| item.span().lo);
+        let directive = Stmt::Expr(ExprStmt {
+            span: Span::new(first_pos, first_pos),
+            expr: Box::new(Expr::Lit(Lit::Str(Str {
+                span: DUMMY_SP,
+                value: StringConstants::USE_CLIENT_DIRECTIVE.into(),
+                raw: None,
+            }))),
+        });
+        module.body.insert(0, ModuleItem::Stmt(directive));
+    }
+
+    /// minimal diff 출력: 기록한 편집만 원본 텍스트에 적용
+    /// 편집이 겹치거나 결과를 다시 파싱할 수 없으면 Err (전체 코드 생성으로 대체)
+    fn apply_minimal_diff(
+        &self,
+        code: &str,
+        base: BytePos,
+        edits: &TextEdits,
+        parse_options: ParseOptions,
+    ) -> Result<String> {
+        let output = edits.apply(code, base)?;
+        parse_file(&output, parse_options)?;
+        Ok(output)
     }
 
     /// Server 모드에서 t 바인딩 생성
     /// TypeScript 버전과 동일한 로직:
     /// const { t } = await getServerTranslation();
-    #[allow(dead_code)]
-    fn create_server_t_binding(&self, _server_fn_name: &str) {
-        // TODO: SWC AST 노드 생성으로 구현
-        // VariableDeclarator {
-        //     name: Pat::Object(ObjectPat {
-        //         props: vec![ObjectPatProp::KeyValue(KeyValuePatProp {
-        //             key: PropName::Ident(Ident {
-        //                 sym: StringConstants::TRANSLATION_FUNCTION.into(),
-        //                 ...
-        //             }),
-        //             value: Box::new(Pat::Ident(BindingIdent {
-        //                 id: Ident {
-        //                     sym: StringConstants::TRANSLATION_FUNCTION.into(),
-        //                     ...
-        //                 },
-        //                 ...
-        //             })),
-        //         })],
-        //         ...
-        //     }),
-        //     init: Some(Box::new(Expr::Await(AwaitExpr {
-        //         arg: Box::new(Expr::Call(CallExpr {
-        //             callee: Callee::Expr(Box::new(Expr::Ident(Ident {
-        //                 sym: server_fn_name.into(),
-        //                 ...
-        //             }))),
-        //             args: vec![],
-        //             ...
-        //         })),
-        //         ...
-        //     }))),
-        //     ...
-        // }
-    }
-
-    /// 함수 body 내의 AST 노드들을 변환
-    /// TypeScript 버전과 동일한 로직:
-    /// transformFunctionBody 호출
-    fn process_function_body(&self, _path: (), source_code: &str) -> bool {
-        let transform_result = transform_function_body((), source_code);
-        transform_result.was_modified
+    fn create_server_t_binding(&self, server_fn_name: &str) -> Stmt {
+        create_t_binding(Expr::Await(AwaitExpr {
+            span: DUMMY_SP,
+            arg: Box::new(Expr::Call(CallExpr {
+                span: DUMMY_SP,
+                callee: Callee::Expr(Box::new(Expr::Ident(Ident {
+                    span: DUMMY_SP,
+                    sym: server_fn_name.into(),
+                    optional: false,
+                    ctxt: Default::default(),
+                }))),
+                args: vec![],
+                type_args: None,
+                ctxt: Default::default(),
+            })),
+        }))
     }
 
     /// 파일들을 처리
     /// TypeScript 버전과 동일한 로직:
-    /// 1. glob으로 파일 목록 가져오기
+    /// 1. include/exclude 패턴으로 파일 목록 가져오기
     /// 2. 각 파일을 파싱 및 변환
     /// 3. mode에 따라 client/server 처리
     /// 4. 필요한 import 추가
-    /// 5. 변환된 코드를 파일에 쓰기 (dry_run이 아닌 경우)
+    /// 5. 변환된 코드를 파일에 쓰기
+    ///    dry_run이면 쓰지 않고 unified diff 출력 (patch_out이 있으면 patch 파일로 저장) + 요약 출력
     pub fn process_files(&self) -> Result<Vec<String>> {
-        // TODO: PerformanceMonitor 추가
-        // let performance_monitor = PerformanceMonitor::new(...);
-        // performance_monitor.start("translation_wrapper:total");
-
-        let file_paths = glob(&self.config.source_pattern)?
-            .filter_map(|entry| entry.ok())
-            .collect::<Vec<_>>();
-        
-        let mut processed_files = Vec::new();
+        self.process_files_with_report().map(|(processed_files, _)| processed_files)
+    }
+
+    /// process_files와 같고, 파일별 변환 후보 문자열 기록을 담은 리포트도 함께 반환
+    ///
+    /// 읽기/파싱과 변환/코드 생성/쓰기는 파일 단위로 병렬 처리한다 (스레드 수는 config.jobs).
+    /// 안내 메시지, diff, 리포트는 파일 목록 순서대로 모아서 출력하므로 jobs와 상관없이 결과가 같다.
+    pub fn process_files_with_report(&self) -> Result<(Vec<String>, TransformReport)> {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(self.config.jobs.unwrap_or(0))
+            .build()?;
+        pool.install(|| self.run_pipeline(None))
+    }
 
-        for file_path in file_paths {
-            // TODO: PerformanceMonitor
-            // performance_monitor.start("file_processing", { file_path });
+    /// 감시 모드에서 바뀐 파일만 처리
+    ///
+    /// 대상 파일 목록은 process_files와 같은 규칙(include/exclude/gitignore)으로 정하고,
+    /// 그중 `changed`(절대 경로)에 있는 파일만 변환한다.
+    /// 나머지 파일은 모드를 import graph로 정해야 할 때만 파싱한다.
+    pub fn process_changed_files(&self, changed: &HashSet<PathBuf>) -> Result<(Vec<String>, TransformReport)> {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(self.config.jobs.unwrap_or(0))
+            .build()?;
+        pool.install(|| self.run_pipeline(Some(changed)))
+    }
 
-            let code = fs::read_to_string(&file_path)?;
-            let mut is_file_modified = false;
+    fn run_pipeline(&self, changed: Option<&HashSet<PathBuf>>) -> Result<(Vec<String>, TransformReport)> {
+        let performance_monitor = &self.performance_monitor;
+        performance_monitor.start(PerformanceNames::TOTAL, &[]);
 
-            // SWC로 파싱
-            let mut ast = match parse_file(&code, ParseOptions::default()) {
-                Ok(module) => module,
-                Err(e) => {
-                    // 파싱 실패 시 에러 로그만 출력하고 다음 파일로
-                    eprintln!("❌ Error parsing {}: {}", file_path.display(), e);
-                    continue;
+        let file_paths = performance_monitor.measure_result(PerformanceNames::GLOB, &[], || {
+            collect_source_files(&self.source_file_options())
+        })?;
+        let (file_paths, unchanged_paths): (Vec<PathBuf>, Vec<PathBuf>) = match changed {
+            Some(changed) => file_paths
+                .into_iter()
+                .partition(|file_path| changed.contains(&absolute_path(file_path))),
+            None => (file_paths, Vec::new()),
+        };
+        // 감시 모드에서 저장된 파일이 처리 대상이 아니면 (다른 확장자, exclude 등) 아무것도 하지 않음
+        if changed.is_some() && file_paths.is_empty() {
+            performance_monitor.end(PerformanceNames::TOTAL, &[]);
+            return Ok((Vec::new(), TransformReport::new(0)));
+        }
+        let total_files = file_paths.len();
+        let mut cache = self.load_cache()?;
+
+        let mut processed_files = Vec::new();
+        let mut dry_run_summary = DryRunSummary::new(file_paths.len());
+        let mut report = TransformReport::new(file_paths.len());
+        let mut patch = String::new();
+        let colorize = self.config.patch_out.is_none() && self.config.report.is_none() && should_colorize();
+
+        // 1. 모든 파일 파싱 (import graph로 모드를 정하려면 변환 전에 전체를 봐야 함)
+        // 모든 파일이 하나의 세션(SourceMap, 주석, GLOBALS)을 공유 (주석 위치를 코드 생성까지 유지)
+        let session = Session::new();
+        let mut tsconfigs = TsConfigCache::default();
+        // tsconfig 캐시는 스레드 사이에 공유하지 않으므로 파싱 옵션은 먼저 순서대로 결정
+        let parse_inputs: Vec<(PathBuf, ParseOptions)> = file_paths
+            .into_iter()
+            .map(|file_path| {
+                let parse_options = self.parse_options(&file_path, &mut tsconfigs);
+                (file_path, parse_options)
+            })
+            .collect();
+        let loaded_files = parse_inputs
+            .into_par_iter()
+            .map(|(file_path, parse_options)| self.load_file(&session, cache.as_ref(), file_path, parse_options))
+            .collect::<Result<Vec<_>>>()?;
+        let mut parsed_files = Vec::new();
+        let mut unchanged_files: Vec<(PathBuf, ParseOptions)> = unchanged_paths
+            .into_iter()
+            .map(|file_path| {
+                let parse_options = self.parse_options(&file_path, &mut tsconfigs);
+                (file_path, parse_options)
+            })
+            .collect();
+        for loaded_file in loaded_files {
+            match loaded_file {
+                LoadedFile::Parsed(parsed_file) => parsed_files.push(parsed_file),
+                LoadedFile::Skipped(file_path, parse_options, reason) => {
+                    report.add_skipped_file(reason);
+                    unchanged_files.push((file_path, parse_options));
                 }
-            };
-            
-            // AST 변환 (한국어 문자열을 t() 함수로 변환)
-            let (transform_result, modified_functions) = transform_module(&mut ast, code.clone());
-            if transform_result.was_modified {
-                is_file_modified = true;
+                // 파싱 실패 시 에러 로그만 출력하고 다음 파일로
+                LoadedFile::Failed(e) => eprintln!("❌ {}", e),
             }
+        }
 
-            if is_file_modified {
-                let was_use_hook_added = false;
-                let was_server_import_added = false;
-
-                let _is_server_mode = self.config.mode.as_deref() == Some("server");
-                let _is_client_mode = self.config.mode.as_deref() == Some("client");
-                let _is_nextjs_framework = self.config.framework.as_deref() == Some("nextjs");
-
-                // "use client" 디렉티브는 Next.js 환경에서 useTranslation 모드일 때만 추가
-                // - React/Vite 프로젝트에서는 필요 없음
-                // - 서버 번역 모드에서는 필요 없음 (서버 컴포넌트이므로)
-                // TODO: SWC AST로 구현
-                // if _is_nextjs_framework && _is_client_mode {
-                //     self.ensure_use_client_directive(&mut ast);
-                // }
-
-                // TODO: SWC AST traverse로 함수 처리
-                // for component_path in modified_component_paths {
-                //     // t() 바인딩이 이미 있는지 확인
-                //     // if component_path.scope.has_binding(StringConstants::TRANSLATION_FUNCTION) {
-                //     //     continue;
-                //     // }
-
-                //     if is_server_mode {
-                //         // server 모드: config에 정의된 서버형 함수 사용
-                //         // component_path.node.async = true;
-                //         // let body = component_path.get("body");
-                //         // let decl = self.create_server_t_binding(
-                //         //     self.config.server_translation_function.as_deref().unwrap_or("getServerTranslation")
-                //         // );
-                //         // if body.is_block_statement() {
-                //         //     body.unshift_container("body", decl);
-                //         //     was_server_import_added = true;
-                //         // } else {
-                //         //     // concise body → block으로 감싼 후 return 유지
-                //         //     let original = body.node;
-                //         //     let block = BlockStmt {
-                //         //         stmts: vec![
-                //         //             Stmt::Decl(Decl::Var(VarDecl {
-                //         //                 decls: vec![decl],
-                //         //                 ...
-                //         //             })),
-                //         //             Stmt::Return(ReturnStmt {
-                //         //                 arg: Some(Box::new(original)),
-                //         //                 ...
-                //         //             }),
-                //         //         ],
-                //         //         ...
-                //         //     };
-                //         //     component_path.node.body = block;
-                //         //     was_server_import_added = true;
-                //         // }
-                //     } else {
-                //         // client 모드 (또는 기본값): useTranslation 사용
-                //         // let body = component_path.get("body");
-                //         // if body.is_block_statement() {
-                //         //     let mut has_hook = false;
-                //         //     body.traverse({
-                //         //         CallExpression: (p) => {
-                //         //             if p.node.callee.name == StringConstants::USE_TRANSLATION {
-                //         //                 has_hook = true;
-                //         //             }
-                //         //         },
-                //         //     });
-                //         //     if !has_hook {
-                //         //         body.unshift_container("body", create_use_translation_hook());
-                //         //         was_use_hook_added = true;
-                //         //     }
-                //         // }
-                //     }
-                // }
-
-                // 필요한 import 추가
-                if was_use_hook_added {
-                    // TODO: SWC AST로 구현
-                    // add_import_if_needed(&mut ast, &self.config.translation_import_source);
+        // 사전 필터로 건너뛴 파일(생성/압축/너무 큰 파일 포함)과 감시 모드에서 저장되지 않은 파일은 바뀌지 않지만 import graph의 중간 경로가 될 수 있으므로,
+        // 모드를 graph로 정해야 하는 파일이 있을 때만 파싱 (변환은 하지 않음)
+        if self.needs_import_graph(&parsed_files) {
+            let graph_files = unchanged_files
+                .into_par_iter()
+                .map(|(file_path, parse_options)| self.load_graph_file(&session, file_path, parse_options))
+                .collect::<Result<Vec<_>>>()?;
+            for graph_file in graph_files {
+                match graph_file {
+                    LoadedFile::Parsed(parsed_file) => parsed_files.push(parsed_file),
+                    LoadedFile::Failed(e) => eprintln!("❌ {}", e),
+                    LoadedFile::Skipped(..) => {}
                 }
-                if was_server_import_added {
-                    // TODO: SWC AST로 구현
-                    // let server_fn = self.config.server_translation_function.as_deref().unwrap_or("getServerTranslation");
-                    // add_server_translation_import(
-                    //     &mut ast,
-                    //     &self.config.translation_import_source,
-                    //     server_fn,
-                    // );
+            }
+        }
+
+        // 2. 파일별 client/server 모드 결정
+        let classifications = self.classify_files(&parsed_files, &mut tsconfigs);
+
+        // 3. 변환
+        let outcomes = parsed_files
+            .into_par_iter()
+            .filter(|parsed_file| !parsed_file.graph_only)
+            .map(|parsed_file| {
+                let classification = classifications
+                    .get(&normalize_path(&parsed_file.path))
+                    .cloned()
+                    .unwrap_or_else(|| self.select_mode(&parsed_file.ast));
+                session.with_globals(|| self.transform_file(&session, parsed_file, classification))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // 4. 파일 순서대로 출력/집계
+        for outcome in outcomes {
+            for message in &outcome.messages {
+                self.log(message);
+            }
+            for warning in &outcome.warnings {
+                eprintln!("{}", warning);
+            }
+            report.add_file(outcome.path.to_string_lossy(), &outcome.transform_result);
+            if let (Some(cache), Some(content_hash)) = (&mut cache, &outcome.content_hash) {
+                let key = cache_key(&outcome.path);
+                if outcome.transform_result.was_modified {
+                    cache.remove(&key);
+                } else {
+                    cache.record_unchanged(key, content_hash.as_str());
                 }
+            }
+            if !outcome.transform_result.was_modified {
+                continue;
+            }
 
-                if !self.config.dry_run {
-                    // 변환된 코드를 파일에 쓰기
-                    let output = generate_code(&ast)?;
-                    fs::write(&file_path, output)?;
+            if let Some((label, diff)) = outcome.diff {
+                if self.config.patch_out.is_some() {
+                    patch.push_str(&diff);
+                } else if !diff.is_empty() {
+                    let diff = if colorize { colorize_diff(&diff) } else { diff };
+                    self.log(diff.trim_end());
                 }
+                dry_run_summary.add_modified_file(label, outcome.transform_result.wrapped_count());
+            }
+
+            processed_files.push(outcome.path.to_string_lossy().to_string());
+        }
 
-                processed_files.push(file_path.to_string_lossy().to_string());
+        // 캐시는 다음 실행을 빠르게 할 뿐이므로 저장에 실패해도 경고만 출력
+        if let Some(cache) = &mut cache {
+            if let Err(e) = cache.save() {
+                eprintln!("{}", ConsoleMessages::cache_write_failed(&cache.path().display().to_string(), &e));
             }
+        }
+
+        let total_files = total_files.to_string();
+        let processed_count = processed_files.len().to_string();
+        performance_monitor.end(
+            PerformanceNames::TOTAL,
+            &[("total_files", total_files.as_str()), ("processed_files", processed_count.as_str())],
+        );
 
-            // TODO: PerformanceMonitor
-            // performance_monitor.end("file_processing", { file_path, modified: is_file_modified });
+        if self.config.dry_run {
+            if let Some(patch_out) = &self.config.patch_out {
+                fs::write(patch_out, &patch)?;
+                self.log(&ConsoleMessages::patch_written(patch_out));
+            }
+            self.log(dry_run_summary.render().trim_end());
         }
 
-        // TODO: PerformanceMonitor
-        // performance_monitor.end("translation_wrapper:total", {
-        //     total_files: file_paths.len(),
-        //     processed_files: processed_files.len(),
-        // });
+        Ok((processed_files, report))
+    }
 
-        Ok(processed_files)
+    /// 파일 파싱 옵션 (확장자별 문법 + 가장 가까운 tsconfig.json의 compilerOptions)
+    fn parse_options(&self, file_path: &Path, tsconfigs: &mut TsConfigCache) -> ParseOptions {
+        let parse_options = ParseOptions::for_path(file_path);
+        match tsconfigs.compiler_options(file_path) {
+            Some(compiler_options) => parse_options.with_tsconfig(&compiler_options),
+            None => parse_options,
+        }
+    }
+
+    /// 증분 실행 캐시 읽기 (config.cache_file이 없으면 None)
+    fn load_cache(&self) -> Result<Option<TransformCache>> {
+        let Some(cache_file) = &self.config.cache_file else {
+            return Ok(None);
+        };
+        Ok(Some(TransformCache::load(cache_file, config_hash(&self.config)?)))
+    }
+
+    /// 파일 읽기 + 사전 필터 + 캐시 확인 + 파싱
+    /// 바뀔 수 없거나 변환하면 안 되는 파일은 파싱하지 않고 Skipped
+    /// 읽기 실패는 Err (전체 중단)
+    fn load_file(
+        &self,
+        session: &Session,
+        cache: Option<&TransformCache>,
+        file_path: PathBuf,
+        parse_options: ParseOptions,
+    ) -> Result<LoadedFile> {
+        if let Some(max_file_size) = self.config.max_file_size {
+            if fs::metadata(&file_path)?.len() > max_file_size {
+                return Ok(LoadedFile::Skipped(file_path, parse_options, FileSkipReason::TooLarge));
+            }
+        }
+        let code = self.read_file(&file_path)?;
+        if let Some(reason) = prefilter_skip_reason(&code) {
+            return Ok(LoadedFile::Skipped(file_path, parse_options, reason));
+        }
+        let content_hash = cache.map(|_| content_hash(&code, &parse_options));
+        if let (Some(cache), Some(content_hash)) = (cache, &content_hash) {
+            // 리포트는 바뀌지 않는 파일의 후보 문자열도 기록하므로 캐시로 건너뛰지 않음
+            if self.config.report.is_none() && cache.is_unchanged(&cache_key(&file_path), content_hash) {
+                return Ok(LoadedFile::Skipped(file_path, parse_options, FileSkipReason::Cached));
+            }
+        }
+        Ok(self.parse_loaded_file(session, file_path, code, parse_options, content_hash))
+    }
+
+    /// import graph용 파일 읽기 + 파싱 (사전 필터 없음)
+    fn load_graph_file(&self, session: &Session, file_path: PathBuf, parse_options: ParseOptions) -> Result<LoadedFile> {
+        let code = self.read_file(&file_path)?;
+        Ok(match self.parse_loaded_file(session, file_path, code, parse_options, None) {
+            LoadedFile::Parsed(parsed_file) => LoadedFile::Parsed(ParsedFile {
+                graph_only: true,
+                ..parsed_file
+            }),
+            loaded_file => loaded_file,
+        })
+    }
+
+    fn read_file(&self, file_path: &Path) -> Result<String> {
+        let path_label = file_path.to_string_lossy();
+        let metadata = [(PerformanceNames::FILE_PATH, path_label.as_ref())];
+        Ok(self
+            .performance_monitor
+            .measure_result(PerformanceNames::READ, &metadata, || fs::read_to_string(file_path))?)
+    }
+
+    fn parse_loaded_file(
+        &self,
+        session: &Session,
+        file_path: PathBuf,
+        code: String,
+        parse_options: ParseOptions,
+        content_hash: Option<String>,
+    ) -> LoadedFile {
+        let path_label = file_path.to_string_lossy().to_string();
+        let metadata = [(PerformanceNames::FILE_PATH, path_label.as_str())];
+        let parsed = self.performance_monitor.measure_result(PerformanceNames::PARSE, &metadata, || {
+            session.parse_file(&code, &parse_options)
+        });
+        match parsed {
+            Ok((ast, file)) => LoadedFile::Parsed(ParsedFile {
+                path: file_path,
+                file,
+                ast,
+                parse_options,
+                graph_only: false,
+                content_hash,
+            }),
+            Err(e) => LoadedFile::Failed(e),
+        }
+    }
+
+    /// 한국어가 없는 파일도 import graph를 위해 파싱해야 하는지
+    /// 모드가 설정되어 있지 않고, 스스로 모드를 정하지 못한(NoSignal) 변환 대상 파일이 있을 때만 필요
+    /// (graph 전파는 NoSignal 파일의 모드만 바꾼다)
+    fn needs_import_graph(&self, files: &[ParsedFile]) -> bool {
+        TranslationMode::from_config(self.config.mode.as_deref()).is_none()
+            && files
+                .iter()
+                .any(|file| self.select_mode(&file.ast).reason == ModeReason::NoSignal)
+    }
+
+    /// 파일 하나 변환
+    /// 한국어 문자열을 t()로 감싸고, 바뀐 파일에는 t 바인딩/디렉티브/import를 추가한 뒤
+    /// dry_run이면 diff를 만들고 아니면 파일에 쓴다
+    fn transform_file(
+        &self,
+        session: &Session,
+        parsed_file: ParsedFile,
+        classification: ModeClassification,
+    ) -> Result<FileOutcome> {
+        let performance_monitor = &self.performance_monitor;
+        let ParsedFile { path: file_path, file, mut ast, parse_options, content_hash, .. } = parsed_file;
+        let code = file.source();
+        let path_label = file_path.to_string_lossy().to_string();
+        let metadata = [(PerformanceNames::FILE_PATH, path_label.as_str())];
+        let file_timer = performance_monitor.timer();
+        let transform_timer = performance_monitor.timer();
+
+        // AST 변환 (한국어 문자열을 t() 함수로 변환)
+        // server 모드에서는 훅을 async로 바꿀 수 없으므로 훅 body는 변환하지 않고 경고만 출력
+        let is_server_mode = classification.mode == TranslationMode::Server;
+        let (mut transform_result, modified_functions) = if is_server_mode {
+            transform_server_module_with_comments(&mut ast, code.to_string(), session.comments())
+        } else {
+            transform_module_with_comments(&mut ast, code.to_string(), session.comments())
+        };
+        transform_result.locate(&file);
+        let warnings = transform_result
+            .skipped_hooks
+            .iter()
+            .map(|hook_name| ConsoleMessages::server_hook_skipped(&path_label, hook_name))
+            .collect();
+        let mut outcome = FileOutcome {
+            path: file_path,
+            transform_result,
+            messages: Vec::new(),
+            warnings,
+            diff: None,
+            content_hash,
+        };
+
+        if !outcome.transform_result.was_modified {
+            performance_monitor.finish(PerformanceNames::TRANSFORM, transform_timer, &metadata);
+            performance_monitor.finish(
+                PerformanceNames::FILE_PROCESSING,
+                file_timer,
+                &[(PerformanceNames::FILE_PATH, path_label.as_str()), ("modified", "false")],
+            );
+            return Ok(outcome);
+        }
+
+        let file_path = &outcome.path;
+        let mut was_use_hook_added = false;
+        let mut was_server_import_added = false;
+
+        if classification.reason != ModeReason::Configured {
+            outcome.messages.push(ConsoleMessages::mode_selected(
+                &path_label,
+                classification.mode.as_str(),
+                &classification.reason.to_string(),
+            ));
+        }
+        let is_client_mode = classification.mode == TranslationMode::Client;
+        let is_nextjs_framework = self.is_nextjs_framework();
+
+        // minimal diff: 원본 위치 기준으로 삽입할 텍스트의 들여쓰기를 맞춤
+        let base = file.start_pos();
+        let source = SourceText::new(code, base);
+        let mut edits = std::mem::take(&mut outcome.transform_result.edits);
+
+        if is_server_mode {
+            // server 모드: config에 정의된 서버형 함수 사용
+            let server_fn = self.server_translation_function();
+            // 이미 import되어 있으면 그 이름, 다른 모듈이 같은 이름을 쓰고 있으면 별칭
+            let server_fn_name =
+                resolve_import_local_name(&ast, &self.config.translation_import_source, server_fn);
+            let binding = self.create_server_t_binding(&server_fn_name);
+            let mut inserter =
+                TranslationBindingInserter::new(&modified_functions, binding, server_fn_name)
+                    .with_async()
+                    .with_source(source);
+            ast.visit_mut_with(&mut inserter);
+            was_server_import_added = inserter.was_binding_added;
+            edits.extend(inserter.edits);
+        } else {
+            // client 모드 (또는 기본값): useTranslation 사용
+            // 이미 별칭으로 import되어 있으면 그 이름, 다른 모듈이 같은 이름을 쓰고 있으면 별칭
+            let hook_name = resolve_import_local_name(
+                &ast,
+                &self.config.translation_import_source,
+                StringConstants::USE_TRANSLATION,
+            );
+            let binding = create_use_translation_hook_as(&hook_name);
+            let mut inserter =
+                TranslationBindingInserter::new(&modified_functions, binding, hook_name)
+                    .with_source(source);
+            ast.visit_mut_with(&mut inserter);
+            was_use_hook_added = inserter.was_binding_added;
+            edits.extend(inserter.edits);
+        }
+
+        // 여기부터는 최상위 구문 단위 변경 (디렉티브, import/require)
+        let items_before_top_level = ast.body.clone();
+
+        // "use client" 디렉티브는 Next.js 환경에서 useTranslation 모드일 때만 추가
+        // - React/Vite 프로젝트에서는 필요 없음
+        // - 서버 번역 모드에서는 필요 없음 (서버 컴포넌트이므로)
+        if is_nextjs_framework && is_client_mode {
+            self.ensure_use_client_directive(&mut ast);
+        }
+
+        // 필요한 import 추가
+        // 스크립트로 파싱한 파일(.cjs)은 require가 없어도 CommonJS (ESM import를 넣으면 실행할 수 없음)
+        let is_commonjs = parse_options.source_type == SourceType::Script || is_commonjs_module(&ast);
+        let import_source = &self.config.translation_import_source;
+        if was_use_hook_added {
+            if is_commonjs {
+                ensure_named_require(&mut ast, import_source, StringConstants::USE_TRANSLATION);
+            } else {
+                add_import_if_needed(&mut ast, import_source);
+            }
+        }
+        if was_server_import_added {
+            if is_commonjs {
+                ensure_named_require(&mut ast, import_source, self.server_translation_function());
+            } else {
+                add_server_translation_import(&mut ast, import_source, self.server_translation_function());
+            }
+        }
+
+        performance_monitor.finish(PerformanceNames::TRANSFORM, transform_timer, &metadata);
+
+        // 변환된 코드 생성 (원본 주석 유지)
+        let codegen_timer = performance_monitor.timer();
+        let minimal_output = if self.config.minimal_diff {
+            record_module_item_edits(&items_before_top_level, &ast.body, source, &mut edits);
+            match self.apply_minimal_diff(code, base, &edits, parse_options) {
+                Ok(output) => Some(output),
+                Err(e) => {
+                    outcome.warnings.push(format!(
+                        "⚠️  Minimal diff failed for {}, regenerating whole file: {}",
+                        file_path.display(),
+                        e
+                    ));
+                    None
+                }
+            }
+        } else {
+            None
+        };
+        let output = match minimal_output {
+            Some(output) => output,
+            None => session.generate_code(&ast)?,
+        };
+        performance_monitor.finish(PerformanceNames::CODEGEN, codegen_timer, &metadata);
+
+        if self.config.dry_run {
+            // 파일을 쓰지 않고 diff로 미리보기
+            let label = diff_path_label(file_path);
+            let diff = unified_diff(&label, code, &output);
+            outcome.diff = Some((label, diff));
+        } else {
+            performance_monitor.measure_result(PerformanceNames::WRITE, &metadata, || {
+                fs::write(file_path, output)
+            })?;
+        }
+
+        performance_monitor.finish(
+            PerformanceNames::FILE_PROCESSING,
+            file_timer,
+            &[(PerformanceNames::FILE_PATH, path_label.as_str()), ("modified", "true")],
+        );
+        Ok(outcome)
+    }
+
+    /// 안내 메시지 출력
+    /// JSON 리포트를 표준 출력으로 내보낼 때는 섞이지 않도록 표준 에러로 출력
+    pub(crate) fn log(&self, message: &str) {
+        if self.config.report.is_some() {
+            eprintln!("{}", message);
+        } else {
+            println!("{}", message);
+        }
     }
 }
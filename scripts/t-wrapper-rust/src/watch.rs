@@ -0,0 +1,140 @@
+//! 감시 모드
+//! source_pattern 디렉토리를 감시(inotify)하다가 저장된 파일만 다시 변환
+//!
+//! 짧은 시간에 몰려 오는 이벤트는 WatchSettings::DEBOUNCE_MS 동안 모아서 한 번에 처리하고,
+//! 변환 결과를 쓰면서 생긴 이벤트는 파일 내용이 직접 쓴 내용 그대로면 무시한다.
+
+use crate::constants::{ConsoleMessages, WatchSettings};
+use crate::source_files::{absolute_path, pattern_base_dirs};
+use crate::translation_wrapper::{ScriptConfig, TranslationWrapper};
+use anyhow::Result;
+use notify::event::{EventKind, ModifyKind};
+use notify::{Event, RecursiveMode, Watcher};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver};
+use std::time::Duration;
+
+/// 감시 모드에서 직접 쓴 파일 내용
+/// 쓰기로 생긴 이벤트를 사용자가 저장한 것으로 착각해 다시 처리하지 않도록 보관
+#[derive(Debug, Default)]
+pub struct SelfWrites {
+    written: HashMap<PathBuf, String>,
+}
+
+impl SelfWrites {
+    /// 변환해서 쓴 파일 기록 (쓴 직후의 내용)
+    pub fn record(&mut self, file_paths: &[String]) {
+        for file_path in file_paths {
+            let path = absolute_path(Path::new(file_path));
+            if let Ok(content) = fs::read_to_string(&path) {
+                self.written.insert(path, content);
+            }
+        }
+    }
+
+    /// 직접 쓴 내용 그대로인 파일을 뺀 나머지
+    /// 내용이 달라진 파일은 사용자가 다시 저장한 것이므로 기록을 지운다
+    pub fn retain_external(&mut self, changed: HashSet<PathBuf>) -> HashSet<PathBuf> {
+        changed
+            .into_iter()
+            .filter(|path| {
+                let Some(written) = self.written.get(path) else {
+                    return true;
+                };
+                if fs::read_to_string(path).is_ok_and(|content| &content == written) {
+                    return false;
+                }
+                self.written.remove(path);
+                true
+            })
+            .collect()
+    }
+}
+
+/// 이벤트 한 묶음 수집 (절대 경로)
+/// 첫 이벤트까지 기다린 뒤 debounce 동안 새 이벤트가 없을 때까지 모은다
+/// 감시가 끝나서 채널이 닫히면 None
+pub fn next_batch(events: &Receiver<notify::Result<Event>>, debounce: Duration) -> Option<HashSet<PathBuf>> {
+    let mut changed = HashSet::new();
+    add_event(&mut changed, events.recv().ok()?);
+    while let Ok(event) = events.recv_timeout(debounce) {
+        add_event(&mut changed, event);
+    }
+    Some(changed)
+}
+
+/// 내용이 바뀌었을 수 있는 파일만 추가 (생성, 쓰기, 이름 변경)
+/// 읽기, 권한 변경, 삭제, 디렉토리 이벤트는 무시
+fn add_event(changed: &mut HashSet<PathBuf>, event: notify::Result<Event>) {
+    let event = match event {
+        Ok(event) => event,
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            return;
+        }
+    };
+    if !matches!(
+        event.kind,
+        EventKind::Create(_)
+            | EventKind::Modify(ModifyKind::Data(_) | ModifyKind::Name(_) | ModifyKind::Any | ModifyKind::Other)
+    ) {
+        return;
+    }
+    changed.extend(
+        event
+            .paths
+            .iter()
+            .filter(|path| path.is_file())
+            .map(|path| absolute_path(path)),
+    );
+}
+
+/// 감시 모드 실행 (종료하지 않음)
+/// 처음에 전체 파일을 한 번 처리한 뒤, 저장된 파일만 다시 처리한다
+/// 처리 중 에러가 나도 출력만 하고 감시는 계속한다
+pub fn run_watch_mode(config: ScriptConfig) -> Result<()> {
+    let include_patterns: Vec<String> = std::iter::once(config.source_pattern.clone())
+        .chain(config.include_patterns.iter().cloned())
+        .collect();
+    let dry_run = config.dry_run;
+    let wrapper = TranslationWrapper::new(Some(config));
+    let mut self_writes = SelfWrites::default();
+
+    let (processed_files, _) = wrapper.process_files_with_report()?;
+    if !dry_run {
+        self_writes.record(&processed_files);
+    }
+    // 감시하는 동안 메트릭이 계속 쌓이지 않도록 매번 초기화
+    wrapper.performance_monitor.reset();
+
+    let (sender, events) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender)?;
+    let dirs = pattern_base_dirs(&include_patterns);
+    for dir in &dirs {
+        watcher.watch(&absolute_path(dir), RecursiveMode::Recursive)?;
+    }
+    let dir_labels: Vec<String> = dirs.iter().map(|dir| dir.display().to_string()).collect();
+    wrapper.log(&ConsoleMessages::watch_started(&dir_labels));
+
+    let debounce = Duration::from_millis(WatchSettings::DEBOUNCE_MS);
+    while let Some(changed) = next_batch(&events, debounce) {
+        let changed = self_writes.retain_external(changed);
+        if changed.is_empty() {
+            continue;
+        }
+        match wrapper.process_changed_files(&changed) {
+            Ok((processed_files, _)) if !dry_run => {
+                for file_path in &processed_files {
+                    wrapper.log(&ConsoleMessages::watch_updated(file_path));
+                }
+                self_writes.record(&processed_files);
+            }
+            Ok(_) => {}
+            Err(e) => eprintln!("{} {}", ConsoleMessages::FATAL_ERROR, e),
+        }
+        wrapper.performance_monitor.reset();
+    }
+    Ok(())
+}
@@ -1,22 +1,54 @@
-/**
+/*!
  * ast-helpers 테스트
  * 순수 함수들 테스트
  */
 
-use t_wrapper_rust::{has_ignore_comment, should_skip_path, is_react_component};
-use t_wrapper_rust::parser::{parse_file, ParseOptions};
+use t_wrapper_rust::{has_ignore_comment, should_skip_path, Ancestor, AncestorKind, is_react_component, is_component_init, split_jsx_text, normalize_jsx_text, interpolation_var_name};
+use swc_common::{BytePos, Span, Spanned, DUMMY_SP};
+use swc_ecma_ast::*;
+use t_wrapper_rust::parser::{parse_file, parse_file_with_comments, ParseOptions};
+
+fn first_stmt_span(module: &Module) -> Span {
+    module.body[0].span()
+}
 
 #[test]
 fn has_ignore_comment_leading_comments에_i18n_ignore가_있으면_true를_반환해야_함() {
     let code = r#"// i18n-ignore
 const text = "hello";"#;
     
-    // TODO: SWC AST로 구현되면 실제 AST 노드로 테스트
-    // 현재는 소스코드 직접 검사 방식
-    let ast = parse_file(code, ParseOptions::default()).unwrap();
-    // TODO: AST traverse로 VariableDeclarator 찾아서 hasIgnoreComment 호출
-    // 현재는 소스코드 직접 검사로 테스트
-    assert!(has_ignore_comment((), Some(code)));
+    let (ast, comments) = parse_file_with_comments(code, ParseOptions::default()).unwrap();
+    assert!(has_ignore_comment(first_stmt_span(&ast), Some(&comments)));
+}
+
+#[test]
+fn has_ignore_comment_블록_주석도_인식해야_함() {
+    let code = r#"/* i18n-ignore */
+const text = "hello";"#;
+    
+    let (ast, comments) = parse_file_with_comments(code, ParseOptions::default()).unwrap();
+    assert!(has_ignore_comment(first_stmt_span(&ast), Some(&comments)));
+}
+
+#[test]
+fn has_ignore_comment_파일_상단의_주석은_아래_노드에_영향을_주지_않아야_함() {
+    let code = r#"// i18n-ignore
+const first = "hello";
+const second = "world";"#;
+    
+    let (ast, comments) = parse_file_with_comments(code, ParseOptions::default()).unwrap();
+    assert!(has_ignore_comment(ast.body[0].span(), Some(&comments)));
+    assert!(!has_ignore_comment(ast.body[1].span(), Some(&comments)));
+}
+
+#[test]
+fn has_ignore_comment_주석이_없으면_false를_반환해야_함() {
+    let code = r#"// 일반 주석
+const text = "hello";"#;
+    
+    let (ast, comments) = parse_file_with_comments(code, ParseOptions::default()).unwrap();
+    assert!(!has_ignore_comment(first_stmt_span(&ast), Some(&comments)));
+    assert!(!has_ignore_comment(first_stmt_span(&ast), None));
 }
 
 #[test]
@@ -24,26 +56,46 @@ fn should_skip_path_i18n_ignore_주석이_있으면_true를_반환해야_함() {
     let code = r#"// i18n-ignore
 const text = "hello";"#;
     
-    // TODO: SWC AST로 구현되면 실제 AST 노드로 테스트
-    let ast = parse_file(code, ParseOptions::default()).unwrap();
-    // TODO: AST traverse로 StringLiteral 찾아서 shouldSkipPath 호출
-    // 현재는 소스코드 직접 검사로 테스트
-    let should_skip = should_skip_path((), has_ignore_comment, Some(code));
+    let (ast, comments) = parse_file_with_comments(code, ParseOptions::default()).unwrap();
+    let should_skip = should_skip_path(first_stmt_span(&ast), &[], has_ignore_comment, Some(&comments));
     assert!(should_skip);
 }
 
 #[test]
-fn should_skip_path_이미_t로_래핑된_경우_true를_반환해야_함() {
-    let code = r#"const text = t("key");"#;
+fn should_skip_path_부모_노드에_i18n_ignore_주석이_있으면_true를_반환해야_함() {
+    let code = r#"// i18n-ignore
+const text = "hello";"#;
     
-    // TODO: SWC AST로 구현되면 실제 AST 노드로 테스트
-    let ast = parse_file(code, ParseOptions::default()).unwrap();
-    // TODO: AST traverse로 StringLiteral 찾아서 shouldSkipPath 호출
-    // 현재는 t() 함수 감지 로직이 없으므로 false 반환
-    let should_skip = should_skip_path((), has_ignore_comment, Some(code));
-    // TODO: 실제 구현 후 true로 변경
-    // assert!(should_skip);
-    let _ = ast;
+    let (ast, comments) = parse_file_with_comments(code, ParseOptions::default()).unwrap();
+    let parent = Ancestor::new(AncestorKind::Node, first_stmt_span(&ast));
+    let literal_span = Span::new(BytePos(30), BytePos(37));
+    assert!(should_skip_path(literal_span, &[parent], has_ignore_comment, Some(&comments)));
+    assert!(!should_skip_path(literal_span, &[], has_ignore_comment, Some(&comments)));
+}
+
+#[test]
+fn should_skip_path_이미_t로_래핑된_경우_true를_반환해야_함() {
+    let ancestors = [
+        Ancestor::new(AncestorKind::Node, DUMMY_SP),
+        Ancestor::new(AncestorKind::TranslationCall, DUMMY_SP),
+    ];
+    assert!(should_skip_path(DUMMY_SP, &ancestors, has_ignore_comment, None));
+}
+
+#[test]
+fn should_skip_path_모듈_경로_프로퍼티_키_타입_디렉티브는_스킵해야_함() {
+    for kind in [
+        AncestorKind::ModuleSource,
+        AncestorKind::PropertyKey,
+        AncestorKind::TypeOnly,
+        AncestorKind::Directive,
+    ] {
+        let ancestors = [Ancestor::new(kind, DUMMY_SP)];
+        assert!(should_skip_path(DUMMY_SP, &ancestors, has_ignore_comment, None), "{:?}", kind);
+    }
+
+    let ancestors = [Ancestor::new(AncestorKind::Node, DUMMY_SP)];
+    assert!(!should_skip_path(DUMMY_SP, &ancestors, has_ignore_comment, None));
 }
 
 #[test]
@@ -57,3 +109,89 @@ fn is_react_component_use로_시작하는_훅은_컴포넌트로_인식해야_
     assert!(is_react_component("useState"));
     assert!(is_react_component("useTranslation"));
 }
+
+#[test]
+fn split_jsx_text_앞뒤_공백과_본문을_분리해야_함() {
+    assert_eq!(split_jsx_text("\n  안녕하세요 "), ("\n  ", "안녕하세요", " "));
+    assert_eq!(split_jsx_text("안녕"), ("", "안녕", ""));
+    assert_eq!(split_jsx_text("  \n  "), ("  \n  ", "", ""));
+}
+
+#[test]
+fn normalize_jsx_text_줄바꿈은_공백_하나로_합쳐야_함() {
+    assert_eq!(normalize_jsx_text("안녕하세요\n      반갑습니다"), "안녕하세요 반갑습니다");
+    assert_eq!(normalize_jsx_text("안녕  하세요"), "안녕  하세요");
+    assert_eq!(normalize_jsx_text("안녕\n\n   \n하세요"), "안녕 하세요");
+}
+
+#[test]
+fn interpolation_var_name_표현식_종류별_이름을_생성해야_함() {
+    let module = parse_file(
+        "[name, user.profile.name, user?.name, getName(), list[0], this.title];",
+        ParseOptions::default(),
+    )
+    .unwrap();
+    let ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) = &module.body[0] else {
+        panic!("expression statement expected");
+    };
+    let Expr::Array(array) = expr.as_ref() else {
+        panic!("array expected");
+    };
+    let names: Vec<String> = array
+        .elems
+        .iter()
+        .enumerate()
+        .map(|(index, elem)| interpolation_var_name(&elem.as_ref().unwrap().expr, index))
+        .collect();
+
+    assert_eq!(
+        names,
+        vec!["name", "user_profile_name", "user_name", "expr3", "expr4", "title"]
+    );
+}
+
+#[test]
+fn is_component_init_함수와_memo_forward_ref_래퍼를_인식해야_함() {
+    let module = parse_file(
+        "[() => 1, function () {}, memo(() => 1), React.forwardRef(function () {}), memo(forwardRef(() => 1)), memo(Other), useMemo(() => 1), 1];",
+        ParseOptions::default(),
+    )
+    .unwrap();
+    let ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) = &module.body[0] else {
+        panic!("expression statement expected");
+    };
+    let Expr::Array(array) = expr.as_ref() else {
+        panic!("array expected");
+    };
+    let results: Vec<bool> = array
+        .elems
+        .iter()
+        .map(|elem| is_component_init(&elem.as_ref().unwrap().expr))
+        .collect();
+
+    assert_eq!(results, vec![true, true, true, true, true, false, false, false]);
+}
+
+#[test]
+fn skip_reason_조상_종류에_맞는_스킵_이유를_반환해야_함() {
+    use t_wrapper_rust::{skip_reason, SkipReason};
+
+    let cases = [
+        (AncestorKind::TranslationCall, SkipReason::AlreadyTranslated),
+        (AncestorKind::ModuleSource, SkipReason::ModuleSource),
+        (AncestorKind::PropertyKey, SkipReason::ObjectKey),
+        (AncestorKind::TypeOnly, SkipReason::TypeOnly),
+        (AncestorKind::Directive, SkipReason::Directive),
+    ];
+    for (kind, reason) in cases {
+        let ancestors = [Ancestor::new(AncestorKind::Node, DUMMY_SP), Ancestor::new(kind, DUMMY_SP)];
+        assert_eq!(skip_reason(DUMMY_SP, &ancestors, has_ignore_comment, None), Some(reason));
+    }
+    assert_eq!(skip_reason(DUMMY_SP, &[Ancestor::new(AncestorKind::Node, DUMMY_SP)], has_ignore_comment, None), None);
+
+    let (ast, comments) = parse_file_with_comments("// i18n-ignore\nconst a = \"한글\";", ParseOptions::default()).unwrap();
+    assert_eq!(
+        skip_reason(first_stmt_span(&ast), &[], has_ignore_comment, Some(&comments)),
+        Some(SkipReason::IgnoreComment)
+    );
+}
@@ -1,72 +1,485 @@
-/**
+/*!
  * ast-transformers 테스트
  * AST 변환 로직 테스트
  */
 
-use t_wrapper_rust::ast_transformers::{transform_function_body, TransformResult};
+#![allow(non_snake_case)]
+
+use t_wrapper_rust::ast_transformers::{
+    transform_module, transform_module_with_comments, transform_server_module_with_comments,
+};
+use t_wrapper_rust::parser::{parse_file, parse_file_with_comments, generate_code, ParseOptions};
 
 #[test]
-fn transform_function_body_한국어_문자열_리터럴을_t_호출로_변환해야_함() {
-    let code = r#"function Component() {
+fn transform_module_한국어_문자열_리터럴을_t_호출로_변환해야_함() {
+    let output = transform(r#"function Component() {
   const text = "안녕하세요";
   return <div>{text}</div>;
-}"#;
-    
-    // TODO: SWC AST traverse로 구현되면 실제 AST 노드로 테스트
-    // 현재는 소스코드 직접 검사로 테스트
-    let result = transform_function_body((), code);
-    assert!(result.was_modified);
+}"#);
+    assert!(output.contains(r#"const text = t("안녕하세요");"#), "{}", output);
 }
 
 #[test]
-fn transform_function_body_한국어_템플릿_리터럴을_t_호출로_변환해야_함() {
+fn transform_module_이미_t로_래핑된_문자열은_변환하지_않아야_함() {
     let code = r#"function Component() {
-  return <div>{`안녕 ${name}`}</div>;
+  return <div>{t("안녕하세요")}</div>;
 }"#;
-    
-    // TODO: SWC AST traverse로 구현되면 실제 AST 노드로 테스트
-    // 현재는 소스코드 직접 검사로 테스트
-    let result = transform_function_body((), code);
-    assert!(result.was_modified);
+    let mut module = parse_file(code, ParseOptions::default()).unwrap();
+    let (result, _) = transform_module(&mut module, code.to_string());
+    assert!(!result.was_modified);
 }
 
 #[test]
-fn transform_function_body_한국어_JSXText를_t_호출로_변환해야_함() {
+fn transform_module_한국어_JSXText를_JSXExpressionContainer로_교체해야_함() {
     let code = r#"function Component() {
   return <div>안녕하세요</div>;
 }"#;
-    
-    // TODO: SWC AST traverse로 구현되면 실제 AST 노드로 테스트
-    // 현재는 소스코드 직접 검사로 테스트
-    let result = transform_function_body((), code);
+
+    let mut module = parse_file(code, ParseOptions::default()).unwrap();
+    let (result, _) = transform_module(&mut module, code.to_string());
+    let output = generate_code(&module).unwrap();
+
     assert!(result.was_modified);
+    assert!(output.contains(r#"<div>{t("안녕하세요")}</div>"#), "{}", output);
 }
 
 #[test]
-fn transform_function_body_이미_t로_래핑된_문자열은_변환하지_않아야_함() {
+fn transform_module_JSXText의_앞뒤_공백은_유지해야_함() {
     let code = r#"function Component() {
-  return <div>{t("key")}</div>;
+  return <p> 총 <b>3</b>개 </p>;
 }"#;
-    
-    // TODO: SWC AST traverse로 구현되면 실제 AST 노드로 테스트
-    // 현재는 t() 함수 감지 로직이 없으므로 false 반환
-    let result = transform_function_body((), code);
-    // TODO: 실제 구현 후 false로 변경
-    // 현재는 한국어가 없으므로 false 반환
-    assert!(!result.was_modified);
+
+    let mut module = parse_file(code, ParseOptions::default()).unwrap();
+    transform_module(&mut module, code.to_string());
+    let output = generate_code(&module).unwrap();
+
+    assert!(output.contains(r#"<p> {t("총")} <b>3</b>{t("개")} </p>"#), "{}", output);
 }
 
 #[test]
-fn transform_function_body_i18n_ignore_주석이_있으면_변환하지_않아야_함() {
+fn transform_module_여러_줄_JSXText는_React_렌더링_기준으로_키를_만들어야_함() {
+    let code = r#"function Component() {
+  return (
+    <div>
+      안녕하세요
+      반갑습니다
+    </div>
+  );
+}"#;
+
+    let mut module = parse_file(code, ParseOptions::default()).unwrap();
+    transform_module(&mut module, code.to_string());
+    let output = generate_code(&module).unwrap();
+
+    assert!(output.contains(r#"{t("안녕하세요 반갑습니다")}"#), "{}", output);
+}
+
+#[test]
+fn transform_module_한국어가_없는_JSXText는_그대로_둬야_함() {
     let code = r#"function Component() {
-  // i18n-ignore
   return <div>Hello</div>;
 }"#;
-    
-    // TODO: SWC AST traverse로 구현되면 실제 AST 노드로 테스트
-    // 현재는 주석 감지 로직이 없으므로 false 반환
-    let result = transform_function_body((), code);
-    // TODO: 실제 구현 후 false로 변경
-    // 현재는 한국어가 없으므로 false 반환
+
+    let mut module = parse_file(code, ParseOptions::default()).unwrap();
+    let (result, _) = transform_module(&mut module, code.to_string());
+    let output = generate_code(&module).unwrap();
+
     assert!(!result.was_modified);
+    assert!(output.contains("<div>Hello</div>"), "{}", output);
+}
+
+fn transform(code: &str) -> String {
+    let mut module = parse_file(code, ParseOptions::default()).unwrap();
+    transform_module(&mut module, code.to_string());
+    generate_code(&module).unwrap()
+}
+
+#[test]
+fn transform_module_템플릿_리터럴을_i18next_interpolation으로_변환해야_함() {
+    let output = transform(r#"function Component({ name }) {
+  return <div>{`안녕하세요 ${name}님`}</div>;
+}"#);
+
+    assert!(output.contains(r#"t("안녕하세요 {{name}}님", {"#), "{}", output);
+    assert!(!output.contains("name: name"), "{}", output);
+}
+
+#[test]
+fn transform_module_템플릿_멤버_표현식은_MEMBER_SEPARATOR로_연결해야_함() {
+    let output = transform(r#"function Component({ user }) {
+  return <div>{`${user.name}님 환영합니다`}</div>;
+}"#);
+
+    assert!(output.contains(r#"t("{{user_name}}님 환영합니다", {"#), "{}", output);
+    assert!(output.contains("user_name: user.name"), "{}", output);
+}
+
+#[test]
+fn transform_module_템플릿_호출_표현식은_EXPR_PREFIX를_사용해야_함() {
+    let output = transform(r#"function Component({ items }) {
+  return <div>{`총 ${items.length}개, ${format(items[0])} 외`}</div>;
+}"#);
+
+    assert!(output.contains(r#"t("총 {{items_length}}개, {{expr1}} 외", {"#), "{}", output);
+    assert!(output.contains("expr1: format(items[0])"), "{}", output);
+}
+
+#[test]
+fn transform_module_템플릿_변수명이_충돌하면_접미사를_붙이고_같은_표현식은_재사용해야_함() {
+    let output = transform(r#"function Component({ user, user_name }) {
+  return <div>{`${user.name} ${user_name} ${user.name}님`}</div>;
+}"#);
+
+    assert!(
+        output.contains(r#"t("{{user_name}} {{user_name_1}} {{user_name}}님", {"#),
+        "{}",
+        output
+    );
+    assert!(output.contains("user_name_1: user_name"), "{}", output);
+    assert!(output.contains("user_name: user.name,"), "{}", output);
+}
+
+#[test]
+fn transform_module_한국어가_없는_템플릿과_태그드_템플릿은_그대로_둬야_함() {
+    let code = r#"function Component({ name }) {
+  const Title = styled.h1`color: red; content: "안녕";`;
+  return <div className={`box ${name}`}>{Title}</div>;
+}"#;
+    let mut module = parse_file(code, ParseOptions::default()).unwrap();
+    let (result, _) = transform_module(&mut module, code.to_string());
+    let output = generate_code(&module).unwrap();
+
+    assert!(!result.was_modified);
+    assert!(output.contains("`box ${name}`"), "{}", output);
+}
+
+#[test]
+fn transform_module_JSX_속성의_한국어_문자열을_t_호출로_감싸야_함() {
+    let output = transform(r#"function SearchForm() {
+  return (
+    <form title="검색">
+      <input placeholder="검색어 입력" aria-label="검색어" />
+      <img alt="로고" src="/로고.png" />
+    </form>
+  );
+}"#);
+
+    assert!(output.contains(r#"title={t("검색")}"#), "{}", output);
+    assert!(output.contains(r#"placeholder={t("검색어 입력")}"#), "{}", output);
+    assert!(output.contains(r#"aria-label={t("검색어")}"#), "{}", output);
+    assert!(output.contains(r#"alt={t("로고")}"#), "{}", output);
+    assert!(output.contains(r#"src="/로고.png""#), "{}", output);
+}
+
+#[test]
+fn transform_module_className_등_보이지_않는_속성은_변환하지_않아야_함() {
+    let code = r#"function Component() {
+  return <div className="헤더" id="메인" title="Hello" />;
+}"#;
+    let mut module = parse_file(code, ParseOptions::default()).unwrap();
+    let (result, _) = transform_module(&mut module, code.to_string());
+    let output = generate_code(&module).unwrap();
+
+    assert!(!result.was_modified);
+    assert!(output.contains(r#"className="헤더""#), "{}", output);
+}
+
+#[test]
+fn transform_module_JSX_속성_표현식_안의_문자열도_변환해야_함() {
+    let output = transform(r#"function Component({ open }) {
+  return <button title={open ? "닫기" : "열기"} />;
+}"#);
+
+    assert!(output.contains(r#"title={open ? t("닫기") : t("열기")}"#), "{}", output);
+}
+
+fn transform_with_comments(code: &str) -> (bool, String) {
+    let (mut module, comments) = parse_file_with_comments(code, ParseOptions::default()).unwrap();
+    let (result, _) = transform_module_with_comments(&mut module, code.to_string(), &comments);
+    (result.was_modified, generate_code(&module).unwrap())
+}
+
+#[test]
+fn transform_server_module_with_comments_훅은_변환하지_않고_기록해야_함() {
+    let code = r#"export function useLabel() {
+  return "라벨";
+}
+const useEmpty = () => null;
+export function Page() {
+  return <p>안녕하세요</p>;
+}"#;
+    let (mut module, comments) = parse_file_with_comments(code, ParseOptions::default()).unwrap();
+    let (result, modified_functions) =
+        transform_server_module_with_comments(&mut module, code.to_string(), &comments);
+    let output = generate_code(&module).unwrap();
+
+    assert_eq!(modified_functions, vec!["Page".to_string()]);
+    assert_eq!(result.skipped_hooks, vec!["useLabel".to_string()]);
+    assert_eq!(result.records[0].skip_reason, Some(t_wrapper_rust::ast_helpers::SkipReason::ServerHook));
+    assert!(output.contains(r#"return "라벨";"#), "{}", output);
+    assert!(output.contains(r#"<p>{t("안녕하세요")}</p>"#), "{}", output);
+}
+
+#[test]
+fn transform_module_with_comments_i18n_ignore는_바로_다음_구문에만_적용되어야_함() {
+    let (_, output) = transform_with_comments(r#"// i18n-ignore
+const API_KEY = "한글 API 키";
+
+function Component() {
+  // i18n-ignore
+  const raw = "무시";
+  const label = "라벨";
+  return <div>{label}</div>;
+}"#);
+
+    assert!(output.contains(r#""한글 API 키""#), "{}", output);
+    assert!(output.contains(r#"const raw = "무시""#), "{}", output);
+    assert!(output.contains(r#"t("라벨")"#), "{}", output);
+}
+
+#[test]
+fn transform_module_with_comments_JSX_주석은_다음_JSX_요소에만_적용되어야_함() {
+    let (_, output) = transform_with_comments(r#"function Component() {
+  return (
+    <div>
+      {/* i18n-ignore */}
+      <p>이것은 무시됩니다</p>
+      <p>번역됩니다</p>
+    </div>
+  );
+}"#);
+
+    assert!(output.contains("<p>이것은 무시됩니다</p>"), "{}", output);
+    assert!(output.contains(r#"<p>{t("번역됩니다")}</p>"#), "{}", output);
+}
+
+#[test]
+fn transform_module_with_comments_객체_프로퍼티_주석은_해당_프로퍼티에만_적용되어야_함() {
+    let (_, output) = transform_with_comments(r#"function Component() {
+  const labels = {
+    /* i18n-ignore */
+    code: "코드",
+    title: "제목",
+  };
+  return <div>{labels.title}</div>;
+}"#);
+
+    assert!(output.contains(r#"code: "코드""#), "{}", output);
+    assert!(output.contains(r#"title: t("제목")"#), "{}", output);
+}
+
+#[test]
+fn transform_module_with_comments_리터럴_앞의_인라인_주석도_인식해야_함() {
+    let (was_modified, output) = transform_with_comments(r#"function Component() {
+  return <input placeholder={/* i18n-ignore */ "입력"} />;
+}"#);
+
+    assert!(!was_modified);
+    assert!(output.contains(r#""입력""#), "{}", output);
+}
+
+#[test]
+fn transform_module_이미_t로_래핑된_문자열은_다시_감싸지_않아야_함() {
+    let code = r#"function Component() {
+  return <div title={t("제목")}>{t("안녕하세요")}{i18n.t(`환영 ${name}`)}</div>;
+}"#;
+    let mut module = parse_file(code, ParseOptions::default()).unwrap();
+    let (result, _) = transform_module(&mut module, code.to_string());
+    let output = generate_code(&module).unwrap();
+
+    assert!(!result.was_modified, "{}", output);
+    assert!(!output.contains("t(t("), "{}", output);
+}
+
+#[test]
+fn transform_module_실행을_반복해도_결과가_같아야_함() {
+    let once = transform(r#"function Component({ name }) {
+  return <div title="제목">안녕하세요 {`${name}님`}</div>;
+}"#);
+    let twice = transform(&once);
+
+    assert_eq!(once, twice);
+}
+
+#[test]
+fn transform_module_import_export_경로와_require는_변환하지_않아야_함() {
+    let code = r#"import "./한글.css";
+import 아이콘 from "./아이콘.svg";
+export * from "./한글모듈";
+export { 값 } from "./한글값";
+const 설정 = require("./설정.json");
+const Page = lazy(() => import("./페이지"));"#;
+    let mut module = parse_file(code, ParseOptions::default()).unwrap();
+    let (result, _) = transform_module(&mut module, code.to_string());
+    let output = generate_code(&module).unwrap();
+
+    assert!(!result.was_modified, "{}", output);
+    assert!(!output.contains(" t(\""), "{}", output);
+    assert!(!output.contains("(t(\""), "{}", output);
+}
+
+#[test]
+fn transform_module_객체_KEY와_computed_접근_KEY는_변환하지_않아야_함() {
+    let output = transform(r#"function Component({ data }) {
+  const map = { ["이름"]: "값", "키": "라벨" };
+  return <div>{data["제목"]}</div>;
+}"#);
+
+    assert!(output.contains(r#"["이름"]: t("값")"#), "{}", output);
+    assert!(output.contains(r#""키": t("라벨")"#), "{}", output);
+    assert!(output.contains(r#"data["제목"]"#), "{}", output);
+}
+
+#[test]
+fn transform_module_TS_타입_enum_declare는_변환하지_않아야_함() {
+    let code = r#"type Status = "대기" | "완료";
+enum Label { Done = "완료" }
+declare const 전역: "한글";
+declare module "한글-모듈" {
+  const value: string;
+}
+declare global {
+  interface Window { title: "제목" }
+}"#;
+    let mut module = parse_file(code, ParseOptions::default()).unwrap();
+    let (result, _) = transform_module(&mut module, code.to_string());
+    let output = generate_code(&module).unwrap();
+
+    assert!(!result.was_modified, "{}", output);
+}
+
+#[test]
+fn transform_module_디렉티브는_변환하지_않아야_함() {
+    let code = r#""use 한글";
+function useAction() {
+  "use strict";
+  "서버 전용";
+  return "완료";
+}"#;
+    let mut module = parse_file(code, ParseOptions::default()).unwrap();
+    transform_module(&mut module, code.to_string());
+    let output = generate_code(&module).unwrap();
+
+    assert!(output.contains(r#""use 한글";"#), "{}", output);
+    assert!(output.contains(r#""use strict";"#), "{}", output);
+    assert!(output.contains(r#""서버 전용";"#), "{}", output);
+    assert!(output.contains(r#"return t("완료")"#), "{}", output);
+}
+
+fn transform_with_functions(code: &str) -> (String, Vec<String>) {
+    let mut module = parse_file(code, ParseOptions::default()).unwrap();
+    let (_, modified_functions) = transform_module(&mut module, code.to_string());
+    (generate_code(&module).unwrap(), modified_functions)
+}
+
+#[test]
+fn transform_module_컴포넌트_밖의_문자열은_변환하지_않아야_함() {
+    let (output, modified_functions) = transform_with_functions(r#"const MESSAGE = "모듈 상수";
+function formatDate(date) {
+  return `${date}일`;
+}
+const helper = () => "유틸";
+const element = <div>모듈 JSX</div>;"#);
+
+    assert!(!output.contains(" t("), "{}", output);
+    assert!(modified_functions.is_empty());
+}
+
+#[test]
+fn transform_module_화살표_함수_컴포넌트와_훅의_이름을_기록해야_함() {
+    let (output, modified_functions) = transform_with_functions(r#"const Button = () => <button>확인</button>;
+const useTitle = function () {
+  return "제목";
+};
+const Empty = () => <div>empty</div>;"#);
+
+    assert!(output.contains(r#"{t("확인")}"#), "{}", output);
+    assert!(output.contains(r#"return t("제목")"#), "{}", output);
+    assert_eq!(modified_functions, vec!["Button", "useTitle"]);
+}
+
+#[test]
+fn transform_module_memo_forwardRef로_감싼_컴포넌트도_변환해야_함() {
+    let (output, modified_functions) = transform_with_functions(r#"const Card = memo(() => <div>카드</div>);
+const Input = React.forwardRef((props, ref) => <input ref={ref} placeholder="입력" />);
+const Both = memo(forwardRef(function (props, ref) {
+  return <span>둘 다</span>;
+}));
+const notComponent = memo(() => <div>소문자</div>);"#);
+
+    assert!(output.contains(r#"{t("카드")}"#), "{}", output);
+    assert!(output.contains(r#"placeholder={t("입력")}"#), "{}", output);
+    assert!(output.contains(r#"{t("둘 다")}"#), "{}", output);
+    assert!(output.contains("소문자"), "{}", output);
+    assert!(!output.contains(r#"t("소문자")"#), "{}", output);
+    assert_eq!(modified_functions, vec!["Card", "Input", "Both"]);
+}
+
+#[test]
+fn transform_module_export_default_function도_컴포넌트로_처리해야_함() {
+    let (output, modified_functions) = transform_with_functions(r#"export default function () {
+  return <main>메인</main>;
+}"#);
+    assert!(output.contains(r#"{t("메인")}"#), "{}", output);
+    assert_eq!(modified_functions, vec!["default"]);
+
+    let (output, modified_functions) = transform_with_functions(r#"export default function Page() {
+  const items = ["하나"].map((item) => `${item} 항목`);
+  return <main>{items}</main>;
+}"#);
+    assert!(output.contains(r#"t("하나")"#), "{}", output);
+    assert!(output.contains(r#"t("{{item}} 항목", {"#), "{}", output);
+    assert_eq!(modified_functions, vec!["Page"]);
+}
+
+#[test]
+fn transform_module_with_comments_후보_문자열마다_위치_키_종류_스킵_이유를_기록해야_함() {
+    use t_wrapper_rust::ast_helpers::SkipReason;
+    use t_wrapper_rust::ast_transformers::StringKind;
+    use t_wrapper_rust::session::Session;
+
+    let code = r#"const TITLE = "모듈 상수";
+function Component({ name }) {
+  const map = { ["키"]: 1 };
+  // i18n-ignore
+  const raw = "무시";
+  return (
+    <div className="한글 클래스" title="제목">
+      {`안녕 ${name}님`}
+      본문
+      {t("이미")}
+    </div>
+  );
+}"#;
+    let session = Session::new();
+    let options = ParseOptions {
+        file_name: Some("src/Component.tsx".to_string()),
+        ..Default::default()
+    };
+    let (mut module, file) = session.parse_file(code, &options).unwrap();
+    let (mut result, _) = transform_module_with_comments(&mut module, code.to_string(), session.comments());
+    result.locate(&file);
+    assert!(result.records.iter().all(|record| record.file == "src/Component.tsx"));
+
+    let summary: Vec<_> = result
+        .records
+        .iter()
+        .map(|record| (record.line, record.column, record.text.as_str(), record.key.as_deref(), record.kind, record.skip_reason))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (1, 15, "모듈 상수", None, StringKind::StringLiteral, Some(SkipReason::NonComponentScope)),
+            (3, 18, "키", None, StringKind::StringLiteral, Some(SkipReason::ObjectKey)),
+            (5, 15, "무시", None, StringKind::StringLiteral, Some(SkipReason::IgnoreComment)),
+            (7, 20, "한글 클래스", None, StringKind::JsxAttribute, Some(SkipReason::NonTranslatableAttribute)),
+            (7, 35, "제목", Some("제목"), StringKind::JsxAttribute, None),
+            (8, 8, "`안녕 ${name}님`", Some("안녕 {{name}}님"), StringKind::TemplateLiteral, None),
+            (9, 7, "본문", Some("본문"), StringKind::JsxText, None),
+            (10, 10, "이미", None, StringKind::StringLiteral, Some(SkipReason::AlreadyTranslated)),
+        ]
+    );
+    assert!(result.records.iter().all(|record| record.file == "src/Component.tsx"));
+    assert_eq!(result.wrapped_count(), 3);
+    assert_eq!(result.skipped_count(), 5);
 }
@@ -0,0 +1,113 @@
+/*!
+ * cache 테스트
+ * 증분 실행 캐시 테스트
+ */
+
+#![allow(non_snake_case)]
+
+use t_wrapper_rust::{config_hash, content_hash, ParseOptions, ScriptConfig, TransformCache, CACHE_TOOL_VERSION};
+use anyhow::Result;
+use tempfile::tempdir;
+use std::fs;
+
+#[test]
+fn TransformCache_저장한_항목을_다시_읽어야_함() -> Result<()> {
+    let dir = tempdir()?;
+    let cache_path = dir.path().join(".i18nexus/cache.json");
+
+    let mut cache = TransformCache::load(&cache_path, "config");
+    assert!(cache.is_empty());
+    cache.record_unchanged("src/a.tsx", "hash-a");
+    cache.record_unchanged("src/b.tsx", "hash-b");
+    cache.remove("src/b.tsx");
+    cache.save()?;
+
+    let json: serde_json::Value = serde_json::from_str(&fs::read_to_string(&cache_path)?)?;
+    assert_eq!(json["version"], CACHE_TOOL_VERSION);
+    assert_eq!(json["config_hash"], "config");
+
+    let cache = TransformCache::load(&cache_path, "config");
+    assert_eq!(cache.len(), 1);
+    assert!(cache.is_unchanged("src/a.tsx", "hash-a"));
+    assert!(!cache.is_unchanged("src/a.tsx", "hash-changed"));
+    assert!(!cache.is_unchanged("src/b.tsx", "hash-b"));
+    Ok(())
+}
+
+#[test]
+fn TransformCache_설정_해시나_도구_버전이_다르거나_읽을_수_없으면_비어_있어야_함() -> Result<()> {
+    let dir = tempdir()?;
+    let cache_path = dir.path().join("cache.json");
+
+    let mut cache = TransformCache::load(&cache_path, "config");
+    cache.record_unchanged("src/a.tsx", "hash-a");
+    cache.save()?;
+    assert!(TransformCache::load(&cache_path, "other-config").is_empty());
+
+    fs::write(
+        &cache_path,
+        r#"{ "version": "0.0.0-old", "config_hash": "config", "files": { "src/a.tsx": "hash-a" } }"#,
+    )?;
+    assert!(TransformCache::load(&cache_path, "config").is_empty());
+
+    // 패키지 버전이 같아도 다른 소스로 빌드한 도구의 캐시는 버림
+    fs::write(
+        &cache_path,
+        format!(
+            r#"{{ "version": "{}+0000000000000000", "config_hash": "config", "files": {{ "src/a.tsx": "hash-a" }} }}"#,
+            env!("CARGO_PKG_VERSION")
+        ),
+    )?;
+    assert!(TransformCache::load(&cache_path, "config").is_empty());
+    assert!(CACHE_TOOL_VERSION.starts_with(concat!(env!("CARGO_PKG_VERSION"), "+")));
+
+    fs::write(&cache_path, "{ not json")?;
+    let mut cache = TransformCache::load(&cache_path, "config");
+    assert!(cache.is_empty());
+    // 버린 캐시는 다음 저장에서 새로 씀
+    cache.save()?;
+    let json: serde_json::Value = serde_json::from_str(&fs::read_to_string(&cache_path)?)?;
+    assert_eq!(json["files"], serde_json::json!({}));
+    Ok(())
+}
+
+#[test]
+fn content_hash_코드와_파싱_옵션이_같을_때만_같아야_함() {
+    let options = ParseOptions::default();
+    let hash = content_hash("const a = \"안녕\";", &options);
+    assert_eq!(hash.len(), 64);
+    assert_eq!(hash, content_hash("const a = \"안녕\";", &options));
+    assert_ne!(hash, content_hash("const a = \"안녕!\";", &options));
+    assert_ne!(
+        hash,
+        content_hash("const a = \"안녕\";", &ParseOptions { jsx: false, ..Default::default() })
+    );
+}
+
+#[test]
+fn config_hash_변환에_영향을_주는_설정만_반영해야_함() -> Result<()> {
+    let base = config_hash(&ScriptConfig::default())?;
+
+    // 실행 옵션은 캐시를 무효화하지 않음
+    let run_options = ScriptConfig {
+        source_pattern: "app/**/*.tsx".to_string(),
+        dry_run: true,
+        jobs: Some(4),
+        cache_file: Some("other.json".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(config_hash(&run_options)?, base);
+
+    // 변환 설정이 바뀌면 무효화
+    let mode = ScriptConfig {
+        mode: Some("server".to_string()),
+        ..Default::default()
+    };
+    assert_ne!(config_hash(&mode)?, base);
+    let import_source = ScriptConfig {
+        translation_import_source: "@/i18n".to_string(),
+        ..Default::default()
+    };
+    assert_ne!(config_hash(&import_source)?, base);
+    Ok(())
+}
@@ -0,0 +1,84 @@
+/*!
+ * classifier 테스트
+ * 파일 단위 서버/클라이언트 분류 테스트
+ */
+
+use t_wrapper_rust::classifier::{classify_module, ModeReason, TranslationMode};
+use t_wrapper_rust::parser::{parse_file, ParseOptions};
+use t_wrapper_rust::ModeClassification;
+
+fn classify(code: &str) -> ModeClassification {
+    let module = parse_file(code, ParseOptions::default()).unwrap();
+    classify_module(&module, "getServerTranslation", TranslationMode::Client)
+}
+
+#[test]
+fn classify_module_디렉티브가_가장_우선해야_함() {
+    let result = classify(r#""use client";
+import { cookies } from "next/headers";"#);
+    assert_eq!(result.mode, TranslationMode::Client);
+    assert_eq!(result.reason, ModeReason::UseClientDirective);
+
+    let result = classify(r#"'use server';
+export async function save() {}"#);
+    assert_eq!(result.mode, TranslationMode::Server);
+    assert_eq!(result.reason, ModeReason::UseServerDirective);
+}
+
+#[test]
+fn classify_module_서버_전용_import는_서버로_분류해야_함() {
+    let result = classify(r#"import "server-only";
+export function Page() { return <div />; }"#);
+    assert_eq!(result.mode, TranslationMode::Server);
+    assert_eq!(result.reason, ModeReason::ServerOnlyImport("server-only".to_string()));
+
+    let result = classify(r#"import { headers } from "next/headers";"#);
+    assert_eq!(result.reason, ModeReason::ServerOnlyImport("next/headers".to_string()));
+}
+
+#[test]
+fn classify_module_서버_번역_함수는_별칭과_커스텀_이름도_인식해야_함() {
+    let result = classify(r#"import { getServerTranslation as getT } from "i18nexus/server";
+export default function Page() {
+  const { t } = getT();
+  return <div />;
+}"#);
+    assert_eq!(result.mode, TranslationMode::Server);
+    assert_eq!(result.reason, ModeReason::ServerTranslationCall("getT".to_string()));
+
+    let module = parse_file(
+        "async function Page() { const { t } = await getServerT(); }",
+        ParseOptions::default(),
+    )
+    .unwrap();
+    let result = classify_module(&module, "getServerT", TranslationMode::Client);
+    assert_eq!(result.reason, ModeReason::ServerTranslationCall("getServerT".to_string()));
+}
+
+#[test]
+fn classify_module_훅을_사용하면_클라이언트로_분류해야_함() {
+    let result = classify(r#"export default async function Page() {
+  const [open, setOpen] = React.useState(false);
+  return <div />;
+}"#);
+    assert_eq!(result.mode, TranslationMode::Client);
+    assert_eq!(result.reason, ModeReason::ClientHook("useState".to_string()));
+}
+
+#[test]
+fn classify_module_async_default_export는_서버로_분류해야_함() {
+    let result = classify("export default async function Page() { return <div />; }");
+    assert_eq!(result.reason, ModeReason::AsyncDefaultExport);
+
+    let result = classify("const Page = async () => <div />;\nexport default Page;");
+    assert_eq!(result.mode, TranslationMode::Server);
+    assert_eq!(result.reason, ModeReason::AsyncDefaultExport);
+}
+
+#[test]
+fn classify_module_신호가_없으면_기본_모드를_사용해야_함() {
+    let module = parse_file("export function Card() { return <div />; }", ParseOptions::default()).unwrap();
+    let result = classify_module(&module, "getServerTranslation", TranslationMode::Server);
+    assert_eq!(result.mode, TranslationMode::Server);
+    assert_eq!(result.reason, ModeReason::NoSignal);
+}
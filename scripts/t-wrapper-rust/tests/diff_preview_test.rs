@@ -0,0 +1,54 @@
+/*!
+ * diff-preview 테스트
+ * dry-run unified diff와 요약 출력 테스트
+ */
+
+#![allow(non_snake_case)]
+
+use t_wrapper_rust::diff_preview::{colorize_diff, unified_diff, DryRunSummary};
+
+#[test]
+fn unified_diff_git_형식의_헤더와_hunk를_만들어야_함() {
+    let original = "function A() {\n  return <p>안녕</p>;\n}\n";
+    let modified = "function A() {\n  const { t } = useTranslation();\n  return <p>{t(\"안녕\")}</p>;\n}\n";
+
+    let diff = unified_diff("src/A.tsx", original, modified);
+    assert_eq!(
+        diff,
+        r#"diff --git a/src/A.tsx b/src/A.tsx
+--- a/src/A.tsx
++++ b/src/A.tsx
+@@ -1,3 +1,4 @@
+ function A() {
+-  return <p>안녕</p>;
++  const { t } = useTranslation();
++  return <p>{t("안녕")}</p>;
+ }
+"#
+    );
+    assert!(unified_diff("src/A.tsx", original, original).is_empty());
+}
+
+#[test]
+fn colorize_diff_줄_종류별로_색상을_적용해야_함() {
+    let diff = "--- a/x\n+++ b/x\n@@ -1 +1 @@\n-old\n+new\n same\n";
+    let colored = colorize_diff(diff);
+    assert!(colored.contains("\x1b[31m-old\x1b[0m\n"));
+    assert!(colored.contains("\x1b[32m+new\x1b[0m\n"));
+    assert!(colored.contains("\x1b[36m@@ -1 +1 @@\x1b[0m\n"));
+    assert!(colored.contains("\x1b[1m+++ b/x\x1b[0m\n"));
+    assert!(colored.ends_with("\n same\n"));
+}
+
+#[test]
+fn DryRunSummary_파일_수와_문자열_수를_요약해야_함() {
+    let mut summary = DryRunSummary::new(3);
+    summary.add_modified_file("src/A.tsx", 2);
+    summary.add_modified_file("src/B.tsx", 1);
+
+    assert_eq!(summary.wrapped_count(), 3);
+    assert_eq!(
+        summary.render(),
+        "[DRY RUN] Summary:\n  - Files processed: 3\n  - Files would be modified: 2\n  - Strings wrapped: 3\n    src/A.tsx (2)\n    src/B.tsx (1)\n"
+    );
+}
@@ -1,12 +1,13 @@
-/**
+/*!
  * t-wrapper E2E 테스트
  * 실제 파일 시스템을 사용하여 전체 워크플로우 테스트
  */
 
+#![allow(non_snake_case)]
+
 use t_wrapper_rust::run_translation_wrapper;
 use t_wrapper_rust::ScriptConfig;
 use std::fs;
-use std::path::PathBuf;
 use tempfile::TempDir;
 
 #[test]
@@ -28,10 +29,9 @@ fn e2e_한국어_문자열을_t_함수로_변환해야_함() {
     run_translation_wrapper(config).unwrap();
 
     let modified_content = fs::read_to_string(&test_file).unwrap();
-    // TODO: 실제 구현 후 확인
-    // assert!(modified_content.contains("t("));
-    // assert!(modified_content.contains("useTranslation"));
-    // assert_ne!(modified_content, original_content);
+    assert!(modified_content.contains(r#"<div>{t("안녕하세요")}</div>"#));
+    assert_ne!(modified_content, original_content);
+    assert!(modified_content.contains("const { t } = useTranslation();"));
 }
 
 #[test]
@@ -54,10 +54,9 @@ fn e2e_템플릿_리터럴을_i18next_형식으로_변환해야_함() {
     run_translation_wrapper(config).unwrap();
 
     let modified_content = fs::read_to_string(&test_file).unwrap();
-    // TODO: 실제 구현 후 확인
-    // assert!(modified_content.contains("t("));
-    // assert!(modified_content.contains("useTranslation"));
-    // assert!(!modified_content.contains("`안녕하세요 ${name}님`"));
+    assert!(modified_content.contains(r#"t("안녕하세요 {{name}}님", {"#));
+    assert!(!modified_content.contains("`안녕하세요 ${name}님`"));
+    assert!(modified_content.contains("const { t } = useTranslation();"));
 }
 
 #[test]
@@ -80,10 +79,9 @@ fn e2e_서버_컴포넌트는_useTranslation_훅을_추가하지_않아야_함()
     run_translation_wrapper(config).unwrap();
 
     let modified_content = fs::read_to_string(&test_file).unwrap();
-    // TODO: 실제 구현 후 확인
-    // assert!(modified_content.contains("t("));
-    // assert!(!modified_content.contains("useTranslation"));
-    // assert!(modified_content.contains("getServerTranslation"));
+    assert!(modified_content.contains("t("));
+    assert!(!modified_content.contains("useTranslation"));
+    assert!(modified_content.contains("getServerTranslation"));
 }
 
 #[test]
@@ -107,9 +105,8 @@ fn e2e_i18n_ignore_주석이_있으면_변환하지_않아야_함() {
     run_translation_wrapper(config).unwrap();
 
     let modified_content = fs::read_to_string(&test_file).unwrap();
-    // TODO: 실제 구현 후 확인
-    // assert!(modified_content.contains(r#"const text = "안녕하세요""#));
-    // assert!(!modified_content.contains(r#"t("안녕하세요")"#));
+    assert!(modified_content.contains(r#"const text = "안녕하세요""#));
+    assert!(!modified_content.contains(r#"t("안녕하세요")"#));
 }
 
 #[test]
@@ -132,10 +129,10 @@ fn e2e_nextjs_환경에서_client_모드일_때만_use_client를_추가해야_
     run_translation_wrapper(config).unwrap();
 
     let modified = fs::read_to_string(&test_file).unwrap();
-    // TODO: 실제 구현 후 확인
-    // assert!(modified.contains("'use client'"));
-    // assert!(modified.contains("useTranslation"));
-    // assert!(modified.contains("t("));
+    assert!(modified.contains("t("));
+    assert!(modified.contains("useTranslation"));
+    assert!(modified.starts_with(r#""use client";
+import { useTranslation } from "i18nexus";"#), "{}", modified);
 }
 
 #[test]
@@ -158,10 +155,9 @@ fn e2e_react_환경에서_client_모드일_때는_use_client를_추가하지_않
     run_translation_wrapper(config).unwrap();
 
     let modified = fs::read_to_string(&test_file).unwrap();
-    // TODO: 실제 구현 후 확인
-    // assert!(!modified.contains("'use client'"));
-    // assert!(modified.contains("useTranslation"));
-    // assert!(modified.contains("t("));
+    assert!(modified.contains("t("));
+    assert!(modified.contains("useTranslation"));
+    assert!(!modified.contains("use client"), "{}", modified);
 }
 
 #[test]
@@ -183,10 +179,42 @@ fn e2e_server_모드에서는_getServerTranslation_기반으로_t_바인딩을_
     run_translation_wrapper(config).unwrap();
 
     let modified = fs::read_to_string(&test_file).unwrap();
-    // TODO: 실제 구현 후 확인
-    // assert!(modified.contains("await getServerTranslation"));
-    // assert!(modified.contains("const { t } ="));
-    // assert!(modified.contains("t("));
+    assert!(modified.contains("t("));
+    assert!(modified.contains("async function ServerComp()"), "{}", modified);
+    assert!(modified.contains("const { t } = await getServerTranslation();"), "{}", modified);
+    assert!(modified.contains(r#"import { getServerTranslation } from "i18nexus";"#), "{}", modified);
+    assert!(!modified.contains("useTranslation"), "{}", modified);
+}
+
+#[test]
+fn e2e_server_모드에서_훅은_async로_바꾸지_않고_그대로_두어야_함() {
+    let temp_dir = TempDir::new().unwrap();
+    let hook_file = temp_dir.path().join("useLabel.ts");
+    let hook_content = r#"export function useLabel(): string {
+  const x = <string>"라벨";
+  return x;
+}"#;
+    fs::write(&hook_file, hook_content).unwrap();
+    let page_file = temp_dir.path().join("Page.tsx");
+    fs::write(&page_file, r#"export const useTitle = () => "제목";
+export function Page() {
+  return <h1>안녕하세요</h1>;
+}"#).unwrap();
+
+    let config = ScriptConfig {
+        source_pattern: temp_dir.path().join("**/*.{ts,tsx}").to_string_lossy().to_string(),
+        dry_run: false,
+        mode: Some("server".to_string()),
+        ..Default::default()
+    };
+    run_translation_wrapper(config).unwrap();
+
+    assert_eq!(fs::read_to_string(&hook_file).unwrap(), hook_content);
+
+    let page = fs::read_to_string(&page_file).unwrap();
+    assert!(page.contains(r#"export const useTitle = ()=>"제목";"#), "{}", page);
+    assert!(page.contains("export async function Page()"), "{}", page);
+    assert!(page.contains(r#"<h1>{t("안녕하세요")}</h1>"#), "{}", page);
 }
 
 #[test]
@@ -209,9 +237,29 @@ fn e2e_serverTranslationFunction_커스텀_함수명을_사용해야_함() {
     run_translation_wrapper(config).unwrap();
 
     let modified = fs::read_to_string(&test_file).unwrap();
-    // TODO: 실제 구현 후 확인
-    // assert!(modified.contains("await getServerT"));
-    // assert!(modified.contains("import { getServerT } from"));
+    assert!(modified.contains("t("));
+    assert!(modified.contains("await getServerT()"), "{}", modified);
+    assert!(modified.contains("import { getServerT } from"), "{}", modified);
+}
+
+#[test]
+fn e2e_tsconfig에_jsx_설정이_없어도_js_파일의_JSX를_변환해야_함() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("tsconfig.json"), r#"{ "compilerOptions": { "target": "ES2020" } }"#).unwrap();
+    let test_file = temp_dir.path().join("Legacy.js");
+    fs::write(&test_file, r#"export function Legacy() {
+  return <div>안녕하세요</div>;
+}"#).unwrap();
+
+    let config = ScriptConfig {
+        source_pattern: temp_dir.path().join("**/*.js").to_string_lossy().to_string(),
+        dry_run: false,
+        ..Default::default()
+    };
+    run_translation_wrapper(config).unwrap();
+
+    let content = fs::read_to_string(&test_file).unwrap();
+    assert!(content.contains(r#"<div>{t("안녕하세요")}</div>"#), "{}", content);
 }
 
 #[test]
@@ -236,3 +284,638 @@ fn e2e_dry_run_모드에서는_파일을_수정하지_않아야_함() {
     assert_eq!(modified_content, original_content);
 }
 
+
+#[test]
+fn e2e_화살표_함수_컴포넌트의_concise_body는_block으로_바꿔_훅을_추가해야_함() {
+    let temp_dir = TempDir::new().unwrap();
+    let test_file = temp_dir.path().join("Arrow.tsx");
+    let original_content = r#"const Arrow = () => <div>안녕하세요</div>;
+const WithHook = () => {
+  const { t } = useTranslation();
+  return <div>반갑습니다</div>;
+};"#;
+
+    fs::write(&test_file, original_content).unwrap();
+
+    let config = ScriptConfig {
+        source_pattern: temp_dir.path().join("**/*.tsx").to_string_lossy().to_string(),
+        dry_run: false,
+        ..Default::default()
+    };
+
+    run_translation_wrapper(config).unwrap();
+
+    let modified_content = fs::read_to_string(&test_file).unwrap();
+    assert!(modified_content.contains(r#"const Arrow = ()=>{
+    const { t } = useTranslation();
+    return <div>{t("안녕하세요")}</div>;
+};"#), "{}", modified_content);
+    assert_eq!(modified_content.matches("useTranslation()").count(), 2, "{}", modified_content);
+}
+
+#[test]
+fn e2e_useTranslation_import를_추가하고_별칭은_그대로_사용해야_함() {
+    let temp_dir = TempDir::new().unwrap();
+    let plain_file = temp_dir.path().join("Plain.tsx");
+    let aliased_file = temp_dir.path().join("Aliased.tsx");
+    fs::write(&plain_file, r#"import React from "react";
+function Plain() {
+  return <div>안녕하세요</div>;
+}"#).unwrap();
+    fs::write(&aliased_file, r#"import { useTranslation as useT } from "i18nexus";
+function Aliased() {
+  return <div>안녕하세요</div>;
+}"#).unwrap();
+
+    let config = ScriptConfig {
+        source_pattern: temp_dir.path().join("**/*.tsx").to_string_lossy().to_string(),
+        dry_run: false,
+        ..Default::default()
+    };
+
+    run_translation_wrapper(config.clone()).unwrap();
+    run_translation_wrapper(config).unwrap();
+
+    let plain = fs::read_to_string(&plain_file).unwrap();
+    assert!(plain.starts_with(r#"import React from "react";
+import { useTranslation } from "i18nexus";
+
+"#), "{}", plain);
+    assert_eq!(plain.matches("import { useTranslation }").count(), 1, "{}", plain);
+    assert_eq!(plain.matches("useTranslation()").count(), 1, "{}", plain);
+
+    let aliased = fs::read_to_string(&aliased_file).unwrap();
+    assert!(aliased.contains("const { t } = useT();"), "{}", aliased);
+    assert!(!aliased.contains("useTranslation()"), "{}", aliased);
+    assert_eq!(aliased.matches("import ").count(), 1, "{}", aliased);
+}
+
+#[test]
+fn e2e_다른_라이브러리의_useTranslation과_겹치면_별칭으로_가져와야_함() {
+    let temp_dir = TempDir::new().unwrap();
+    let test_file = temp_dir.path().join("Legacy.tsx");
+    fs::write(&test_file, r#"import { useTranslation } from "react-i18next";
+function Legacy() {
+  return <div>안녕하세요</div>;
+}"#).unwrap();
+
+    let config = ScriptConfig {
+        source_pattern: temp_dir.path().join("**/*.tsx").to_string_lossy().to_string(),
+        dry_run: false,
+        ..Default::default()
+    };
+    run_translation_wrapper(config).unwrap();
+
+    let content = fs::read_to_string(&test_file).unwrap();
+    assert!(content.contains(r#"import { useTranslation } from "react-i18next";"#), "{}", content);
+    assert!(content.contains(r#"import { useTranslation as _useTranslation } from "i18nexus";"#), "{}", content);
+    assert!(content.contains("const { t } = _useTranslation();"), "{}", content);
+}
+
+#[test]
+fn e2e_server_모드에서_화살표_함수_컴포넌트는_async_block으로_바꿔야_함() {
+    let temp_dir = TempDir::new().unwrap();
+    let test_file = temp_dir.path().join("page.tsx");
+    let original_content = r#"const Page = () => <main>메인 페이지</main>;
+export default Page;"#;
+    fs::write(&test_file, original_content).unwrap();
+
+    let config = ScriptConfig {
+        source_pattern: temp_dir.path().join("**/*.tsx").to_string_lossy().to_string(),
+        dry_run: false,
+        mode: Some("server".to_string()),
+        ..Default::default()
+    };
+
+    run_translation_wrapper(config.clone()).unwrap();
+    run_translation_wrapper(config).unwrap();
+
+    let modified = fs::read_to_string(&test_file).unwrap();
+    assert!(modified.contains(r#"const Page = async ()=>{
+    const { t } = await getServerTranslation();
+    return <main>{t("메인 페이지")}</main>;
+};"#), "{}", modified);
+    assert_eq!(modified.matches("getServerTranslation").count(), 2, "{}", modified);
+}
+
+#[test]
+fn e2e_use_client는_shebang_뒤에_추가하고_기존_디렉티브는_유지해야_함() {
+    let temp_dir = TempDir::new().unwrap();
+    let shebang_file = temp_dir.path().join("Shebang.tsx");
+    let existing_file = temp_dir.path().join("Existing.tsx");
+    let server_file = temp_dir.path().join("Action.tsx");
+    fs::write(&shebang_file, r#"#!/usr/bin/env node
+import React from "react";
+function Shebang() {
+  return <div>안녕하세요</div>;
+}"#).unwrap();
+    fs::write(&existing_file, r#"'use client';
+function Existing() {
+  return <div>안녕하세요</div>;
+}"#).unwrap();
+    fs::write(&server_file, r#""use server";
+function useAction() {
+  return "완료";
+}"#).unwrap();
+
+    let config = ScriptConfig {
+        source_pattern: temp_dir.path().join("**/*.tsx").to_string_lossy().to_string(),
+        dry_run: false,
+        mode: Some("client".to_string()),
+        framework: Some("nextjs".to_string()),
+        ..Default::default()
+    };
+
+    run_translation_wrapper(config).unwrap();
+
+    let shebang = fs::read_to_string(&shebang_file).unwrap();
+    assert!(shebang.starts_with(r#"#!/usr/bin/env node
+"use client";
+"#), "{}", shebang);
+
+    let existing = fs::read_to_string(&existing_file).unwrap();
+    assert_eq!(existing.matches("use client").count(), 1, "{}", existing);
+    assert!(existing.starts_with("'use client';"), "{}", existing);
+
+    let server = fs::read_to_string(&server_file).unwrap();
+    assert!(!server.contains("use client"), "{}", server);
+    assert!(server.starts_with(r#""use server";"#), "{}", server);
+}
+
+#[test]
+fn e2e_mode가_없으면_파일별로_client_server를_선택해야_함() {
+    let temp_dir = TempDir::new().unwrap();
+    let page_file = temp_dir.path().join("page.tsx");
+    let counter_file = temp_dir.path().join("Counter.tsx");
+    fs::write(&page_file, r#"export default async function Page() {
+  return <main>메인</main>;
+}"#).unwrap();
+    fs::write(&counter_file, r#"export function Counter() {
+  const [count, setCount] = useState(0);
+  return <button>증가</button>;
+}"#).unwrap();
+
+    let config = ScriptConfig {
+        source_pattern: temp_dir.path().join("**/*.tsx").to_string_lossy().to_string(),
+        dry_run: false,
+        framework: Some("nextjs".to_string()),
+        ..Default::default()
+    };
+
+    run_translation_wrapper(config).unwrap();
+
+    let page = fs::read_to_string(&page_file).unwrap();
+    assert!(page.contains("const { t } = await getServerTranslation();"), "{}", page);
+    assert!(!page.contains("use client"), "{}", page);
+
+    let counter = fs::read_to_string(&counter_file).unwrap();
+    assert!(counter.starts_with(r#""use client";"#), "{}", counter);
+    assert!(counter.contains("const { t } = useTranslation();"), "{}", counter);
+}
+
+#[test]
+fn e2e_클라이언트_모듈이_import한_공유_컴포넌트는_useTranslation을_사용해야_함() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    fs::create_dir_all(root.join("src/app")).unwrap();
+    fs::create_dir_all(root.join("src/components")).unwrap();
+    fs::write(root.join("tsconfig.json"), r#"{ "compilerOptions": { "paths": { "@/*": ["./src/*"] } } }"#).unwrap();
+    fs::write(root.join("src/app/page.tsx"), r#"import Shell from "@/components/Shell";
+import Title from "@/components/Title";
+export default function Page() {
+  return <Shell><Title /></Shell>;
+}"#).unwrap();
+    fs::write(root.join("src/components/Shell.tsx"), r#""use client";
+import Shared from "./Shared";
+export default function Shell({ children }) {
+  return <div><Shared />{children}</div>;
+}"#).unwrap();
+    fs::write(root.join("src/components/Shared.tsx"), r#"export default function Shared() {
+  return <p>공유 컴포넌트</p>;
+}"#).unwrap();
+    fs::write(root.join("src/components/Title.tsx"), r#"export default function Title() {
+  return <h1>제목</h1>;
+}"#).unwrap();
+
+    let config = ScriptConfig {
+        source_pattern: root.join("src/**/*.tsx").to_string_lossy().to_string(),
+        dry_run: false,
+        framework: Some("nextjs".to_string()),
+        ..Default::default()
+    };
+
+    run_translation_wrapper(config).unwrap();
+
+    let shared = fs::read_to_string(root.join("src/components/Shared.tsx")).unwrap();
+    assert!(shared.starts_with(r#""use client";"#), "{}", shared);
+    assert!(shared.contains("const { t } = useTranslation();"), "{}", shared);
+
+    let title = fs::read_to_string(root.join("src/components/Title.tsx")).unwrap();
+    assert!(title.contains("async function Title()"), "{}", title);
+    assert!(title.contains("const { t } = await getServerTranslation();"), "{}", title);
+}
+
+#[test]
+fn e2e_중괄호_패턴으로_여러_확장자를_처리하고_exclude는_건너뛰어야_함() {
+    let temp_dir = TempDir::new().unwrap();
+    let component_file = temp_dir.path().join("src/Component.tsx");
+    let util_file = temp_dir.path().join("src/useLabel.ts");
+    let test_file = temp_dir.path().join("src/Component.test.tsx");
+    let vendor_file = temp_dir.path().join("src/node_modules/pkg/Vendor.tsx");
+    fs::create_dir_all(vendor_file.parent().unwrap()).unwrap();
+    let component = r#"function Component() {
+  return <div>안녕하세요</div>;
+}"#;
+    fs::write(&component_file, component).unwrap();
+    fs::write(&util_file, r#"export function useLabel() {
+  return "라벨";
+}"#).unwrap();
+    fs::write(&test_file, component).unwrap();
+    fs::write(&vendor_file, component).unwrap();
+
+    let config = ScriptConfig {
+        source_pattern: temp_dir.path().join("src/**/*.{js,jsx,ts,tsx}").to_string_lossy().to_string(),
+        exclude_patterns: vec!["**/*.test.tsx".to_string()],
+        dry_run: false,
+        ..Default::default()
+    };
+
+    run_translation_wrapper(config).unwrap();
+
+    assert!(fs::read_to_string(&component_file).unwrap().contains(r#"{t("안녕하세요")}"#));
+    assert!(fs::read_to_string(&util_file).unwrap().contains(r#"t("라벨")"#));
+    assert_eq!(fs::read_to_string(&test_file).unwrap(), component);
+    assert_eq!(fs::read_to_string(&vendor_file).unwrap(), component);
+}
+
+#[test]
+fn e2e_확장자별_문법으로_ts_타입_단언과_jsx_파일을_처리해야_함() {
+    let temp_dir = TempDir::new().unwrap();
+    let hook_file = temp_dir.path().join("useTitle.ts");
+    let component_file = temp_dir.path().join("Legacy.jsx");
+    fs::write(&hook_file, r#"export function useTitle(value) {
+  const count = <number>value;
+  return "제목";
+}"#).unwrap();
+    fs::write(&component_file, r#"export function Legacy() {
+  return <p>레거시</p>;
+}"#).unwrap();
+
+    let config = ScriptConfig {
+        source_pattern: temp_dir.path().join("*.{ts,jsx}").to_string_lossy().to_string(),
+        dry_run: false,
+        ..Default::default()
+    };
+
+    run_translation_wrapper(config).unwrap();
+
+    let hook = fs::read_to_string(&hook_file).unwrap();
+    assert!(hook.contains(r#"t("제목")"#), "{}", hook);
+    assert!(hook.contains("<number>value"), "{}", hook);
+    let component = fs::read_to_string(&component_file).unwrap();
+    assert!(component.contains(r#"{t("레거시")}"#), "{}", component);
+}
+
+#[test]
+fn e2e_CommonJS_파일은_require로_useTranslation을_가져와야_함() {
+    let temp_dir = TempDir::new().unwrap();
+    let component_file = temp_dir.path().join("Legacy.js");
+    let script_file = temp_dir.path().join("legacy.cjs");
+    fs::write(&component_file, r#""use strict";
+const React = require("react");
+
+function Legacy() {
+  return <p>레거시</p>;
+}
+
+module.exports = Legacy;"#).unwrap();
+    // 최상위 return은 스크립트에서만 허용됨
+    fs::write(&script_file, r#"const path = require("path");
+if (!path) return;
+function useMenu() {
+  return "메뉴";
+}
+module.exports = { useMenu };"#).unwrap();
+
+    let config = ScriptConfig {
+        source_pattern: temp_dir.path().join("*.{js,cjs}").to_string_lossy().to_string(),
+        dry_run: false,
+        ..Default::default()
+    };
+
+    run_translation_wrapper(config).unwrap();
+
+    let component = fs::read_to_string(&component_file).unwrap();
+    assert!(component.starts_with(r#""use strict";
+const { useTranslation } = require("i18nexus");"#), "{}", component);
+    assert!(!component.contains("import "), "{}", component);
+    assert!(component.contains(r#"{t("레거시")}"#), "{}", component);
+
+    let script = fs::read_to_string(&script_file).unwrap();
+    assert!(script.contains(r#"const { useTranslation } = require("i18nexus");"#), "{}", script);
+    assert!(script.contains(r#"t("메뉴")"#), "{}", script);
+}
+
+#[test]
+fn e2e_변환된_파일에서_주석을_유지해야_함() {
+    let temp_dir = TempDir::new().unwrap();
+    let test_file = temp_dir.path().join("Component.tsx");
+    fs::write(&test_file, r#"// eslint-disable-next-line react/display-name
+/** 인사 컴포넌트 */
+export function Greeting() {
+  // i18n-ignore
+  const raw = "무시";
+  return (
+    <div>
+      {/* i18n-ignore */}
+      <span>그대로</span>
+      <p>안녕하세요</p>
+    </div>
+  );
+}"#).unwrap();
+
+    let config = ScriptConfig {
+        source_pattern: temp_dir.path().join("**/*.tsx").to_string_lossy().to_string(),
+        dry_run: false,
+        ..Default::default()
+    };
+
+    run_translation_wrapper(config).unwrap();
+
+    let content = fs::read_to_string(&test_file).unwrap();
+    assert!(content.contains("// eslint-disable-next-line react/display-name\n/** 인사 컴포넌트 */\nexport function Greeting()"), "{}", content);
+    assert!(content.contains("// i18n-ignore\n    const raw = \"무시\";"), "{}", content);
+    assert!(content.contains("/* i18n-ignore */"), "{}", content);
+    assert!(content.contains("<span>그대로</span>"), "{}", content);
+    assert!(content.contains(r#"<p>{t("안녕하세요")}</p>"#), "{}", content);
+
+    // 다시 실행해도 i18n-ignore가 남아 있으므로 무시 대상은 그대로
+    let config = ScriptConfig {
+        source_pattern: temp_dir.path().join("**/*.tsx").to_string_lossy().to_string(),
+        dry_run: false,
+        ..Default::default()
+    };
+    run_translation_wrapper(config).unwrap();
+    let rerun = fs::read_to_string(&test_file).unwrap();
+    assert!(rerun.contains("const raw = \"무시\";"), "{}", rerun);
+    assert!(rerun.contains("<span>그대로</span>"), "{}", rerun);
+}
+
+#[test]
+fn e2e_파일_헤더_주석은_출력의_첫_줄로_남아야_함() {
+    let temp_dir = TempDir::new().unwrap();
+    let with_import = temp_dir.path().join("WithImport.tsx");
+    let without_import = temp_dir.path().join("WithoutImport.tsx");
+    fs::write(&with_import, r#"/** License: MIT */
+// Copyright 2024 i18nexus
+import React from "react";
+
+export function Greeting() {
+  return <p>안녕하세요</p>;
+}"#).unwrap();
+    fs::write(&without_import, r#"/** License: MIT */
+// Copyright 2024 i18nexus
+
+/** 인사 컴포넌트 */
+export function Greeting() {
+  return <p>안녕하세요</p>;
+}"#).unwrap();
+
+    let config = ScriptConfig {
+        source_pattern: temp_dir.path().join("**/*.tsx").to_string_lossy().to_string(),
+        dry_run: false,
+        ..Default::default()
+    };
+
+    run_translation_wrapper(config).unwrap();
+
+    let content = fs::read_to_string(&with_import).unwrap();
+    assert!(content.starts_with(r#"/** License: MIT */
+// Copyright 2024 i18nexus
+import React from "react";
+import { useTranslation } from "i18nexus";
+
+export function Greeting()"#), "{}", content);
+
+    let content = fs::read_to_string(&without_import).unwrap();
+    assert!(content.starts_with(r#"/** License: MIT */
+// Copyright 2024 i18nexus
+
+import { useTranslation } from "i18nexus";
+
+/** 인사 컴포넌트 */
+export function Greeting()"#), "{}", content);
+}
+
+#[test]
+fn e2e_minimal_diff_server_모드에서_첫_구문이_컴포넌트여도_import를_async보다_앞에_넣어야_함() {
+    let temp_dir = TempDir::new().unwrap();
+    let test_file = temp_dir.path().join("old.js");
+    fs::write(&test_file, "function Title() {\n  return \"제목\";\n}\n").unwrap();
+
+    let config = ScriptConfig {
+        source_pattern: temp_dir.path().join("**/*.js").to_string_lossy().to_string(),
+        mode: Some("server".to_string()),
+        minimal_diff: true,
+        ..Default::default()
+    };
+    run_translation_wrapper(config).unwrap();
+
+    let content = fs::read_to_string(&test_file).unwrap();
+    assert_eq!(content, r#"import { getServerTranslation } from "i18nexus";
+async function Title() {
+  const { t } = await getServerTranslation();
+  return t("제목");
+}
+"#);
+}
+
+#[test]
+fn e2e_minimal_diff는_변환하지_않은_코드를_바이트_단위로_유지해야_함() {
+    let temp_dir = TempDir::new().unwrap();
+    let test_file = temp_dir.path().join("Component.tsx");
+    fs::write(&test_file, r#"/* 라이선스 */
+import React from 'react'
+
+const  LABEL = 'label' // 정렬용 공백 유지
+
+export default function Greeting({ name }) {
+    const title = '제목'
+    return (
+        <div className='box'>
+            <h1 title={`${name}님 환영합니다`}>{title}</h1>
+            <p>
+                안녕하세요
+            </p>
+        </div>
+    )
+}
+
+export const Badge = () => <span>새 글</span>
+"#).unwrap();
+
+    let config = ScriptConfig {
+        source_pattern: temp_dir.path().join("**/*.tsx").to_string_lossy().to_string(),
+        framework: Some("nextjs".to_string()),
+        mode: Some("client".to_string()),
+        minimal_diff: true,
+        ..Default::default()
+    };
+
+    run_translation_wrapper(config).unwrap();
+
+    let content = fs::read_to_string(&test_file).unwrap();
+    assert_eq!(content, r#"/* 라이선스 */
+"use client";
+import React from 'react'
+import { useTranslation } from "i18nexus";
+
+const  LABEL = 'label' // 정렬용 공백 유지
+
+export default function Greeting({ name }) {
+    const { t } = useTranslation();
+    const title = t("제목")
+    return (
+        <div className='box'>
+            <h1 title={t("{{name}}님 환영합니다", {
+                name
+            })}>{title}</h1>
+            <p>
+                {t("안녕하세요")}
+            </p>
+        </div>
+    )
+}
+
+export const Badge = () => {
+  const { t } = useTranslation();
+  return <span>{t("새 글")}</span>;
+}
+"#);
+}
+
+#[test]
+fn e2e_minimal_diff_server_모드에서_async와_바인딩만_삽입해야_함() {
+    let temp_dir = TempDir::new().unwrap();
+    let test_file = temp_dir.path().join("page.tsx");
+    fs::write(&test_file, r#"import { getServerTranslation } from 'i18nexus/server'
+
+export default function Page() {
+	return <main className='page'>서버 페이지</main>
+}
+"#).unwrap();
+
+    let config = ScriptConfig {
+        source_pattern: temp_dir.path().join("**/*.tsx").to_string_lossy().to_string(),
+        translation_import_source: "i18nexus/server".to_string(),
+        mode: Some("server".to_string()),
+        minimal_diff: true,
+        ..Default::default()
+    };
+
+    run_translation_wrapper(config).unwrap();
+
+    let content = fs::read_to_string(&test_file).unwrap();
+    assert_eq!(content, r#"import { getServerTranslation } from 'i18nexus/server'
+
+export default async function Page() {
+	const { t } = await getServerTranslation();
+	return <main className='page'>{t("서버 페이지")}</main>
+}
+"#);
+}
+
+#[test]
+fn e2e_dry_run_patch_out은_파일을_바꾸지_않고_patch를_저장해야_함() {
+    let temp_dir = TempDir::new().unwrap();
+    let test_file = temp_dir.path().join("Component.tsx");
+    let untouched_file = temp_dir.path().join("Plain.tsx");
+    let original_content = r#"function Component() {
+  return <div title="제목">안녕하세요</div>;
+}
+"#;
+    fs::write(&test_file, original_content).unwrap();
+    fs::write(&untouched_file, "export const a = 1;\n").unwrap();
+    let patch_file = temp_dir.path().join("i18n.patch");
+
+    let config = ScriptConfig {
+        source_pattern: temp_dir.path().join("**/*.tsx").to_string_lossy().to_string(),
+        dry_run: true,
+        patch_out: Some(patch_file.to_string_lossy().to_string()),
+        minimal_diff: true,
+        ..Default::default()
+    };
+
+    run_translation_wrapper(config).unwrap();
+
+    assert_eq!(fs::read_to_string(&test_file).unwrap(), original_content);
+    let patch = fs::read_to_string(&patch_file).unwrap();
+    assert!(patch.contains("Component.tsx\n+++ b/"), "{}", patch);
+    assert!(patch.contains("-  return <div title=\"제목\">안녕하세요</div>;\n"), "{}", patch);
+    assert!(patch.contains("+  const { t } = useTranslation();\n"), "{}", patch);
+    assert!(patch.contains("+  return <div title={t(\"제목\")}>{t(\"안녕하세요\")}</div>;\n"), "{}", patch);
+    assert!(!patch.contains("Plain.tsx"), "{}", patch);
+}
+
+#[test]
+fn e2e_매개변수로_t를_받는_컴포넌트에는_useTranslation을_추가하지_않아야_함() {
+    let temp_dir = TempDir::new().unwrap();
+    let test_file = temp_dir.path().join("Component.tsx");
+    // withTranslation HOC가 t를 props로 넘기는 형태
+    fs::write(
+        &test_file,
+        r#"function Greeting({ t }) {
+  return <div>안녕하세요</div>;
+}
+
+const Farewell = ({ t = (key) => key, name }) => <p>안녕히 가세요</p>;
+"#,
+    )
+    .unwrap();
+
+    let config = ScriptConfig {
+        source_pattern: temp_dir.path().join("**/*.tsx").to_string_lossy().to_string(),
+        mode: Some("client".to_string()),
+        ..Default::default()
+    };
+
+    run_translation_wrapper(config).unwrap();
+
+    let modified_content = fs::read_to_string(&test_file).unwrap();
+    assert!(modified_content.contains(r#"<div>{t("안녕하세요")}</div>"#), "{}", modified_content);
+    assert!(modified_content.contains(r#"<p>{t("안녕히 가세요")}</p>"#), "{}", modified_content);
+    assert!(!modified_content.contains("useTranslation"), "{}", modified_content);
+    // t가 두 번 선언되지 않아 다시 파싱할 수 있어야 함
+    t_wrapper_rust::parse_file(&modified_content, t_wrapper_rust::ParseOptions::default()).unwrap();
+}
+
+#[test]
+fn e2e_require가_없는_cjs_파일도_require로_useTranslation을_가져와야_함() {
+    let temp_dir = TempDir::new().unwrap();
+    let script_file = temp_dir.path().join("menu.cjs");
+    fs::write(&script_file, r#"#!/usr/bin/env node
+"use strict";
+if (module.parent == null) return;
+function useMenu() {
+  return "메뉴";
+}
+module.exports = { useMenu };"#).unwrap();
+
+    let config = ScriptConfig {
+        source_pattern: temp_dir.path().join("*.cjs").to_string_lossy().to_string(),
+        mode: Some("client".to_string()),
+        ..Default::default()
+    };
+
+    run_translation_wrapper(config).unwrap();
+
+    let script = fs::read_to_string(&script_file).unwrap();
+    assert!(script.starts_with(r#"#!/usr/bin/env node
+"use strict";
+const { useTranslation } = require("i18nexus");"#), "{}", script);
+    assert!(!script.contains("import "), "{}", script);
+    assert!(script.contains(r#"t("메뉴")"#), "{}", script);
+    // 최상위 return이 있으므로 스크립트로만 다시 파싱할 수 있어야 함
+    let options = t_wrapper_rust::ParseOptions::for_path(&script_file);
+    t_wrapper_rust::parse_file(&script, options).unwrap();
+}
@@ -0,0 +1,190 @@
+/*!
+ * import-graph 테스트
+ * import 경로 해석과 클라이언트 경계 전파 테스트
+ */
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use t_wrapper_rust::classifier::{ModeClassification, ModeReason, TranslationMode};
+use t_wrapper_rust::import_graph::{module_specifiers, normalize_path, resolve_import, ImportGraph, TsConfigChain, TsConfigPaths};
+use t_wrapper_rust::parser::{parse_file, ParseOptions};
+use tempfile::tempdir;
+
+fn file_set(paths: &[&str]) -> HashSet<PathBuf> {
+    paths.iter().map(PathBuf::from).collect()
+}
+
+#[test]
+fn normalize_path_점과_상위_디렉토리를_정리해야_함() {
+    assert_eq!(normalize_path(Path::new("src/app/../components/./Button")), PathBuf::from("src/components/Button"));
+    assert_eq!(normalize_path(Path::new("/root/src/./a.tsx")), PathBuf::from("/root/src/a.tsx"));
+}
+
+#[test]
+fn module_specifiers_import_export_동적_import를_수집해야_함() {
+    let module = parse_file(
+        r#"import a from "./a";
+import type { B } from "./b";
+export * from "./c";
+export { d } from "./d";
+const e = require("./e");
+const F = lazy(() => import("./f"));"#,
+        ParseOptions::default(),
+    )
+    .unwrap();
+
+    assert_eq!(module_specifiers(&module), vec!["./a", "./c", "./d", "./e", "./f"]);
+}
+
+#[test]
+fn resolve_import_상대_경로와_확장자_index를_해석해야_함() {
+    let files = file_set(&["src/app/page.tsx", "src/components/Button.tsx", "src/ui/index.ts"]);
+    let importer = Path::new("src/app/page.tsx");
+
+    assert_eq!(
+        resolve_import(importer, "../components/Button", &files, None),
+        Some(PathBuf::from("src/components/Button.tsx"))
+    );
+    assert_eq!(
+        resolve_import(importer, "../ui", &files, None),
+        Some(PathBuf::from("src/ui/index.ts"))
+    );
+    assert_eq!(resolve_import(importer, "react", &files, None), None);
+    assert_eq!(resolve_import(importer, "./missing", &files, None), None);
+}
+
+#[test]
+fn ts_config_paths_주석이_있는_tsconfig의_paths_별칭을_해석해야_함() {
+    let dir = tempdir().unwrap();
+    let tsconfig_path = dir.path().join("tsconfig.json");
+    fs::write(&tsconfig_path, r#"{
+  // Next.js 기본 설정
+  "compilerOptions": {
+    "baseUrl": ".",
+    /* 별칭 */
+    "paths": {
+      "@/*": ["./src/*"],
+      "~ui": ["./src/ui/index.ts"],
+    },
+  },
+}"#).unwrap();
+
+    let tsconfig = TsConfigPaths::load(&tsconfig_path).unwrap();
+    assert_eq!(TsConfigPaths::find(&dir.path().join("src/app")), Some(tsconfig_path));
+
+    let button = dir.path().join("src/components/Button.tsx");
+    let ui = dir.path().join("src/ui/index.ts");
+    let files: HashSet<PathBuf> = [button.clone(), ui.clone()].into_iter().collect();
+    let importer = dir.path().join("src/app/page.tsx");
+
+    assert_eq!(resolve_import(&importer, "@/components/Button", &files, Some(&tsconfig)), Some(button));
+    assert_eq!(resolve_import(&importer, "~ui", &files, Some(&tsconfig)), Some(ui));
+    assert_eq!(resolve_import(&importer, "@tanstack/react-query", &files, Some(&tsconfig)), None);
+}
+
+#[test]
+fn ts_config_paths_extends로_이어진_설정을_자식_우선으로_합쳐야_함() {
+    let dir = tempdir().unwrap();
+    let write = |path: &str, content: &str| {
+        let path = dir.path().join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    };
+    // 패키지 설정의 paths는 패키지 위치 기준, 상위 설정의 baseUrl은 그 설정 위치 기준
+    write("node_modules/@acme/tsconfig/tsconfig.json", r#"{ "compilerOptions": { "paths": { "~ui": ["./ui/index.ts"] } } }"#);
+    write("configs/tsconfig.base.json", r#"{
+  "extends": "@acme/tsconfig",
+  "compilerOptions": { "baseUrl": "..", "paths": { "@/*": ["./lib/*"] } }
+}"#);
+    write("app/tsconfig.json", r#"{
+  // 자식의 paths가 상위 paths를 대체
+  "extends": ["./missing", "../configs/tsconfig.base"],
+  "compilerOptions": { "paths": { "@/*": ["./src/*"] } }
+}"#);
+
+    let tsconfig = TsConfigPaths::load(&dir.path().join("app/tsconfig.json")).unwrap();
+    assert_eq!(
+        tsconfig.resolve_alias("@/components/Button"),
+        vec![normalize_path(&dir.path().join("src/components/Button"))]
+    );
+    assert!(tsconfig.resolve_alias("~ui").is_empty());
+
+    let chain = TsConfigChain::load(&dir.path().join("configs/tsconfig.base.json")).unwrap();
+    let (config_dir, _) = chain.compiler_option("baseUrl").unwrap();
+    assert_eq!(config_dir, normalize_path(&dir.path().join("configs")));
+
+    let package = TsConfigPaths::load(&dir.path().join("node_modules/@acme/tsconfig/tsconfig.json")).unwrap();
+    assert_eq!(
+        package.resolve_alias("~ui"),
+        vec![normalize_path(&dir.path().join("node_modules/@acme/tsconfig/ui/index.ts"))]
+    );
+
+    // 순환 참조는 에러
+    write("loop/a.json", r#"{ "extends": "./b.json" }"#);
+    write("loop/b.json", r#"{ "extends": "./a" }"#);
+    assert!(TsConfigChain::load(&dir.path().join("loop/a.json")).is_err());
+}
+
+#[test]
+fn add_module_같은_파일을_여러_번_import해도_간선은_하나여야_함() {
+    let files = file_set(&["app/page.tsx", "app/Button.tsx", "app/Card.tsx"]);
+    let module = parse_file(
+        r#"import Button from "./Button";
+import Card from "./Card";
+export { ButtonProps } from "./Button";"#,
+        ParseOptions::default(),
+    )
+    .unwrap();
+    let mut graph = ImportGraph::new();
+    graph.add_module(Path::new("app/page.tsx"), &module, &files, None);
+    assert_eq!(
+        graph.imports_of(Path::new("app/page.tsx")),
+        [PathBuf::from("app/Button.tsx"), PathBuf::from("app/Card.tsx")]
+    );
+}
+
+#[test]
+fn propagate_client_boundaries_클라이언트_모듈이_import한_파일만_클라이언트가_되어야_함() {
+    let sources = [
+        ("app/page.tsx", r#"import Shell from "./Shell"; import Card from "./Card";"#),
+        ("app/Shell.tsx", r#""use client"; import Shared from "./Shared"; import { save } from "./actions";"#),
+        ("app/Shared.tsx", r#"import Leaf from "./Leaf";"#),
+        ("app/Leaf.tsx", ""),
+        ("app/Card.tsx", ""),
+        ("app/actions.ts", r#""use server"; import Util from "./Util";"#),
+        ("app/Util.ts", ""),
+    ];
+    let files = file_set(&sources.map(|(path, _)| path));
+    let mut graph = ImportGraph::new();
+    let mut classifications = HashMap::new();
+    for (path, code) in sources {
+        let module = parse_file(code, ParseOptions::default()).unwrap();
+        graph.add_module(Path::new(path), &module, &files, None);
+        let classification = match code {
+            code if code.starts_with("\"use client\"") => {
+                ModeClassification::new(TranslationMode::Client, ModeReason::UseClientDirective)
+            }
+            code if code.starts_with("\"use server\"") => {
+                ModeClassification::new(TranslationMode::Server, ModeReason::UseServerDirective)
+            }
+            _ => ModeClassification::new(TranslationMode::Server, ModeReason::NoSignal),
+        };
+        classifications.insert(PathBuf::from(path), classification);
+    }
+
+    graph.propagate_client_boundaries(&mut classifications);
+
+    let mode_of = |path: &str| classifications[&PathBuf::from(path)].mode;
+    assert_eq!(mode_of("app/page.tsx"), TranslationMode::Server);
+    assert_eq!(mode_of("app/Card.tsx"), TranslationMode::Server);
+    assert_eq!(mode_of("app/Shell.tsx"), TranslationMode::Client);
+    assert_eq!(mode_of("app/Shared.tsx"), TranslationMode::Client);
+    assert_eq!(mode_of("app/Leaf.tsx"), TranslationMode::Client);
+    assert_eq!(mode_of("app/actions.ts"), TranslationMode::Server);
+    assert_eq!(mode_of("app/Util.ts"), TranslationMode::Server);
+    assert_eq!(
+        classifications[&PathBuf::from("app/Leaf.tsx")].reason,
+        ModeReason::ImportedByClient("app/Shared.tsx".to_string())
+    );
+}
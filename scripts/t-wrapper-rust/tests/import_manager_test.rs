@@ -1,21 +1,35 @@
-/**
+/*!
  * import-manager 테스트
  * Import 관리 로직 테스트
  */
 
-use t_wrapper_rust::import_manager::{create_use_translation_hook, add_import_if_needed};
+#![allow(non_snake_case)]
+
+use t_wrapper_rust::import_manager::{
+    create_use_translation_hook, add_import_if_needed, add_server_translation_import,
+    find_import_local_name, is_commonjs_module, resolve_import_local_name,
+};
+use swc_ecma_ast::{Module, ModuleDecl, ModuleItem};
 use t_wrapper_rust::parser::{parse_file, generate_code, ParseOptions};
+use t_wrapper_rust::session::Session;
+
+fn import_count(ast: &Module) -> usize {
+    ast.body
+        .iter()
+        .filter(|item| matches!(item, ModuleItem::ModuleDecl(ModuleDecl::Import(_))))
+        .count()
+}
 
 #[test]
 fn add_import_if_needed_import가_없으면_추가해야_함() {
     let code = r#"function Component() {}"#;
     
-    // TODO: SWC AST로 구현되면 실제 AST 노드로 테스트
     let mut ast = parse_file(code, ParseOptions::default()).unwrap();
     let result = add_import_if_needed(&mut ast, "next-i18next");
     assert!(result);
-    // TODO: AST에 ImportDeclaration이 추가되었는지 확인
-    // assert!(ast.body[0].is_import_declaration());
+    assert!(matches!(ast.body[0], ModuleItem::ModuleDecl(ModuleDecl::Import(_))));
+    let output = generate_code(&ast).unwrap();
+    assert!(output.contains(r#"import { useTranslation } from "next-i18next";"#), "{}", output);
 }
 
 #[test]
@@ -23,15 +37,10 @@ fn add_import_if_needed_import가_이미_있으면_추가하지_않아야_함()
     let code = r#"import { useTranslation } from "next-i18next";
 function Component() {}"#;
     
-    // TODO: SWC AST로 구현되면 실제 AST 노드로 테스트
     let mut ast = parse_file(code, ParseOptions::default()).unwrap();
     let result = add_import_if_needed(&mut ast, "next-i18next");
-    // TODO: 실제 구현 후 false로 변경
-    // assert!(!result);
-    // TODO: ImportDeclaration이 1개만 있는지 확인
-    // let imports: Vec<_> = ast.body.iter().filter(|node| node.is_import_declaration()).collect();
-    // assert_eq!(imports.len(), 1);
-    let _ = result;
+    assert!(!result);
+    assert_eq!(import_count(&ast), 1);
 }
 
 #[test]
@@ -39,17 +48,201 @@ fn add_import_if_needed_같은_소스의_import가_있지만_useTranslation이_
     let code = r#"import { other } from "next-i18next";
 function Component() {}"#;
     
-    // TODO: SWC AST로 구현되면 실제 AST 노드로 테스트
     let mut ast = parse_file(code, ParseOptions::default()).unwrap();
     let result = add_import_if_needed(&mut ast, "next-i18next");
-    // TODO: 실제 구현 후 false로 변경 (이미 import가 있으므로)
-    // assert!(!result);
-    // TODO: ImportDeclaration에 useTranslation이 추가되었는지 확인
-    // let import_node = ast.body.iter().find(|node| node.is_import_declaration());
-    // assert!(import_node.is_some());
-    // let has_use_translation = import_node.specifiers.iter().any(|spec| spec.imported.name == "useTranslation");
-    // assert!(has_use_translation);
-    let _ = result;
+    assert!(result);
+    assert_eq!(import_count(&ast), 1);
+    let output = generate_code(&ast).unwrap();
+    assert!(output.contains(r#"import { other, useTranslation } from "next-i18next";"#), "{}", output);
+}
+
+#[test]
+fn add_import_if_needed_디렉티브_뒤에_추가해야_함() {
+    let code = r#""use client";
+import React from "react";
+function Component() {}"#;
+
+    let mut ast = parse_file(code, ParseOptions::default()).unwrap();
+    assert!(add_import_if_needed(&mut ast, "i18nexus"));
+    let output = generate_code(&ast).unwrap();
+    assert!(output.starts_with(r#""use client";
+import React from "react";
+import { useTranslation } from "i18nexus";
+
+function Component"#), "{}", output);
+
+    let code = r#""use client";
+function Component() {}"#;
+    let mut ast = parse_file(code, ParseOptions::default()).unwrap();
+    assert!(add_import_if_needed(&mut ast, "i18nexus"));
+    let output = generate_code(&ast).unwrap();
+    assert!(output.starts_with(r#""use client";
+import { useTranslation } from "i18nexus";"#), "{}", output);
+}
+
+#[test]
+fn add_import_if_needed_파일_헤더_주석_아래에_추가해야_함() {
+    let session = Session::new();
+    let code = r#"/** License: MIT */
+// Copyright 2024 i18nexus
+import React from "react";
+
+function Component() {}"#;
+    let (mut ast, _) = session.parse_file(code, &ParseOptions::default()).unwrap();
+    assert!(add_import_if_needed(&mut ast, "i18nexus"));
+    let output = session.generate_code(&ast).unwrap();
+    assert!(output.starts_with(r#"/** License: MIT */
+// Copyright 2024 i18nexus
+import React from "react";
+import { useTranslation } from "i18nexus";
+
+function Component"#), "{}", output);
+
+    // import가 없으면 빈 줄로 떨어진 헤더만 맨 위에 남고, 구문에 붙은 주석은 구문과 함께 출력
+    let code = r#"/** License: MIT */
+
+/** 컴포넌트 설명 */
+function Component() {}"#;
+    let (mut ast, _) = session.parse_file(code, &ParseOptions::default()).unwrap();
+    assert!(add_import_if_needed(&mut ast, "i18nexus"));
+    let output = session.generate_code(&ast).unwrap();
+    assert!(output.starts_with(r#"/** License: MIT */
+
+import { useTranslation } from "i18nexus";
+
+/** 컴포넌트 설명 */
+function Component"#), "{}", output);
+}
+
+#[test]
+fn add_import_if_needed_별칭과_default_import를_존중해야_함() {
+    let code = r#"import { useTranslation as useT } from "i18nexus";"#;
+    let mut ast = parse_file(code, ParseOptions::default()).unwrap();
+    assert!(!add_import_if_needed(&mut ast, "i18nexus"));
+    assert_eq!(
+        find_import_local_name(&ast, "i18nexus", "useTranslation").as_deref(),
+        Some("useT")
+    );
+
+    let code = r#"import i18n from "i18nexus";"#;
+    let mut ast = parse_file(code, ParseOptions::default()).unwrap();
+    assert!(add_import_if_needed(&mut ast, "i18nexus"));
+    let output = generate_code(&ast).unwrap();
+    assert!(output.contains(r#"import i18n, { useTranslation } from "i18nexus";"#), "{}", output);
+
+    let code = r#"import * as i18n from "i18nexus";
+import type { useTranslation as Hook } from "i18nexus";"#;
+    let mut ast = parse_file(code, ParseOptions::default()).unwrap();
+    assert!(add_import_if_needed(&mut ast, "i18nexus"));
+    assert_eq!(import_count(&ast), 3);
+}
+
+#[test]
+fn add_import_if_needed_다른_소스에서_같은_이름을_쓰면_별칭으로_추가해야_함() {
+    let code = r#"import { useTranslation } from "react-i18next";
+const _useTranslation = null;"#;
+    let mut ast = parse_file(code, ParseOptions::default()).unwrap();
+    assert_eq!(resolve_import_local_name(&ast, "i18nexus", "useTranslation"), "_useTranslation2");
+    assert!(add_import_if_needed(&mut ast, "i18nexus"));
+    assert!(!add_import_if_needed(&mut ast, "i18nexus"));
+    assert_eq!(import_count(&ast), 2);
+    assert_eq!(
+        find_import_local_name(&ast, "i18nexus", "useTranslation").as_deref(),
+        Some("_useTranslation2")
+    );
+    let output = generate_code(&ast).unwrap();
+    assert!(
+        output.contains(r#"import { useTranslation as _useTranslation2 } from "i18nexus";"#),
+        "{}",
+        output
+    );
+
+    // CommonJS도 같은 규칙으로 별칭
+    let code = r#"const { useTranslation } = require("react-i18next");"#;
+    let mut ast = parse_file(code, ParseOptions::default()).unwrap();
+    assert!(add_import_if_needed(&mut ast, "i18nexus"));
+    let output = generate_code(&ast).unwrap();
+    assert!(
+        output.contains(r#"const { useTranslation: _useTranslation } = require("i18nexus");"#),
+        "{}",
+        output
+    );
+}
+
+#[test]
+fn add_import_if_needed_반복_실행해도_중복되지_않아야_함() {
+    let mut ast = parse_file("function Component() {}", ParseOptions::default()).unwrap();
+    assert!(add_import_if_needed(&mut ast, "i18nexus"));
+    assert!(!add_import_if_needed(&mut ast, "i18nexus"));
+
+    let output = generate_code(&ast).unwrap();
+    let mut reparsed = parse_file(&output, ParseOptions::default()).unwrap();
+    assert!(!add_import_if_needed(&mut reparsed, "i18nexus"));
+    assert_eq!(import_count(&reparsed), 1);
+}
+
+#[test]
+fn add_server_translation_import_서버_함수를_import해야_함() {
+    let code = r#"import { useTranslation } from "i18nexus";"#;
+    let mut ast = parse_file(code, ParseOptions::default()).unwrap();
+    assert!(add_server_translation_import(&mut ast, "i18nexus", "getServerT"));
+    assert!(!add_server_translation_import(&mut ast, "i18nexus", "getServerT"));
+    let output = generate_code(&ast).unwrap();
+    assert!(output.contains(r#"import { useTranslation, getServerT } from "i18nexus";"#), "{}", output);
+}
+
+#[test]
+fn is_commonjs_module_import_export가_없고_require를_쓰면_true여야_함() {
+    let cjs = parse_file(r#"const React = require("react");"#, ParseOptions::default()).unwrap();
+    assert!(is_commonjs_module(&cjs));
+
+    let esm = parse_file(
+        r#"import React from "react";
+const legacy = require("./legacy");"#,
+        ParseOptions::default(),
+    )
+    .unwrap();
+    assert!(!is_commonjs_module(&esm));
+
+    let plain = parse_file("function Component() {}", ParseOptions::default()).unwrap();
+    assert!(!is_commonjs_module(&plain));
+}
+
+#[test]
+fn add_import_if_needed_CommonJS_파일에는_require로_추가해야_함() {
+    let code = r#""use strict";
+const React = require("react");
+function Component() {}"#;
+    let mut ast = parse_file(code, ParseOptions::default()).unwrap();
+    assert!(add_import_if_needed(&mut ast, "i18nexus"));
+    assert!(!add_import_if_needed(&mut ast, "i18nexus"));
+    assert_eq!(import_count(&ast), 0);
+
+    let output = generate_code(&ast).unwrap();
+    assert!(
+        output.starts_with("\"use strict\";\nconst { useTranslation } = require(\"i18nexus\");"),
+        "{}",
+        output
+    );
+}
+
+#[test]
+fn add_import_if_needed_CommonJS_기존_구조_분해_require에_속성을_추가해야_함() {
+    let code = r#"const { I18nProvider, ...rest } = require("i18nexus");"#;
+    let mut ast = parse_file(code, ParseOptions::default()).unwrap();
+    assert!(add_server_translation_import(&mut ast, "i18nexus", "getServerT"));
+    let output = generate_code(&ast).unwrap();
+    assert!(
+        output.contains(r#"const { I18nProvider, getServerT, ...rest } = require("i18nexus");"#),
+        "{}",
+        output
+    );
+
+    // 별칭으로 이미 꺼낸 경우 별칭 이름을 사용하고 추가하지 않음
+    let code = r#"const { useTranslation: useT } = require("i18nexus");"#;
+    let mut ast = parse_file(code, ParseOptions::default()).unwrap();
+    assert_eq!(find_import_local_name(&ast, "i18nexus", "useTranslation").as_deref(), Some("useT"));
+    assert!(!add_import_if_needed(&mut ast, "i18nexus"));
 }
 
 #[test]
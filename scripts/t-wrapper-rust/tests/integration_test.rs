@@ -1,5 +1,5 @@
-/// 통합 테스트
-/// 전체 워크플로우를 테스트
+//! 통합 테스트
+//! 전체 워크플로우를 테스트
 
 use t_wrapper_rust::*;
 
@@ -14,15 +14,16 @@ fn test_ast_helpers_module() {
 #[test]
 fn test_ast_transformers_module() {
     // ast_transformers 모듈이 제대로 export되는지 확인
-    let result = transform_function_body((), "function Component() { return <div>안녕하세요</div>; }");
+    let code = "function Component() { return <div>안녕하세요</div>; }";
+    let mut module = parse_file(code, ParseOptions::default()).unwrap();
+    let (result, _) = transform_module(&mut module, code.to_string());
     assert!(result.was_modified);
 }
 
 #[test]
 fn test_translation_wrapper_module() {
     // translation_wrapper 모듈이 제대로 export되는지 확인
-    let wrapper = TranslationWrapper::new(None);
     // 기본 생성 테스트
-    assert!(true);
+    let _wrapper = TranslationWrapper::new(None);
 }
 
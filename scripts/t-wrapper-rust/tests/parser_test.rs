@@ -1,3 +1,5 @@
+#![allow(non_snake_case)]
+
 use t_wrapper_rust::parser::*;
 
 #[test]
@@ -35,3 +37,126 @@ export default function Page() {
     assert!(result.is_ok());
 }
 
+#[test]
+fn for_path_확장자별로_문법을_선택해야_함() {
+    use std::path::Path;
+
+    // .ts: JSX가 꺼져 있어야 `<T>` 타입 단언을 파싱할 수 있음
+    let ts = ParseOptions::for_path(Path::new("src/utils.ts"));
+    assert!(parse_file("const size = <number>value;", ts.clone()).is_ok());
+    assert!(parse_file("const el = <div>안녕</div>;", ts).is_err());
+
+    let tsx = ParseOptions::for_path(Path::new("src/App.tsx"));
+    assert!(parse_file("const el = <div>안녕</div>;", tsx).is_ok());
+
+    let jsx = ParseOptions::for_path(Path::new("src/App.jsx"));
+    assert!(!jsx.typescript);
+    assert!(parse_file("const el = <div>안녕</div>;", jsx.clone()).is_ok());
+    assert!(parse_file("const size: number = 1;", jsx).is_err());
+
+    let mjs = ParseOptions::for_path(Path::new("scripts/build.mjs"));
+    assert!(!mjs.typescript && !mjs.jsx);
+    assert!(parse_file("export const a = 1 < 2;", mjs).is_ok());
+}
+
+#[test]
+fn for_path_cjs와_js_스크립트를_파싱해야_함() {
+    use std::path::Path;
+
+    // 모듈(strict mode)로는 파싱할 수 없는 스크립트 코드
+    let script = r#"const config = require("./config");
+with (config) { console.log("설정"); }
+if (!config) return;"#;
+    let module = parse_file(script, ParseOptions::for_path(Path::new("legacy/config.cjs"))).unwrap();
+    assert_eq!(module.body.len(), 3);
+    assert!(parse_file(script, ParseOptions::default()).is_err());
+
+    // .js는 import/export 유무로 판별
+    let sloppy = r#"var data = 010; module.exports = data;"#;
+    assert!(parse_file(sloppy, ParseOptions::for_path(Path::new("legacy/data.js"))).is_ok());
+    let esm = r#"import a from "./a"; export default a;"#;
+    assert!(parse_file(esm, ParseOptions::for_path(Path::new("src/index.js"))).is_ok());
+}
+
+#[test]
+fn parse_file_에러_메시지에_실제_파일_경로와_위치가_포함되어야_함() {
+    use std::path::Path;
+
+    let options = ParseOptions::for_path(Path::new("src/app/page.tsx"));
+    let error = parse_file("const a = 1;\nconst = ;", options).unwrap_err();
+    let message = format!("{:#}", error);
+    assert!(message.contains("src/app/page.tsx:2:"), "{}", message);
+}
+
+#[test]
+fn with_tsconfig_jsx_target_experimentalDecorators를_반영해야_함() -> anyhow::Result<()> {
+    use std::path::Path;
+    use swc_ecma_ast::EsVersion;
+
+    let dir = tempfile::tempdir()?;
+    let tsconfig_path = dir.path().join("tsconfig.json");
+    std::fs::write(
+        &tsconfig_path,
+        r#"{
+  // JSONC
+  "compilerOptions": {
+    "jsx": "preserve",
+    "target": "ES2017",
+    "experimentalDecorators": true,
+  }
+}"#,
+    )?;
+    let compiler_options = TsConfigCompilerOptions::load(&tsconfig_path)?;
+    assert_eq!(compiler_options.jsx.as_deref(), Some("preserve"));
+    assert_eq!(compiler_options.target, Some(EsVersion::Es2017));
+    assert_eq!(compiler_options.experimental_decorators, Some(true));
+
+    let js = ParseOptions::for_path(Path::new("src/legacy.js")).with_tsconfig(&compiler_options);
+    assert!(js.jsx && js.decorators);
+    assert_eq!(js.target, EsVersion::Es2017);
+
+    // jsx 설정이 없어도 .js의 JSX 기본값은 유지
+    let js = ParseOptions::for_path(Path::new("src/legacy.js")).with_tsconfig(&TsConfigCompilerOptions::default());
+    assert!(js.jsx);
+    let ts = ParseOptions::for_path(Path::new("src/legacy.ts")).with_tsconfig(&compiler_options);
+    assert!(!ts.jsx);
+
+    // extends한 설정의 옵션을 물려받고, 자식 설정이 덮어쓴다
+    let child_path = dir.path().join("app/tsconfig.json");
+    std::fs::create_dir_all(child_path.parent().unwrap())?;
+    std::fs::write(&child_path, r#"{ "extends": "../tsconfig.json", "compilerOptions": { "target": "ES2020" } }"#)?;
+    let compiler_options = TsConfigCompilerOptions::load(&child_path)?;
+    assert_eq!(compiler_options.jsx.as_deref(), Some("preserve"));
+    assert_eq!(compiler_options.target, Some(EsVersion::Es2020));
+    Ok(())
+}
+
+#[test]
+fn generate_code_with_comments_같은_SourceMap으로_주석을_유지해야_함() {
+    use swc_common::{sync::Lrc, SourceMap};
+
+    let code = r#"/**
+ * 라이선스
+ */
+// eslint-disable-next-line no-console
+console.log("a"); // 끝 주석
+
+/** JSDoc */
+function Component() {
+  /* 블록 */
+  return null;
+}
+"#;
+    let cm: Lrc<SourceMap> = Default::default();
+    // 앞서 다른 파일을 파싱해 BytePos가 0부터 시작하지 않아도 주석 위치가 맞아야 함
+    parse_file_with_source_map(&cm, "const other = 1; // 다른 파일", ParseOptions::default()).unwrap();
+    let (module, comments) = parse_file_with_source_map(&cm, code, ParseOptions::default()).unwrap();
+    let output = generate_code_with_comments(&module, &cm, &comments).unwrap();
+
+    assert!(output.starts_with("/**\n * 라이선스\n */\n// eslint-disable-next-line no-console\nconsole.log(\"a\"); // 끝 주석\n"), "{}", output);
+    assert!(output.contains("/** JSDoc */\nfunction Component() {\n    /* 블록 */\n    return null;"), "{}", output);
+    assert!(!output.contains("다른 파일"), "{}", output);
+
+    // 주석 없이 출력하는 generate_code는 기존과 같음
+    assert!(!generate_code(&module).unwrap().contains("JSDoc"));
+}
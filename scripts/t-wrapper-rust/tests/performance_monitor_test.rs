@@ -0,0 +1,106 @@
+/*!
+ * performance-monitor 테스트
+ * PerformanceMonitor, PerformanceReporter 테스트
+ */
+
+#![allow(non_snake_case)]
+
+use std::time::Duration;
+use t_wrapper_rust::{PerformanceMonitor, PerformanceNames, PerformanceReporter};
+
+#[test]
+fn start_end_이름_단위로_측정하고_메타데이터를_기록해야_함() {
+    let monitor = PerformanceMonitor::new(true);
+    monitor.start("parse", &[]);
+    let metric = monitor.end("parse", &[("file_path", "a.tsx")]).unwrap();
+
+    assert_eq!(metric.name, "parse");
+    assert_eq!(metric.metadata.get("file_path").map(String::as_str), Some("a.tsx"));
+    assert_eq!(metric.thread, 1);
+    assert_eq!(monitor.get_report().metrics, vec![metric]);
+}
+
+#[test]
+fn end_시작하지_않은_측정이면_None을_반환해야_함() {
+    let monitor = PerformanceMonitor::new(true);
+    assert!(monitor.end("parse", &[]).is_none());
+    assert!(monitor.get_report().metrics.is_empty());
+}
+
+#[test]
+fn 비활성화된_모니터는_아무것도_기록하지_않아야_함() {
+    let monitor = PerformanceMonitor::new(false);
+    monitor.start("parse", &[]);
+    assert!(monitor.end("parse", &[]).is_none());
+    assert_eq!(monitor.measure_sync("read", &[], || 42), 42);
+
+    let report = monitor.get_report();
+    assert!(report.metrics.is_empty());
+    assert_eq!(report.summary.slowest_operation, "N/A");
+}
+
+#[test]
+fn measure_result_에러면_메타데이터에_error를_추가해야_함() {
+    let monitor = PerformanceMonitor::new(true);
+    let ok: Result<i32, String> = monitor.measure_result("read", &[], || Ok(1));
+    let err: Result<i32, String> = monitor.measure_result("read", &[], || Err("fail".to_string()));
+    assert_eq!(ok, Ok(1));
+    assert!(err.is_err());
+
+    let report = monitor.get_report();
+    let errors: Vec<Option<&str>> = report
+        .metrics
+        .iter()
+        .map(|metric| metric.metadata.get("error").map(String::as_str))
+        .collect();
+    assert_eq!(errors, vec![None, Some("true")]);
+    assert_eq!(report.total_for("read").1, 2);
+}
+
+#[test]
+fn get_report_요약과_완료_리포트를_만들어야_함() {
+    let monitor = PerformanceMonitor::new(true);
+    monitor.measure_sync(PerformanceNames::PARSE, &[], || std::thread::sleep(Duration::from_millis(2)));
+    monitor.measure_sync(
+        PerformanceNames::FILE_PROCESSING,
+        &[(PerformanceNames::FILE_PATH, "src/app/Page.tsx")],
+        || (),
+    );
+
+    let report = monitor.get_report();
+    assert_eq!(report.summary.total_operations, 2);
+    assert!(report.summary.slowest_operation.starts_with("parse ("));
+    assert!(report.summary.fastest_operation.starts_with("file_processing ("));
+
+    let completion = PerformanceReporter::render_completion_report(
+        &report,
+        &["src/app/Page.tsx".to_string()],
+        Duration::from_millis(10),
+        "Done",
+    );
+    assert!(completion.contains("✅ Done"));
+    assert!(completion.contains("Files Processed:   1 files"));
+    assert!(completion.contains("parse"));
+    assert!(completion.contains("1. Page.tsx"));
+
+    let verbose = PerformanceReporter::render_report(&report, true);
+    assert!(verbose.contains("📋 Detailed Metrics:"));
+    assert!(verbose.contains("src/app/Page.tsx"));
+}
+
+#[test]
+fn chrome_trace_메트릭마다_complete_이벤트를_만들어야_함() {
+    let monitor = PerformanceMonitor::new(true);
+    monitor.measure_sync("codegen", &[("file_path", "a.tsx")], || ());
+    let trace = PerformanceReporter::chrome_trace(&monitor.get_report());
+
+    assert_eq!(trace["displayTimeUnit"], "ms");
+    let events = trace["traceEvents"].as_array().unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0]["name"], "codegen");
+    assert_eq!(events[0]["ph"], "X");
+    assert_eq!(events[0]["pid"], 1);
+    assert_eq!(events[0]["tid"], 1);
+    assert_eq!(events[0]["args"]["file_path"], "a.tsx");
+    assert!(events[0]["ts"].is_u64() && events[0]["dur"].is_u64());
+}
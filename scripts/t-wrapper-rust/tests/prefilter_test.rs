@@ -0,0 +1,71 @@
+/*!
+ * prefilter 테스트
+ * 파싱 전 사전 필터 테스트
+ */
+
+#![allow(non_snake_case)]
+
+use t_wrapper_rust::prefilter::{is_minified, may_contain_korean, prefilter_skip_reason, FileSkipReason};
+
+#[test]
+fn may_contain_korean_가부터_힣까지의_UTF8_바이트를_찾아야_함() {
+    assert!(may_contain_korean("const a = \"가\";".as_bytes()));
+    assert!(may_contain_korean("const a = \"힣\";".as_bytes()));
+    assert!(may_contain_korean("<p>안녕하세요</p>".as_bytes()));
+
+    assert!(!may_contain_korean(b"export const a = 1;"));
+    // 한자, 가나, 한글 자모는 korean_text 패턴(가-힣)과 마찬가지로 대상이 아님
+    assert!(!may_contain_korean("const a = \"日本語 ひらがな ㄱㄴ\";".as_bytes()));
+    // U+A000대 (EA 80 ..)는 가(U+AC00) 앞
+    assert!(!may_contain_korean("const a = \"ꀀ\";".as_bytes()));
+    // U+D7B0 (ED 9E B0)은 힣(U+D7A3) 뒤지만 두 번째 바이트만 보므로 보수적으로 true
+    assert!(may_contain_korean("const a = \"ힰ\";".as_bytes()));
+}
+
+#[test]
+fn may_contain_korean_이스케이프가_있으면_파싱해서_확인하도록_true를_반환해야_함() {
+    assert!(may_contain_korean(br#"const a = "\uAC00";"#));
+    assert!(may_contain_korean(b"<p>&#44032;</p>"));
+    assert!(!may_contain_korean(br#"const a = "\n";"#));
+}
+
+#[test]
+fn is_minified_긴_줄이_있고_평균_줄_길이도_길어야_함() {
+    let minified = format!("var a=\"{}\";", "x".repeat(1200));
+    assert!(is_minified(&minified));
+
+    let data_uri = format!(
+        "{}const icon = \"data:image/png;base64,{}\";\n",
+        "const a = 1;\n".repeat(200),
+        "A".repeat(1200)
+    );
+    assert!(!is_minified(&data_uri));
+    assert!(!is_minified(""));
+}
+
+#[test]
+fn prefilter_skip_reason_생성_번들_minified_한국어_없음_순서로_판단해야_함() {
+    assert_eq!(
+        prefilter_skip_reason("// @generated by codegen\nexport const a = \"안녕\";"),
+        Some(FileSkipReason::Generated)
+    );
+    assert_eq!(
+        prefilter_skip_reason("/* eslint-disable */\nexport const a = \"안녕\";"),
+        Some(FileSkipReason::EslintDisabled)
+    );
+    assert_eq!(
+        prefilter_skip_reason(&format!("var a=\"안녕{}\";", "x".repeat(1200))),
+        Some(FileSkipReason::Minified)
+    );
+    assert_eq!(prefilter_skip_reason("export const a = 1;"), Some(FileSkipReason::NoKorean));
+
+    // 특정 규칙만 끄는 주석은 번들 표시가 아님
+    assert_eq!(
+        prefilter_skip_reason("/* eslint-disable no-console */\nexport const a = \"안녕\";"),
+        None
+    );
+    assert_eq!(prefilter_skip_reason("// eslint-disable-next-line\nconst a = \"안녕\";"), None);
+    // 앞부분(2048바이트) 뒤의 @generated는 보지 않음
+    let late_marker = format!("const a = \"안녕\";\n{}// @generated\n", "// 주석\n".repeat(300));
+    assert_eq!(prefilter_skip_reason(&late_marker), None);
+}
@@ -0,0 +1,103 @@
+/*!
+ * session 테스트
+ * Session, SourceFileHandle 테스트
+ */
+
+#![allow(non_snake_case)]
+
+use swc_common::comments::Comments;
+use swc_common::Spanned;
+use t_wrapper_rust::parser::ParseOptions;
+use t_wrapper_rust::session::{LineCol, Session};
+use t_wrapper_rust::has_ignore_comment;
+
+fn options(file_name: &str) -> ParseOptions {
+    ParseOptions {
+        file_name: Some(file_name.to_string()),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn parse_file_파일마다_겹치지_않는_위치를_받아야_함() {
+    let session = Session::new();
+    let (first_module, first) = session.parse_file("const a = 1;\n", &options("a.tsx")).unwrap();
+    let (second_module, second) = session.parse_file("const b = 2;\n", &options("b.tsx")).unwrap();
+
+    assert_eq!(first.name(), "a.tsx");
+    assert_eq!(second.source(), "const b = 2;\n");
+    assert!(second.start_pos() > first_module.span.hi);
+    assert_eq!(second.offset(second_module.span.lo), 0);
+    assert_eq!(second.pos(6), second_module.span.lo + swc_common::BytePos(6));
+}
+
+#[test]
+fn line_col_파일과_세션_기준_위치를_1부터_시작하는_줄_열로_바꿔야_함() {
+    let session = Session::new();
+    session.parse_file("const a = 1;\n", &options("a.tsx")).unwrap();
+    let code = "const a = 1;\nconst 제목 = \"안녕\";\n";
+    let (module, file) = session.parse_file(code, &options("b.tsx")).unwrap();
+
+    let string_offset = code.find('"').unwrap();
+    let expected = LineCol { line: 2, column: 12 };
+    assert_eq!(file.offset_line_col(string_offset), expected);
+    assert_eq!(session.line_col(file.pos(string_offset)), expected);
+    assert_eq!(file.line_col(module.body[1].span().lo), LineCol { line: 2, column: 1 });
+}
+
+#[test]
+fn comments_모든_파일의_주석을_하나의_저장소에서_조회해야_함() {
+    let session = Session::new();
+    let (first, _) = session.parse_file("// i18n-ignore\nconst a = \"가\";", &options("a.tsx")).unwrap();
+    let (second, _) = session.parse_file("const b = \"나\";\n// i18n-ignore\nconst c = \"다\";", &options("b.tsx")).unwrap();
+
+    let comments: &dyn Comments = session.comments();
+    assert!(has_ignore_comment(first.body[0].span(), Some(comments)));
+    assert!(!has_ignore_comment(second.body[0].span(), Some(comments)));
+    assert!(has_ignore_comment(second.body[1].span(), Some(comments)));
+}
+
+#[test]
+fn generate_code_자기_파일의_주석만_유지해야_함() {
+    let session = Session::new();
+    session.parse_file("// 다른 파일\nconst a = 1;", &options("a.tsx")).unwrap();
+    let (module, _) = session
+        .parse_file("/** 설명 */\nexport const b = 2; // 끝 주석\n", &options("b.tsx"))
+        .unwrap();
+
+    let output = session.generate_code(&module).unwrap();
+    assert_eq!(output, "/** 설명 */\nexport const b = 2; // 끝 주석\n");
+}
+
+#[test]
+fn parse_file_여러_스레드에서_같은_세션으로_파싱할_수_있어야_함() {
+    let session = Session::new();
+    let handles: Vec<_> = std::thread::scope(|scope| {
+        let jobs: Vec<_> = (0..4)
+            .map(|index| {
+                let session = &session;
+                scope.spawn(move || {
+                    let code = format!("// 파일 {}\nexport const value = {};\n", index, index);
+                    let (module, file) = session.parse_file(&code, &options(&format!("{}.tsx", index))).unwrap();
+                    (module, file)
+                })
+            })
+            .collect();
+        jobs.into_iter().map(|job| job.join().unwrap()).collect()
+    });
+
+    for (index, (module, file)) in handles.iter().enumerate() {
+        assert_eq!(file.name(), format!("{}.tsx", index));
+        let output = session.generate_code(module).unwrap();
+        assert_eq!(output, format!("// 파일 {}\nexport const value = {};\n", index, index));
+    }
+}
+
+#[test]
+fn parse_file_파싱_에러는_파일_경로와_줄_열을_포함해야_함() {
+    let session = Session::new();
+    let error = session
+        .parse_file("const a = 1;\nconst 제목 = ;\n", &options("src/page.tsx"))
+        .unwrap_err();
+    assert!(error.to_string().contains("(src/page.tsx:2:"), "{}", error);
+}
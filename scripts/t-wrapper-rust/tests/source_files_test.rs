@@ -0,0 +1,181 @@
+/*!
+ * source-files 테스트
+ * brace expansion과 include/exclude 패턴 파일 수집 테스트
+ */
+
+use anyhow::Result;
+use std::fs;
+use std::path::{Path, PathBuf};
+use t_wrapper_rust::source_files::{
+    absolute_path, collect_source_files, expand_braces, pattern_base_dirs, SourceFileOptions,
+};
+use tempfile::tempdir;
+
+fn write_files(root: &Path, files: &[&str]) -> Result<()> {
+    for file in files {
+        let path = root.join(file);
+        fs::create_dir_all(path.parent().unwrap())?;
+        fs::write(path, "export {};")?;
+    }
+    Ok(())
+}
+
+fn relative_paths(root: &Path, paths: Vec<PathBuf>) -> Vec<String> {
+    paths
+        .iter()
+        .map(|path| path.strip_prefix(root).unwrap().to_string_lossy().into_owned())
+        .collect()
+}
+
+fn pattern(root: &Path, pattern: &str) -> String {
+    root.join(pattern).to_string_lossy().into_owned()
+}
+
+#[test]
+fn expand_braces_중괄호_대안을_모두_확장해야_함() {
+    assert_eq!(
+        expand_braces("src/**/*.{js,jsx,ts,tsx}"),
+        vec!["src/**/*.js", "src/**/*.jsx", "src/**/*.ts", "src/**/*.tsx"]
+    );
+    assert_eq!(
+        expand_braces("{app,pages}/*.{ts,tsx}"),
+        vec!["app/*.ts", "app/*.tsx", "pages/*.ts", "pages/*.tsx"]
+    );
+}
+
+#[test]
+fn expand_braces_중첩과_짝이_맞지_않는_중괄호를_처리해야_함() {
+    assert_eq!(expand_braces("*.{ts,{m,c}js}"), vec!["*.ts", "*.mjs", "*.cjs"]);
+    assert_eq!(expand_braces("src/{a}/*.ts"), vec!["src/{a}/*.ts"]);
+    assert_eq!(expand_braces("src/{a,b/*.ts"), vec!["src/{a,b/*.ts"]);
+    assert_eq!(expand_braces("src/**/*.ts"), vec!["src/**/*.ts"]);
+}
+
+#[test]
+fn collect_source_files_기본_패턴으로_모든_확장자를_찾아야_함() -> Result<()> {
+    let dir = tempdir()?;
+    write_files(dir.path(), &["src/a.js", "src/b.jsx", "src/c/d.ts", "src/c/e.tsx", "src/f.css"])?;
+
+    let files = collect_source_files(&SourceFileOptions {
+        include_patterns: vec![pattern(dir.path(), "src/**/*.{js,jsx,ts,tsx}")],
+        ..Default::default()
+    })?;
+
+    assert_eq!(
+        relative_paths(dir.path(), files),
+        vec!["src/a.js", "src/b.jsx", "src/c/d.ts", "src/c/e.tsx"]
+    );
+    Ok(())
+}
+
+#[test]
+fn collect_source_files_여러_include_패턴을_중복_없이_합쳐야_함() -> Result<()> {
+    let dir = tempdir()?;
+    write_files(dir.path(), &["app/page.tsx", "components/Button.tsx", "lib/util.ts"])?;
+
+    let files = collect_source_files(&SourceFileOptions {
+        include_patterns: vec![
+            pattern(dir.path(), "app/**/*.tsx"),
+            pattern(dir.path(), "components/**/*.tsx"),
+            pattern(dir.path(), "**/*.tsx"),
+        ],
+        ..Default::default()
+    })?;
+
+    assert_eq!(relative_paths(dir.path(), files), vec!["app/page.tsx", "components/Button.tsx"]);
+    Ok(())
+}
+
+#[test]
+fn collect_source_files_exclude_패턴과_기본_제외_디렉토리를_건너뛰어야_함() -> Result<()> {
+    let dir = tempdir()?;
+    write_files(
+        dir.path(),
+        &[
+            "src/Button.tsx",
+            "src/Button.test.tsx",
+            "src/Button.stories.tsx",
+            "src/node_modules/pkg/index.tsx",
+            "src/.next/server/page.tsx",
+            "src/dist/bundle.tsx",
+        ],
+    )?;
+
+    let files = collect_source_files(&SourceFileOptions {
+        include_patterns: vec![pattern(dir.path(), "src/**/*.tsx")],
+        exclude_patterns: vec!["**/*.{test,stories}.tsx".to_string()],
+        ..Default::default()
+    })?;
+
+    assert_eq!(relative_paths(dir.path(), files), vec!["src/Button.tsx"]);
+    Ok(())
+}
+
+#[test]
+fn collect_source_files_기본_제외_디렉토리_안의_프로젝트도_찾아야_함() -> Result<()> {
+    let outer = tempdir()?;
+    let root = outer.path().join("dist").join("app");
+    write_files(&root, &["src/Button.tsx", "src/dist/bundle.tsx", "src/node_modules/pkg/index.tsx"])?;
+
+    let files = collect_source_files(&SourceFileOptions {
+        include_patterns: vec![pattern(&root, "src/**/*.tsx")],
+        ..Default::default()
+    })?;
+    assert_eq!(relative_paths(&root, files), vec!["src/Button.tsx"]);
+
+    // `..`로 올라가는 상대 패턴도 패턴 아래 경로만 확인
+    let nested = root.join("src");
+    let files = collect_source_files(&SourceFileOptions {
+        include_patterns: vec![pattern(&nested, "../src/**/*.tsx")],
+        ..Default::default()
+    })?;
+    assert_eq!(files.len(), 1, "{:?}", files);
+    Ok(())
+}
+
+#[test]
+fn collect_source_files_respect_gitignore면_gitignore_대상을_제외해야_함() -> Result<()> {
+    let dir = tempdir()?;
+    fs::create_dir(dir.path().join(".git"))?;
+    fs::write(dir.path().join(".gitignore"), "generated/\n*.gen.tsx\n!keep.gen.tsx\n")?;
+    write_files(
+        dir.path(),
+        &["src/App.tsx", "src/generated/Api.tsx", "src/Icons.gen.tsx", "src/keep.gen.tsx"],
+    )?;
+
+    let options = SourceFileOptions {
+        include_patterns: vec![pattern(dir.path(), "src/**/*.tsx")],
+        respect_gitignore: true,
+        ..Default::default()
+    };
+    let files = collect_source_files(&options)?;
+    assert_eq!(relative_paths(dir.path(), files), vec!["src/App.tsx", "src/keep.gen.tsx"]);
+
+    let files = collect_source_files(&SourceFileOptions {
+        respect_gitignore: false,
+        ..options
+    })?;
+    assert_eq!(files.len(), 4);
+    Ok(())
+}
+
+#[test]
+fn pattern_base_dirs_glob_문자_앞까지의_디렉토리를_중복_없이_반환해야_함() {
+    let patterns = |patterns: &[&str]| pattern_base_dirs(&patterns.iter().map(|p| p.to_string()).collect::<Vec<_>>());
+
+    assert_eq!(patterns(&["src/**/*.{ts,tsx}"]), vec![PathBuf::from("src")]);
+    assert_eq!(
+        patterns(&["{app,components}/**/*.tsx", "app/page.tsx"]),
+        vec![PathBuf::from("app"), PathBuf::from("components")]
+    );
+    assert_eq!(patterns(&["*.tsx", "src/**/*.tsx"]), vec![PathBuf::from(".")]);
+    assert_eq!(patterns(&["src/app/**/*.tsx", "src/**/*.ts"]), vec![PathBuf::from("src")]);
+}
+
+#[test]
+fn absolute_path_점과_상위_디렉토리를_정리해야_함() -> Result<()> {
+    let current_dir = std::env::current_dir()?;
+    assert_eq!(absolute_path(Path::new("./src/../app/page.tsx")), current_dir.join("app/page.tsx"));
+    assert_eq!(absolute_path(Path::new("/tmp/a/./b/../c.tsx")), PathBuf::from("/tmp/a/c.tsx"));
+    Ok(())
+}
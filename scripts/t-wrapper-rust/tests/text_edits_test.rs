@@ -0,0 +1,116 @@
+/*!
+ * text-edits 테스트
+ * 원본 span 기준 편집 적용 테스트
+ */
+
+#![allow(non_snake_case)]
+
+use swc_common::{BytePos, Span};
+use t_wrapper_rust::text_edits::{SourceText, TextEdits};
+
+fn span(lo: u32, hi: u32) -> Span {
+    Span::new(BytePos(lo), BytePos(hi))
+}
+
+#[test]
+fn apply_편집_범위만_바꾸고_나머지는_그대로_유지해야_함() {
+    // SourceMap의 BytePos는 파일 시작 위치(base)부터 시작
+    let base = BytePos(10);
+    let source = "const a = '안녕'  ;";
+    let mut edits = TextEdits::new();
+    edits.replace(span(20, 28), r#"t("안녕")"#);
+    edits.insert(BytePos(10), "// 머리\n");
+
+    let output = edits.apply(source, base).unwrap();
+    assert_eq!(output, "// 머리\nconst a = t(\"안녕\")  ;");
+}
+
+#[test]
+fn apply_같은_위치에서는_삽입을_교체보다_먼저_기록_순서대로_적용해야_함() {
+    let mut edits = TextEdits::new();
+    edits.replace(span(1, 4), "X");
+    edits.insert(BytePos(1), "a");
+    edits.insert(BytePos(1), "b");
+    edits.insert(BytePos(4), "c");
+
+    assert_eq!(edits.apply("0123456", BytePos(1)).unwrap(), "abXc3456");
+}
+
+#[test]
+fn apply_같은_위치에서는_최상위_구문_삽입을_먼저_적용해야_함() {
+    let mut edits = TextEdits::new();
+    // 첫 구문의 `async `를 먼저 기록해도 import가 앞에 와야 함
+    edits.insert(BytePos(1), "async ");
+    edits.insert_module_item(BytePos(1), "import x;\n");
+
+    assert_eq!(edits.apply("function A() {}", BytePos(1)).unwrap(), "import x;\nasync function A() {}");
+}
+
+#[test]
+fn replace_code_여러_줄_코드는_원본_줄의_들여쓰기로_맞춰야_함() {
+    // 코드 생성기의 4칸 들여쓰기가 2칸 파일에 그대로 들어가면 안 됨
+    let source = "function A() {\n  return f(x);\n}";
+    let mut edits = TextEdits::new();
+    edits.replace_code(span(25, 29), "t(\"a\", {\n    name\n})");
+    assert_eq!(
+        edits.apply(source, BytePos(1)).unwrap(),
+        "function A() {\n  return t(\"a\", {\n    name\n  });\n}"
+    );
+
+    // 템플릿 리터럴 안의 줄바꿈은 문자열 내용이므로 그대로 둠
+    let mut edits = TextEdits::new();
+    edits.replace_code(span(25, 29), "t(\"a\", {\n    v: `x\ny`\n})");
+    assert_eq!(
+        edits.apply(source, BytePos(1)).unwrap(),
+        "function A() {\n  return t(\"a\", {\n    v: `x\ny`\n});\n}"
+    );
+}
+
+#[test]
+fn replace_바깥_범위를_교체하면_안쪽_편집은_제거해야_함() {
+    let mut edits = TextEdits::new();
+    edits.replace(span(3, 5), "inner");
+    edits.insert(BytePos(1), "<");
+    edits.insert(BytePos(8), ">");
+    edits.replace(span(1, 8), "outer");
+
+    assert_eq!(edits.len(), 3);
+    assert_eq!(edits.apply("0123456789", BytePos(1)).unwrap(), "<outer>789");
+}
+
+#[test]
+fn apply_겹치는_편집과_범위_밖_편집은_에러여야_함() {
+    let mut edits = TextEdits::new();
+    edits.insert(BytePos(2), "x");
+    edits.replace(span(1, 4), "a");
+    edits.replace(span(3, 6), "b");
+    assert!(edits.apply("0123456789", BytePos(1)).is_err());
+
+    let mut edits = TextEdits::new();
+    edits.replace(span(0, 2), "a");
+    assert!(edits.apply("0123", BytePos(1)).is_err());
+
+    let mut edits = TextEdits::new();
+    edits.replace(span(1, 20), "a");
+    assert!(edits.apply("0123", BytePos(1)).is_err());
+}
+
+#[test]
+fn SourceText_들여쓰기와_줄_위치를_원본_기준으로_계산해야_함() {
+    let code = "function A() {\n\treturn 1;\n}\n";
+    let source = SourceText::new(code, BytePos(1));
+    // "return"의 위치
+    let pos = BytePos(1 + code.find("return").unwrap() as u32);
+    assert_eq!(source.line_indent(pos), "\t");
+    assert_eq!(source.indent_unit(pos), "\t");
+    assert_eq!(source.line_indent(BytePos(1)), "");
+    assert!(!source.is_same_line(BytePos(1), pos));
+    assert!(source.is_same_line(pos, pos + BytePos(6)));
+
+    // 덜 들여쓴 앞 줄과의 차이가 한 단계
+    let code = "<div>\n    <p>\n        {a}\n    </p>\n</div>";
+    let source = SourceText::new(code, BytePos(1));
+    let pos = BytePos(1 + code.find("{a}").unwrap() as u32);
+    assert_eq!(source.indent_unit(pos), "    ");
+    assert_eq!(source.indent_unit(BytePos(1)), "  ");
+}
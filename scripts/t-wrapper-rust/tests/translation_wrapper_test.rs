@@ -1,13 +1,14 @@
-/**
+/*!
  * translation-wrapper 테스트
  * TranslationWrapper 클래스 테스트
  */
 
+#![allow(non_snake_case)]
+
 use t_wrapper_rust::{TranslationWrapper, ScriptConfig};
 use anyhow::Result;
-use tempfile::{tempdir, NamedTempFile};
+use tempfile::tempdir;
 use std::fs;
-use std::path::PathBuf;
 
 #[test]
 fn process_files_한국어가_포함된_파일을_처리해야_함() -> Result<()> {
@@ -48,10 +49,9 @@ fn process_files_nextjs_환경에서_client_모드일_때만_use_client를_추
 
     wrapper.process_files()?;
     let content = fs::read_to_string(&file_path)?;
-    // TODO: 실제 AST 변환 및 코드 생성 후 검증
-    // assert!(content.contains("'use client'"));
-    // assert!(content.contains("useTranslation"));
-    // assert!(content.contains("t("));
+    assert!(content.contains("t("));
+    assert!(content.contains("useTranslation"));
+    assert!(content.contains("\"use client\""));
     Ok(())
 }
 
@@ -74,10 +74,9 @@ fn process_files_react_환경에서_client_모드일_때는_use_client를_추가
 
     wrapper.process_files()?;
     let content = fs::read_to_string(&file_path)?;
-    // TODO: 실제 AST 변환 및 코드 생성 후 검증
-    // assert!(!content.contains("'use client'"));
-    // assert!(content.contains("useTranslation"));
-    // assert!(content.contains("t("));
+    assert!(content.contains("t("));
+    assert!(content.contains("useTranslation"));
+    assert!(!content.contains("use client"));
     Ok(())
 }
 
@@ -100,9 +99,328 @@ fn process_files_server_모드에서는_지정한_serverTranslationFunction으
 
     wrapper.process_files()?;
     let content = fs::read_to_string(&file_path)?;
-    // TODO: 실제 AST 변환 및 코드 생성 후 검증
-    // assert!(content.contains("await getServerT"));
-    // assert!(content.contains("const { t } ="));
-    // assert!(content.contains("t("));
+    assert!(content.contains("t("));
+    assert!(content.contains("await getServerT"));
+    assert!(content.contains("const { t } ="));
+    Ok(())
+}
+
+#[test]
+fn process_files_with_report_파일별_후보_문자열과_요약을_JSON으로_만들어야_함() -> Result<()> {
+    let dir = tempdir()?;
+    let component = dir.path().join("Component.tsx");
+    fs::write(&component, r#"const TITLE = "상수";
+export function Component() {
+  return <p className="본문">안녕하세요</p>;
+}"#)?;
+    fs::write(dir.path().join("util.tsx"), "export const a = 1;")?;
+
+    let wrapper = TranslationWrapper::new(Some(ScriptConfig {
+        source_pattern: dir.path().join("**/*.tsx").to_string_lossy().to_string(),
+        dry_run: true,
+        report: Some("json".to_string()),
+        ..Default::default()
+    }));
+    let (processed_files, report) = wrapper.process_files_with_report()?;
+    assert_eq!(processed_files.len(), 1);
+
+    let json: serde_json::Value = serde_json::from_str(&report.to_json()?)?;
+    assert_eq!(
+        json["summary"],
+        serde_json::json!({
+            "files_processed": 2,
+            "files_modified": 1,
+            "strings_wrapped": 1,
+            "strings_skipped": 2,
+            "files_skipped": 1,
+            "files_skipped_by_reason": { "no_korean": 1 }
+        })
+    );
+    let files = json["files"].as_array().unwrap();
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0]["file"], component.to_string_lossy().as_ref());
+    assert_eq!(
+        files[0]["strings"][2],
+        serde_json::json!({
+            "file": component.to_string_lossy(),
+            "line": 3,
+            "column": 28,
+            "text": "안녕하세요",
+            "key": "안녕하세요",
+            "kind": "jsx_text",
+            "wrapped": true,
+            "skip_reason": null
+        })
+    );
+    assert_eq!(files[0]["strings"][0]["skip_reason"], "non_component_scope");
+    assert_eq!(files[0]["strings"][1]["skip_reason"], "non_translatable_attribute");
+    Ok(())
+}
+
+#[test]
+fn run_translation_wrapper_trace_out이_있으면_단계별_trace를_저장해야_함() -> Result<()> {
+    let dir = tempdir()?;
+    fs::write(dir.path().join("test.ts"), "function Component() {\n  return \"안녕하세요\";\n}\n")?;
+    let trace_path = dir.path().join("trace.json");
+
+    t_wrapper_rust::run_translation_wrapper(ScriptConfig {
+        source_pattern: dir.path().join("**/*.ts").to_string_lossy().to_string(),
+        dry_run: true,
+        trace_out: Some(trace_path.to_string_lossy().to_string()),
+        ..Default::default()
+    })?;
+
+    let trace: serde_json::Value = serde_json::from_str(&fs::read_to_string(&trace_path)?)?;
+    let names: Vec<&str> = trace["traceEvents"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|event| event["name"].as_str().unwrap())
+        .collect();
+    for name in ["translation_wrapper:total", "glob", "read", "parse", "transform", "codegen", "file_processing"] {
+        assert!(names.contains(&name), "{} not in {:?}", name, names);
+    }
+    assert!(!names.contains(&"write"));
+    Ok(())
+}
+
+#[test]
+fn process_files_with_report_jobs와_상관없이_같은_순서의_결과를_만들어야_함() -> Result<()> {
+    let dir = tempdir()?;
+    for index in 0..24 {
+        let code = if index % 3 == 0 {
+            format!("export const value{} = {};", index, index)
+        } else {
+            format!(
+                "export function Page{0}() {{\n  return <p title=\"제목 {0}\">안녕하세요 {0}</p>;\n}}\n",
+                index
+            )
+        };
+        fs::write(dir.path().join(format!("page{:02}.tsx", index)), code)?;
+    }
+
+    let run = |jobs: usize| -> Result<(Vec<String>, String, String)> {
+        let patch_path = dir.path().join(format!("jobs{}.patch", jobs));
+        let wrapper = TranslationWrapper::new(Some(ScriptConfig {
+            source_pattern: dir.path().join("**/*.tsx").to_string_lossy().to_string(),
+            dry_run: true,
+            patch_out: Some(patch_path.to_string_lossy().to_string()),
+            jobs: Some(jobs),
+            ..Default::default()
+        }));
+        let (processed_files, report) = wrapper.process_files_with_report()?;
+        Ok((processed_files, report.to_json()?, fs::read_to_string(patch_path)?))
+    };
+
+    let sequential = run(1)?;
+    let parallel = run(4)?;
+    assert_eq!(sequential.0.len(), 16);
+    let mut sorted = sequential.0.clone();
+    sorted.sort();
+    assert_eq!(sequential.0, sorted);
+    assert_eq!(sequential, parallel);
+    Ok(())
+}
+
+#[test]
+fn process_files_with_report_파싱_전에_건너뛴_파일을_이유별로_세어야_함() -> Result<()> {
+    let dir = tempdir()?;
+    let component = "export function Page() {\n  return <p>안녕하세요</p>;\n}\n";
+    fs::write(dir.path().join("Page.tsx"), component)?;
+    fs::write(dir.path().join("generated.tsx"), format!("// @generated\n{}", component))?;
+    fs::write(dir.path().join("bundle.tsx"), format!("/* eslint-disable */\n{}", component))?;
+    fs::write(dir.path().join("large.tsx"), format!("{}{}", component, "// 패딩\n".repeat(100)))?;
+    fs::write(dir.path().join("util.tsx"), "export const a = 1;\n")?;
+
+    let wrapper = TranslationWrapper::new(Some(ScriptConfig {
+        source_pattern: dir.path().join("**/*.tsx").to_string_lossy().to_string(),
+        mode: Some("client".to_string()),
+        max_file_size: Some(500),
+        ..Default::default()
+    }));
+    let (processed_files, report) = wrapper.process_files_with_report()?;
+
+    assert_eq!(processed_files, vec![dir.path().join("Page.tsx").to_string_lossy().to_string()]);
+    let json: serde_json::Value = serde_json::from_str(&report.to_json()?)?;
+    assert_eq!(json["summary"]["files_processed"], 5);
+    assert_eq!(json["summary"]["files_skipped"], 4);
+    assert_eq!(
+        json["summary"]["files_skipped_by_reason"],
+        serde_json::json!({ "too_large": 1, "generated": 1, "eslint_disabled": 1, "no_korean": 1 })
+    );
+    assert!(fs::read_to_string(dir.path().join("generated.tsx"))?.starts_with("// @generated\nexport function Page"));
+    Ok(())
+}
+
+#[test]
+fn process_files_한국어가_없는_파일도_import_graph_경로로_사용해야_함() -> Result<()> {
+    let dir = tempdir()?;
+    // page(use client, 한국어 없음) → Panel(한국어 없음) → Label(한국어, 신호 없음)
+    fs::write(
+        dir.path().join("page.tsx"),
+        "\"use client\";\nimport { Panel } from \"./Panel\";\nexport default function Page() {\n  return <Panel />;\n}\n",
+    )?;
+    fs::write(
+        dir.path().join("Panel.tsx"),
+        "import { Label } from \"./Label\";\nexport function Panel() {\n  return <Label />;\n}\n",
+    )?;
+    let label = dir.path().join("Label.tsx");
+    fs::write(&label, "export function Label() {\n  return <span>안녕하세요</span>;\n}\n")?;
+
+    let wrapper = TranslationWrapper::new(Some(ScriptConfig {
+        source_pattern: dir.path().join("**/*.tsx").to_string_lossy().to_string(),
+        framework: Some("nextjs".to_string()),
+        ..Default::default()
+    }));
+    let (processed_files, report) = wrapper.process_files_with_report()?;
+
+    assert_eq!(processed_files, vec![label.to_string_lossy().to_string()]);
+    assert_eq!(report.summary.files_skipped, 2);
+    let content = fs::read_to_string(&label)?;
+    assert!(content.contains("useTranslation()"), "{}", content);
+    assert!(!content.contains("getServerTranslation"), "{}", content);
+    Ok(())
+}
+
+#[test]
+fn process_files_사전_필터로_건너뛴_파일도_import_graph_경로로_사용해야_함() -> Result<()> {
+    let dir = tempdir()?;
+    // page(use client) → Panel(@generated, 변환하지 않음) → Label(한국어, 신호 없음)
+    fs::write(
+        dir.path().join("page.tsx"),
+        "\"use client\";\nimport { Panel } from \"./Panel\";\nexport default function Page() {\n  return <Panel />;\n}\n",
+    )?;
+    let panel = dir.path().join("Panel.tsx");
+    let panel_code = "// @generated\nimport { Label } from \"./Label\";\nexport function Panel() {\n  return <Label title=\"생성됨\" />;\n}\n";
+    fs::write(&panel, panel_code)?;
+    let label = dir.path().join("Label.tsx");
+    fs::write(&label, "export function Label() {\n  return <span>안녕하세요</span>;\n}\n")?;
+
+    let wrapper = TranslationWrapper::new(Some(ScriptConfig {
+        source_pattern: dir.path().join("**/*.tsx").to_string_lossy().to_string(),
+        framework: Some("nextjs".to_string()),
+        ..Default::default()
+    }));
+    let (processed_files, _) = wrapper.process_files_with_report()?;
+
+    assert_eq!(processed_files, vec![label.to_string_lossy().to_string()]);
+    assert_eq!(fs::read_to_string(&panel)?, panel_code);
+    let content = fs::read_to_string(&label)?;
+    assert!(content.contains("useTranslation()"), "{}", content);
+    assert!(!content.contains("getServerTranslation"), "{}", content);
+    Ok(())
+}
+
+#[test]
+fn process_files_with_report_캐시에_있는_바뀌지_않은_파일은_파싱하지_않아야_함() -> Result<()> {
+    let dir = tempdir()?;
+    let page = dir.path().join("Page.tsx");
+    let label = dir.path().join("Label.tsx");
+    fs::write(&page, "export function Page() {\n  return <p>안녕하세요</p>;\n}\n")?;
+    // i18n-ignore로 감싸지 않는 한국어만 있어 바뀌지 않는 파일
+    fs::write(&label, "export function Label() {\n  // i18n-ignore\n  return <span>{\"고정\"}</span>;\n}\n")?;
+    let cache_path = dir.path().join(".i18nexus/cache.json");
+    let config = ScriptConfig {
+        source_pattern: dir.path().join("**/*.tsx").to_string_lossy().to_string(),
+        mode: Some("client".to_string()),
+        cache_file: Some(cache_path.to_string_lossy().to_string()),
+        ..Default::default()
+    };
+
+    // 1회차: Page는 변환되어 기록하지 않고, Label만 기록
+    let (processed_files, report) = TranslationWrapper::new(Some(config.clone())).process_files_with_report()?;
+    assert_eq!(processed_files, vec![page.to_string_lossy().to_string()]);
+    assert_eq!(report.summary.files_skipped, 0);
+    let cache: serde_json::Value = serde_json::from_str(&fs::read_to_string(&cache_path)?)?;
+    assert_eq!(cache["files"].as_object().map(|files| files.len()), Some(1));
+
+    // 2회차: 변환된 Page를 다시 확인해 기록, Label은 캐시로 건너뜀
+    let (processed_files, report) = TranslationWrapper::new(Some(config.clone())).process_files_with_report()?;
+    assert!(processed_files.is_empty());
+    assert_eq!(report.summary.files_skipped, 1);
+
+    // 3회차: 두 파일 모두 캐시로 건너뜀
+    let (_, report) = TranslationWrapper::new(Some(config.clone())).process_files_with_report()?;
+    assert_eq!(report.summary.files_skipped, 2);
+    assert_eq!(report.summary.files_skipped_by_reason.len(), 1);
+
+    // 내용이 바뀐 파일은 다시 처리
+    fs::write(&label, "export function Label() {\n  return <span>새 문구</span>;\n}\n")?;
+    let (processed_files, report) = TranslationWrapper::new(Some(config.clone())).process_files_with_report()?;
+    assert_eq!(processed_files, vec![label.to_string_lossy().to_string()]);
+    assert_eq!(report.summary.files_skipped, 1);
+    Ok(())
+}
+
+#[test]
+fn process_files_with_report_설정이_바뀌거나_리포트를_요청하면_캐시로_건너뛰지_않아야_함() -> Result<()> {
+    let dir = tempdir()?;
+    fs::write(
+        dir.path().join("Label.tsx"),
+        "export function Label() {\n  // i18n-ignore\n  return <span>{\"고정\"}</span>;\n}\n",
+    )?;
+    let config = ScriptConfig {
+        source_pattern: dir.path().join("**/*.tsx").to_string_lossy().to_string(),
+        mode: Some("client".to_string()),
+        cache_file: Some(dir.path().join("cache.json").to_string_lossy().to_string()),
+        ..Default::default()
+    };
+    TranslationWrapper::new(Some(config.clone())).process_files_with_report()?;
+    let (_, report) = TranslationWrapper::new(Some(config.clone())).process_files_with_report()?;
+    assert_eq!(report.summary.files_skipped, 1);
+
+    let (_, report) = TranslationWrapper::new(Some(ScriptConfig {
+        report: Some("json".to_string()),
+        ..config.clone()
+    }))
+    .process_files_with_report()?;
+    assert_eq!(report.summary.files_skipped, 0);
+    assert_eq!(report.summary.strings_skipped, 1);
+
+    let server_config = ScriptConfig {
+        mode: Some("server".to_string()),
+        ..config
+    };
+    let (_, report) = TranslationWrapper::new(Some(server_config.clone())).process_files_with_report()?;
+    assert_eq!(report.summary.files_skipped, 0);
+    let (_, report) = TranslationWrapper::new(Some(server_config)).process_files_with_report()?;
+    assert_eq!(report.summary.files_skipped, 1);
+    Ok(())
+}
+
+#[test]
+fn process_changed_files_바뀐_파일만_변환하고_나머지는_import_graph에만_사용해야_함() -> Result<()> {
+    let dir = tempdir()?;
+    // page(use client) → Panel(한국어, 신호 없음, 저장되지 않음) → Label(한국어, 신호 없음, 저장됨)
+    fs::write(
+        dir.path().join("page.tsx"),
+        "\"use client\";\nimport { Panel } from \"./Panel\";\nexport default function Page() {\n  return <Panel />;\n}\n",
+    )?;
+    let panel = dir.path().join("Panel.tsx");
+    let panel_source = "import { Label } from \"./Label\";\nexport function Panel() {\n  return <div><Label />안녕</div>;\n}\n";
+    fs::write(&panel, panel_source)?;
+    let label = dir.path().join("Label.tsx");
+    fs::write(&label, "export function Label() {\n  return <span>안녕하세요</span>;\n}\n")?;
+    let notes = dir.path().join("notes.md");
+    fs::write(&notes, "안녕")?;
+
+    let wrapper = TranslationWrapper::new(Some(ScriptConfig {
+        source_pattern: dir.path().join("**/*.tsx").to_string_lossy().to_string(),
+        framework: Some("nextjs".to_string()),
+        ..Default::default()
+    }));
+    let changed = std::collections::HashSet::from([t_wrapper_rust::absolute_path(&label)]);
+    let (processed_files, report) = wrapper.process_changed_files(&changed)?;
+
+    assert_eq!(processed_files, vec![label.to_string_lossy().to_string()]);
+    assert_eq!(report.summary.files_processed, 1);
+    assert!(fs::read_to_string(&label)?.contains("useTranslation()"));
+    assert_eq!(fs::read_to_string(&panel)?, panel_source);
+
+    // 처리 대상이 아닌 파일만 바뀌면 아무것도 하지 않음
+    let changed = std::collections::HashSet::from([t_wrapper_rust::absolute_path(&notes)]);
+    let (processed_files, report) = wrapper.process_changed_files(&changed)?;
+    assert!(processed_files.is_empty());
+    assert_eq!(report.summary.files_processed, 0);
     Ok(())
 }
@@ -1,4 +1,4 @@
-/// ast_helpers 모듈 통합 테스트
+//! ast_helpers 모듈 통합 테스트
 
 use t_wrapper_rust::is_react_component;
 use t_wrapper_rust::is_server_component;
@@ -0,0 +1,67 @@
+/*!
+ * watch 테스트
+ * 감시 모드 이벤트 묶음과 자기 쓰기 이벤트 무시 테스트
+ */
+
+#![allow(non_snake_case)]
+
+use anyhow::Result;
+use notify::event::{CreateKind, DataChange, EventKind, MetadataKind, ModifyKind, RemoveKind};
+use notify::Event;
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+use std::sync::mpsc;
+use std::time::Duration;
+use t_wrapper_rust::{absolute_path, next_batch, SelfWrites};
+use tempfile::tempdir;
+
+fn event(kind: EventKind, path: &Path) -> notify::Result<Event> {
+    Ok(Event::new(kind).add_path(path.to_path_buf()))
+}
+
+#[test]
+fn next_batch_debounce_동안_온_이벤트를_한_묶음으로_모아야_함() -> Result<()> {
+    let dir = tempdir()?;
+    let page = dir.path().join("Page.tsx");
+    let label = dir.path().join("Label.tsx");
+    let removed = dir.path().join("Removed.tsx");
+    fs::write(&page, "")?;
+    fs::write(&label, "")?;
+
+    let (sender, events) = mpsc::channel();
+    // 저장 한 번에 여러 이벤트가 옴
+    sender.send(event(EventKind::Create(CreateKind::File), &page))?;
+    sender.send(event(EventKind::Modify(ModifyKind::Data(DataChange::Content)), &page))?;
+    sender.send(event(EventKind::Modify(ModifyKind::Data(DataChange::Any)), &label))?;
+    // 권한 변경, 삭제, 디렉토리는 무시
+    sender.send(event(EventKind::Modify(ModifyKind::Metadata(MetadataKind::Permissions)), &label))?;
+    sender.send(event(EventKind::Remove(RemoveKind::File), &removed))?;
+    sender.send(event(EventKind::Create(CreateKind::Folder), dir.path()))?;
+
+    let batch = next_batch(&events, Duration::from_millis(50)).unwrap();
+    assert_eq!(batch, HashSet::from([absolute_path(&page), absolute_path(&label)]));
+
+    drop(sender);
+    assert_eq!(next_batch(&events, Duration::from_millis(50)), None);
+    Ok(())
+}
+
+#[test]
+fn SelfWrites_직접_쓴_내용_그대로인_파일만_제외해야_함() -> Result<()> {
+    let dir = tempdir()?;
+    let page = dir.path().join("Page.tsx");
+    let label = dir.path().join("Label.tsx");
+    fs::write(&page, "const { t } = useTranslation();")?;
+    fs::write(&label, "<p>안녕</p>")?;
+
+    let mut self_writes = SelfWrites::default();
+    self_writes.record(&[page.to_string_lossy().to_string()]);
+    let changed = HashSet::from([absolute_path(&page), absolute_path(&label)]);
+    assert_eq!(self_writes.retain_external(changed.clone()), HashSet::from([absolute_path(&label)]));
+
+    // 사용자가 다시 저장하면 처리
+    fs::write(&page, "<p>새 문구</p>")?;
+    assert_eq!(self_writes.retain_external(changed), HashSet::from([absolute_path(&page), absolute_path(&label)]));
+    Ok(())
+}
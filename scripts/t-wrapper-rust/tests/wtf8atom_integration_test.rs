@@ -1,4 +1,4 @@
-/**
+/*!
  * Wtf8Atom 변환 통합 테스트
  * ast_transformers에서 실제로 사용되는 변환 로직이 올바르게 작동하는지 확인
  */
@@ -8,8 +8,11 @@ use t_wrapper_rust::ast_transformers::transform_module;
 
 #[test]
 fn test_wtf8atom_conversion_in_ast_transform() {
-    // 한국어가 포함된 간단한 코드 (JSX 없이)
-    let code = r#"const text = "안녕하세요";"#;
+    // 한국어가 포함된 간단한 코드 (JSX 없이, 훅 body 안)
+    let code = r#"function useText() {
+  const text = "안녕하세요";
+  return text;
+}"#;
     
     let mut module = parse_file(code, Default::default()).unwrap();
     let (result, _) = transform_module(&mut module, code.to_string());
@@ -40,8 +43,11 @@ fn test_wtf8atom_conversion_only_korean_strings() {
 #[test]
 fn test_wtf8atom_conversion_mixed_strings() {
     // 한국어와 영어가 섞인 코드
-    let code = r#"const korean = "안녕하세요";
-const english = "Hello";"#;
+    let code = r#"function useText() {
+  const korean = "안녕하세요";
+  const english = "Hello";
+  return [korean, english];
+}"#;
     
     let mut module = parse_file(code, Default::default()).unwrap();
     let (result, _) = transform_module(&mut module, code.to_string());
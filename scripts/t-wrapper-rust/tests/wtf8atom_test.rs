@@ -1,4 +1,4 @@
-/**
+/*!
  * Wtf8Atom 변환 테스트
  * to_atom_lossy()와 as_wtf8().to_string_lossy() 메서드가 작동하는지 확인
  */